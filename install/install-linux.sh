@@ -22,8 +22,9 @@ echo Exec=$APP_PATH >> $DESKTOP_FILE
 echo Name=HDL Register Wizard >> $DESKTOP_FILE
 echo Icon=$ICON_PATH/${APP_NAME}.png >> $DESKTOP_FILE
 
-# copy binary
+# copy binaries
 cp $SRC_EXEC_PATH $APP_PATH
+cp ${SRC_EXEC_PATH}_diff ${APP_PATH}_diff
 
 # copy icon
 mkdir -p $ICON_PATH
@@ -14,6 +14,7 @@ DESKTOP_FILE=$SHARED_PATH/applications/${APP_NAME}.desktop
 
 rm $DESKTOP_FILE
 rm $APP_PATH
+rm ${APP_PATH}_diff
 rm $ICON_PATH/${APP_NAME}.png
 rm -r $DATA_PATH
 
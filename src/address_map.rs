@@ -0,0 +1,297 @@
+//! address map of an interface
+//!
+//! Places the registers and memories of an interface in its address space the same way the generation
+//! does, so that the automatic addresses can be seen before generating. The result is a sorted list of
+//! blocks, with the free gaps between them and the items sharing addresses.
+
+use crate::file_formats::mdf;
+use crate::page::PageType;
+use std::collections::HashMap;
+
+/// kind of an item in the address map
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ItemKind {
+    /// register, possibly with several elements
+    Register,
+    /// register generated by an interrupt block
+    Interrupt,
+    /// memory
+    Memory,
+    /// range reserved for future use
+    Reserved,
+}
+
+/// register or memory placed in the address space
+#[derive(PartialEq, Clone)]
+pub struct MapItem {
+    /// register or memory name
+    pub name: String,
+    /// what the item is
+    pub kind: ItemKind,
+    /// page where the item is edited
+    pub page: PageType,
+    /// true if the address was assigned automatically
+    pub auto: bool,
+    /// address range of each element, as start address and number of addresses
+    pub elements: Vec<(u128, u128)>,
+    /// names of the other items using some of the same addresses
+    pub conflicts: Vec<String>,
+}
+
+/// block drawn in the address map
+#[derive(PartialEq, Clone, Debug)]
+pub struct MapBlock {
+    /// index of the item in the map, or None for free space
+    pub item: Option<usize>,
+    /// element index when an array is expanded
+    pub element: Option<usize>,
+    /// first address of the block
+    pub start: u128,
+    /// number of addresses in the block
+    pub size: u128,
+}
+
+/// address map of an interface
+#[derive(PartialEq, Clone, Default)]
+pub struct AddressMap {
+    /// address increment between two bus words
+    pub word_increment: u128,
+    /// registers and memories with their addresses
+    pub items: Vec<MapItem>,
+    /// problem found when placing the items. When there is one, only the fixed addresses are shown
+    pub error: Option<String>,
+}
+
+/// interface placed in its address space the way the generation does it: expression results, interrupt
+/// registers after the interface ones, addresses assigned on all the variants, absolute addresses in the
+/// groups, and then only the registers and fields of the generated variant
+pub struct PlacedInterface {
+    /// the placed copy of the interface
+    pub interface: mdf::Interface,
+    /// number of each register in the model interface, None for the interrupt registers
+    pub sources: Vec<Option<usize>>,
+    /// true for each register whose address was assigned automatically
+    pub auto_registers: Vec<bool>,
+    /// true for each memory whose address was assigned automatically
+    pub auto_memories: Vec<bool>,
+    /// problems found when placing the interface. When the addresses can't be assigned, only the fixed ones
+    /// outside of groups are kept
+    pub errors: Vec<String>,
+}
+
+/// items of a list whose flag is set
+fn keep_selected<T>(list: Vec<T>, kept: &[bool]) -> Vec<T> {
+    list.into_iter().zip(kept).filter(|(_, kept)| **kept).map(|(item, _)| item).collect()
+}
+
+impl PlacedInterface {
+    /// place an interface of the model
+    pub fn new(model: &mdf::Mdf, interface: &mdf::Interface) -> Self {
+        let mut errors = Vec::new();
+        let mut placed = interface.clone();
+        if let Err(e) = model.parameter_values().and_then(|values| placed.evaluate_expressions(&values)) {
+            errors.push(e);
+            placed = interface.clone();
+        }
+        let register_count = placed.registers.len();
+        if let Err(e) = placed.expand_interrupt_blocks() {
+            errors.push(e);
+        }
+
+        // remember which addresses are given by the user before the assignment
+        let auto_groups: Vec<String> =
+            placed.groups.iter().filter(|group| group.base.is_none()).map(|group| group.name.clone()).collect();
+        let mut auto_registers: Vec<bool> = placed
+            .registers
+            .iter()
+            .map(|register| {
+                register.address.value.is_none()
+                    || register.group.as_ref().map_or(false, |group| auto_groups.contains(group))
+            })
+            .collect();
+        let auto_memories: Vec<bool> = placed.memories.iter().map(|memory| memory.address.is_none()).collect();
+
+        // if the addresses can't be assigned, keep only the fixed ones outside of groups
+        let declared = placed.clone();
+        if let Err(e) = placed.assign_addresses().and_then(|_| placed.flatten_groups()) {
+            errors.push(e);
+            placed = declared;
+            for register in placed.registers.iter_mut().filter(|register| register.group.is_some()) {
+                register.address.value = None;
+            }
+        }
+
+        let mut sources: Vec<Option<usize>> =
+            (0..placed.registers.len()).map(|n| if n < register_count { Some(n) } else { None }).collect();
+        if let Some(variant) = model.generated_variant(None) {
+            if !placed.in_variant(&variant) {
+                errors.push(format!("interface {} isn't generated in variant {}", placed.name, variant));
+            }
+            let kept: Vec<bool> = placed.registers.iter().map(|register| register.in_variant(&variant)).collect();
+            sources = keep_selected(sources, &kept);
+            auto_registers = keep_selected(auto_registers, &kept);
+            placed.select_variant(&variant);
+        }
+
+        PlacedInterface { interface: placed, sources, auto_registers, auto_memories, errors }
+    }
+}
+
+impl MapItem {
+    /// first address used by the item
+    pub fn start(&self) -> u128 {
+        self.elements.iter().map(|(start, _)| *start).min().unwrap_or(0)
+    }
+
+    /// address following the last one used by the item, u128::MAX if the item goes to the end of the address space
+    pub fn end(&self) -> u128 {
+        self.elements.iter().map(|(start, size)| start.saturating_add(*size)).max().unwrap_or(0)
+    }
+
+    /// number of addresses from the first to the last one used by the item
+    pub fn size(&self) -> u128 {
+        let first = self.start();
+        self.elements.iter().map(|(start, size)| (start - first).saturating_add(*size)).max().unwrap_or(0)
+    }
+}
+
+impl MapBlock {
+    /// last address of the block
+    pub fn last(&self) -> u128 {
+        self.start.saturating_add(self.size.saturating_sub(1))
+    }
+}
+
+impl AddressMap {
+    /// build the address map of an interface of the model
+    pub fn new(model: &mdf::Mdf, int_num: usize) -> Self {
+        let mut map = AddressMap::default();
+        let interface = match model.interfaces.get(int_num) {
+            Some(interface) => interface,
+            None => return map,
+        };
+        let placed = PlacedInterface::new(model, interface);
+        map.error = placed.errors.first().cloned();
+        let interface = placed.interface;
+        let width_bits = match interface.get_data_width() {
+            Some(width) if width > 0 => width,
+            _ => {
+                map.error = Some(format!("Unable to determine the width of interface {}", interface.name));
+                return map;
+            }
+        };
+        map.word_increment = interface.addressing_mode().word_increment(width_bits);
+
+        for ((register, source), auto) in interface.registers.iter().zip(placed.sources).zip(placed.auto_registers) {
+            if let Some(address) = register.address.value {
+                let element_span = register.element_span(width_bits, map.word_increment);
+                let offsets = match &register.address.stride {
+                    None => vec![0],
+                    Some(stride) => stride.offsets(element_span),
+                };
+                let (kind, page) = match source {
+                    Some(reg_num) => (ItemKind::Register, PageType::Register(int_num, reg_num, None)),
+                    None => (ItemKind::Interrupt, PageType::Interface(int_num)),
+                };
+                map.items.push(MapItem {
+                    name: register.name.clone(),
+                    kind,
+                    page,
+                    auto,
+                    elements: offsets.into_iter().map(|offset| (address.value.saturating_add(offset), element_span)).collect(),
+                    conflicts: Vec::new(),
+                });
+            }
+        }
+        for (memory, auto) in interface.memories.iter().zip(placed.auto_memories) {
+            if let Some(address) = memory.address {
+                map.items.push(MapItem {
+                    name: memory.name.clone(),
+                    kind: ItemKind::Memory,
+                    page: PageType::Interface(int_num),
+                    auto,
+                    elements: vec![(address.value, memory.span(map.word_increment))],
+                    conflicts: Vec::new(),
+                });
+            }
+        }
+
+        for range in interface.reserved.iter().filter(|range| range.end.value >= range.start.value) {
+            map.items.push(MapItem {
+                name: range.name.clone(),
+                kind: ItemKind::Reserved,
+                page: PageType::Interface(int_num),
+                auto: false,
+                elements: vec![(range.start.value, (range.end.value - range.start.value).saturating_add(1))],
+                conflicts: Vec::new(),
+            });
+        }
+
+        map.find_conflicts();
+        map
+    }
+
+    /// fill the conflicts of each item, going through all the element ranges sorted by address
+    fn find_conflicts(&mut self) {
+        let mut ranges: Vec<(u128, u128, usize)> = self
+            .items
+            .iter()
+            .enumerate()
+            .flat_map(|(n, item)| item.elements.iter().map(move |(start, size)| (*start, start.saturating_add(*size), n)))
+            .collect();
+        ranges.sort();
+
+        let mut conflicts: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (i, (_, end, item)) in ranges.iter().enumerate() {
+            for (other_start, _, other) in ranges[i + 1..].iter() {
+                if other_start >= end {
+                    break;
+                }
+                if other != item {
+                    for (a, b) in [(item, other), (other, item)] {
+                        let list = conflicts.entry(*a).or_default();
+                        if !list.contains(b) {
+                            list.push(*b);
+                        }
+                    }
+                }
+            }
+        }
+
+        for (item, others) in conflicts {
+            let names = others.iter().map(|other| self.items[*other].name.clone()).collect();
+            self.items[item].conflicts = names;
+        }
+    }
+
+    /// blocks to draw, sorted by address with the free space between them. The arrays are shown as a single
+    /// block, unless their item index is in the expanded list
+    pub fn blocks(&self, expanded: &[usize]) -> Vec<MapBlock> {
+        let mut blocks: Vec<MapBlock> = Vec::new();
+        for (n, item) in self.items.iter().enumerate() {
+            if item.elements.len() > 1 && expanded.contains(&n) {
+                blocks.extend(item.elements.iter().enumerate().map(|(e, (start, size))| MapBlock {
+                    item: Some(n),
+                    element: Some(e),
+                    start: *start,
+                    size: *size,
+                }));
+            } else {
+                blocks.push(MapBlock { item: Some(n), element: None, start: item.start(), size: item.size() });
+            }
+        }
+        blocks.sort_by_key(|block| (block.start, block.item));
+
+        // insert the free space between the blocks, from address 0
+        let mut with_gaps = Vec::new();
+        let mut next_free: u128 = 0;
+        for block in blocks {
+            if block.start > next_free {
+                with_gaps.push(MapBlock { item: None, element: None, start: next_free, size: block.start - next_free });
+            }
+            next_free = u128::max(next_free, block.start.saturating_add(block.size));
+            with_gaps.push(block);
+        }
+        with_gaps
+    }
+}
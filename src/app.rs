@@ -337,9 +337,13 @@ pub fn LiveHelp(app_data: Signal<HdlWizardApp>, page_type: page::PageType, live_
                 ("Register", text)
             },
             page::PageType::Preview => ("Preview", include_str!(concat!(env!("OUT_DIR"), "/live_help/preview.html")).to_owned()),
+            page::PageType::Validation => ("Validation", include_str!(concat!(env!("OUT_DIR"), "/live_help/validation.html")).to_owned()),
+            page::PageType::AddressMap(_) => ("Address map", include_str!(concat!(env!("OUT_DIR"), "/live_help/address-map.html")).to_owned()),
+            page::PageType::Compare => ("Compare", include_str!(concat!(env!("OUT_DIR"), "/live_help/compare.html")).to_owned()),
 //                _ => ("WIP","<p>Not written yet</p>".to_owned()) 
             page::PageType::ChangeRegisterField(_,_,_) => ("", String::new()),
             page::PageType::Settings(page::SettingsPageType::Strings) => ("WIP","<p>Not written yet</p>".to_owned()),
+            page::PageType::Settings(page::SettingsPageType::Generation) => ("WIP","<p>Not written yet</p>".to_owned()),
         };
         rsx!(
             aside { class: "panel ext-sticky m-5 is-link ext-livehelp",
@@ -0,0 +1,29 @@
+//! Command line comparison of two register files
+//!
+//! This is a separate console program, as the main application is built as a windowed program on Windows,
+//! where it has no console to write the report to.
+#![forbid(unsafe_code)]
+#![cfg_attr(not(debug_assertions), deny(warnings))] // Forbid warnings in release builds
+#![warn(clippy::all, rust_2018_idioms)]
+
+/// Compare the two files given on the command line and write the report on the standard output
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    use hdl_register_wizard::diff;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match diff::command_line(&args) {
+        Ok((report, changed)) => {
+            print!("{report}");
+            std::process::exit(if changed { 1 } else { 0 });
+        }
+        Err(e) => {
+            eprintln!("{e}\n\n{}", diff::USAGE);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// There is no command line on the webapp
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,586 @@
+//! semantic comparison of two models
+//!
+//! Compares the registers of two models the way a firmware developer sees them: the registers and fields are
+//! matched by name, the ones that disappeared on one side and appeared on the other with the same fields or at
+//! the same place are reported as renamed, and only the changes visible from the bus are listed (addresses, widths, array sizes,
+//! access types, reset values and descriptions). The addresses are compared after the automatic assignment, so a register
+//! moved by the insertion of another one is reported too. The report can be written as text, HTML or JSON.
+
+use crate::address_map::PlacedInterface;
+use crate::file_formats::mdf;
+use serde::Serialize;
+
+/// what changed in an interface, register or field
+#[derive(Serialize, strum_macros::Display, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ChangeKind {
+    /// only in the new model
+    Added,
+    /// only in the old model
+    Removed,
+    /// same item, with another name
+    Renamed,
+    /// the register addresses or the field bits changed, with the same width
+    Moved,
+    /// the number of bits changed
+    Width,
+    /// the number of elements of a register array changed
+    Count,
+    /// the access type changed
+    Access,
+    /// the reset value changed
+    Reset,
+    /// the summary or the description changed
+    Description,
+}
+
+/// a difference between the two models
+#[derive(Serialize, PartialEq, Clone, Debug)]
+pub struct Change {
+    /// what changed
+    pub kind: ChangeKind,
+    /// interface name, in the new model unless the interface was removed
+    pub interface: String,
+    /// register name, in the new model unless the register was removed. None for an interface change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub register: Option<String>,
+    /// field name, in the new model unless the field was removed. None for an interface or register change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// value in the old model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    /// value in the new model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+}
+
+/// result of the comparison of two models
+#[derive(Serialize, PartialEq, Clone, Debug, Default)]
+pub struct ModelDiff {
+    /// name of the old model
+    pub old_name: String,
+    /// name of the new model
+    pub new_name: String,
+    /// differences, interface by interface
+    pub changes: Vec<Change>,
+    /// problems found when placing the registers. The declared addresses are compared instead
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// report formats
+#[derive(
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+    Debug,
+)]
+pub enum OutputFormat {
+    /// plain text, for a terminal or release notes
+    #[strum(serialize = "text")]
+    Text,
+    /// HTML table, for a web page or a pull request comment
+    #[strum(serialize = "html")]
+    Html,
+    /// JSON, for other tools
+    #[strum(serialize = "json")]
+    Json,
+}
+
+/// register of an interface, with the values used for the comparison
+struct RegisterInfo {
+    /// the register with its addresses assigned
+    register: mdf::Register,
+    /// width in bits
+    width: u32,
+    /// absolute address of each element, None if it isn't known
+    addresses: Option<Vec<u128>>,
+    /// true if the address is given in the model instead of being assigned automatically
+    fixed: bool,
+}
+
+/// prepare the registers of an interface the same way the generation does: expression results, interrupt
+/// registers, absolute addresses and the generated variant. `side` tells which model it is in the warnings
+fn prepare_interface(
+    model: &mdf::Mdf,
+    interface: &mdf::Interface,
+    side: &str,
+    warnings: &mut Vec<String>,
+) -> Vec<RegisterInfo> {
+    let placed = PlacedInterface::new(model, interface);
+    warnings.extend(placed.errors.iter().map(|e| format!("{} model, interface {}: {}", side, interface.name, e)));
+
+    let interface_width = placed.interface.get_data_width().unwrap_or(0);
+    let word_increment = placed.interface.addressing_mode().word_increment(interface_width);
+    placed
+        .interface
+        .registers
+        .into_iter()
+        .zip(placed.auto_registers)
+        .map(|(register, auto)| {
+            let width = register.get_data_width().unwrap_or(interface_width);
+            let addresses = register.address.value.map(|address| {
+                register
+                    .offsets(interface_width, word_increment)
+                    .into_iter()
+                    .map(|offset| address.value.saturating_add(offset))
+                    .collect()
+            });
+            RegisterInfo { register, width, addresses, fixed: !auto }
+        })
+        .collect()
+}
+
+/// interfaces of the model in its generated variant
+fn generated_interfaces(model: &mdf::Mdf) -> Vec<&mdf::Interface> {
+    let variant = model.generated_variant(None);
+    model
+        .interfaces
+        .iter()
+        .filter(|interface| variant.as_ref().map_or(true, |variant| interface.in_variant(variant)))
+        .collect()
+}
+
+/// number of elements of a register array, 1 for a single register
+fn element_count(register: &mdf::Register) -> u128 {
+    register.address.stride.as_ref().map_or(1, |stride| stride.total_count())
+}
+
+/// summary and description, as one text
+fn description_text(summary: &Option<Vec<String>>, description: &Option<Vec<String>>) -> String {
+    [summary, description]
+        .iter()
+        .filter_map(|lines| lines.as_ref())
+        .map(|lines| lines.join("\n"))
+        .filter(|text| !text.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// bits used by a field, for display
+fn field_bits(field: &mdf::Field) -> String {
+    let position = match field.position {
+        mdf::FieldPosition::Single(bit) => format!("bit {}", bit),
+        mdf::FieldPosition::Field(msb, lsb) => format!("bits {}:{}", msb, lsb),
+    };
+    match field.array_count() {
+        1 => position,
+        count => format!("{} x{}", position, count),
+    }
+}
+
+/// pair the items of two lists: first by name, then the remaining ones with the `same_place` function. Returns
+/// the pairs as indexes in the old and new lists, the old items left alone and the new items left alone
+fn match_items<T>(
+    old: &[T],
+    new: &[T],
+    name: impl Fn(&T) -> &str,
+    same_place: impl Fn(&T, &T) -> bool,
+) -> (Vec<(usize, usize)>, Vec<usize>, Vec<usize>) {
+    let mut new_used = vec![false; new.len()];
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut old_alone = Vec::new();
+
+    for (o, old_item) in old.iter().enumerate() {
+        match new.iter().enumerate().position(|(n, new_item)| !new_used[n] && name(new_item) == name(old_item)) {
+            Some(n) => {
+                new_used[n] = true;
+                pairs.push((o, n));
+            }
+            None => old_alone.push(o),
+        }
+    }
+
+    // renamed items: the old ones without a match take the first free new one at the same place
+    old_alone.retain(|o| {
+        match new.iter().enumerate().position(|(n, new_item)| !new_used[n] && same_place(&old[*o], new_item)) {
+            Some(n) => {
+                new_used[n] = true;
+                pairs.push((*o, n));
+                false
+            }
+            None => true,
+        }
+    });
+
+    let new_alone = (0..new.len()).filter(|n| !new_used[*n]).collect();
+    (pairs, old_alone, new_alone)
+}
+
+impl Change {
+    /// create a change
+    fn new(kind: ChangeKind, interface: &str, register: Option<&str>, field: Option<&str>) -> Self {
+        Change {
+            kind,
+            interface: interface.to_owned(),
+            register: register.map(str::to_owned),
+            field: field.map(str::to_owned),
+            old: None,
+            new: None,
+        }
+    }
+
+    /// set the old and new values
+    fn values(mut self, old: Option<String>, new: Option<String>) -> Self {
+        self.old = old;
+        self.new = new;
+        self
+    }
+
+    /// interface, register or field concerned by the change
+    pub fn subject(&self) -> String {
+        match (&self.register, &self.field) {
+            (None, _) => format!("interface {}", self.interface),
+            (Some(register), None) => format!("register {}", register),
+            (Some(register), Some(field)) => format!("field {}.{}", register, field),
+        }
+    }
+
+    /// one line description of the change
+    pub fn description(&self) -> String {
+        let old = self.old.clone().unwrap_or_default();
+        let new = self.new.clone().unwrap_or_default();
+        let subject = self.subject();
+        match self.kind {
+            ChangeKind::Added => match &self.new {
+                Some(new) => format!("{} added at {}", subject, new),
+                None => format!("{} added", subject),
+            },
+            ChangeKind::Removed => match &self.old {
+                Some(old) => format!("{} removed, was at {}", subject, old),
+                None => format!("{} removed", subject),
+            },
+            ChangeKind::Renamed => format!("{} renamed from {}", subject, old),
+            ChangeKind::Moved => format!("{} moved from {} to {}", subject, old, new),
+            ChangeKind::Width => format!("{} width changed from {} to {}", subject, old, new),
+            ChangeKind::Count => format!("{} changed from {} to {}", subject, old, new),
+            ChangeKind::Access => format!("{} access changed from {} to {}", subject, old, new),
+            ChangeKind::Reset => format!("{} reset value changed from {} to {}", subject, old, new),
+            ChangeKind::Description => format!("{} description changed", subject),
+        }
+    }
+}
+
+impl ModelDiff {
+    /// compare two models
+    pub fn new(old: &mdf::Mdf, new: &mdf::Mdf) -> Self {
+        let mut diff = ModelDiff { old_name: old.name.clone(), new_name: new.name.clone(), ..Default::default() };
+
+        // only the interfaces of the generated variants are compared
+        let old_interfaces = generated_interfaces(old);
+        let new_interfaces = generated_interfaces(new);
+
+        // with one interface on each side, a different name is a rename
+        let single = old_interfaces.len() == 1 && new_interfaces.len() == 1;
+        let (pairs, old_alone, new_alone) =
+            match_items(&old_interfaces, &new_interfaces, |interface| &interface.name, |_, _| single);
+
+        for o in old_alone {
+            diff.changes.push(Change::new(ChangeKind::Removed, &old_interfaces[o].name, None, None));
+        }
+        for (o, n) in pairs {
+            diff.compare_interfaces(old, old_interfaces[o], new, new_interfaces[n]);
+        }
+        for n in new_alone {
+            diff.changes.push(Change::new(ChangeKind::Added, &new_interfaces[n].name, None, None));
+        }
+        diff
+    }
+
+    /// compare the registers of two interfaces
+    fn compare_interfaces(
+        &mut self,
+        old_model: &mdf::Mdf,
+        old_interface: &mdf::Interface,
+        new_model: &mdf::Mdf,
+        new_interface: &mdf::Interface,
+    ) {
+        let interface = new_interface.name.as_str();
+        if old_interface.name != new_interface.name {
+            self.changes.push(
+                Change::new(ChangeKind::Renamed, interface, None, None)
+                    .values(Some(old_interface.name.clone()), Some(new_interface.name.clone())),
+            );
+        }
+
+        let old_registers = prepare_interface(old_model, old_interface, "old", &mut self.warnings);
+        let new_registers = prepare_interface(new_model, new_interface, "new", &mut self.warnings);
+
+        // a register is renamed if it holds the same fields, or uses the same addresses. The automatic addresses
+        // shift when a register is removed, so they are only a hint when the description is the same too
+        let (pairs, old_alone, _) = match_items(
+            &old_registers,
+            &new_registers,
+            |info| &info.register.name,
+            |old, new| {
+                let field_names = |info: &RegisterInfo| {
+                    info.register.fields.iter().map(|field| field.name.clone()).collect::<Vec<_>>()
+                };
+                let description = |info: &RegisterInfo| {
+                    description_text(&info.register.summary, &info.register.description)
+                };
+                let same_address = old.addresses.is_some() && old.addresses == new.addresses;
+                (!old.register.fields.is_empty() && field_names(old) == field_names(new))
+                    || (same_address && old.fixed && new.fixed)
+                    || (same_address && !description(old).is_empty() && description(old) == description(new))
+            },
+        );
+
+        for o in old_alone {
+            let register = &old_registers[o].register;
+            self.changes.push(
+                Change::new(ChangeKind::Removed, interface, Some(&register.name), None)
+                    .values(Some(register.address.nice_str()), None),
+            );
+        }
+
+        // list the changes in the new register order
+        let mut pairs = pairs;
+        pairs.sort_by_key(|(_, n)| *n);
+        let mut pairs = pairs.into_iter().peekable();
+        for (n, new_info) in new_registers.iter().enumerate() {
+            match pairs.next_if(|(_, paired)| *paired == n) {
+                Some((o, _)) => self.compare_registers(interface, &old_registers[o], new_info),
+                None => self.changes.push(
+                    Change::new(ChangeKind::Added, interface, Some(&new_info.register.name), None)
+                        .values(None, Some(new_info.register.address.nice_str())),
+                ),
+            }
+        }
+    }
+
+    /// compare two registers
+    fn compare_registers(&mut self, interface: &str, old: &RegisterInfo, new: &RegisterInfo) {
+        let register = new.register.name.as_str();
+        let change = |kind| Change::new(kind, interface, Some(register), None);
+
+        if old.register.name != new.register.name {
+            self.changes.push(
+                change(ChangeKind::Renamed).values(Some(old.register.name.clone()), Some(register.to_owned())),
+            );
+        }
+        // a different base address is a move, a different number of elements is reported on its own
+        let base = |info: &RegisterInfo| info.addresses.as_ref().and_then(|addresses| addresses.first().copied());
+        let (old_count, new_count) = (element_count(&old.register), element_count(&new.register));
+        if base(old) != base(new) {
+            let address = |info: &RegisterInfo| {
+                info.register.address.value.map_or("auto".to_owned(), |address| address.to_string())
+            };
+            self.changes.push(change(ChangeKind::Moved).values(Some(address(old)), Some(address(new))));
+        } else if old_count == new_count && old.addresses != new.addresses {
+            // same elements with another spacing
+            self.changes.push(
+                change(ChangeKind::Moved)
+                    .values(Some(old.register.address.nice_str()), Some(new.register.address.nice_str())),
+            );
+        }
+        if old_count != new_count {
+            self.changes.push(
+                change(ChangeKind::Count)
+                    .values(Some(format!("{} elements", old_count)), Some(format!("{} elements", new_count))),
+            );
+        }
+        // the width of a bitfield register follows its fields, whose changes are listed below
+        let bitfields = old.register.signal.is_none() && new.register.signal.is_none();
+        if old.width != new.width && !bitfields {
+            self.changes.push(
+                change(ChangeKind::Width)
+                    .values(Some(format!("{} bits", old.width)), Some(format!("{} bits", new.width))),
+            );
+        }
+        if old.register.access != new.register.access {
+            let access = |access: Option<mdf::AccessType>| access.map_or("none".to_owned(), |access| access.to_string());
+            self.changes.push(
+                change(ChangeKind::Access).values(Some(access(old.register.access)), Some(access(new.register.access))),
+            );
+        }
+        if old.register.reset.map(|reset| reset.value) != new.register.reset.map(|reset| reset.value) {
+            let reset = |reset: Option<crate::utils::VectorValue>| reset.map_or("none".to_owned(), |reset| reset.to_string());
+            self.changes.push(
+                change(ChangeKind::Reset).values(Some(reset(old.register.reset)), Some(reset(new.register.reset))),
+            );
+        }
+        let old_description = description_text(&old.register.summary, &old.register.description);
+        let new_description = description_text(&new.register.summary, &new.register.description);
+        if old_description != new_description {
+            self.changes.push(change(ChangeKind::Description).values(Some(old_description), Some(new_description)));
+        }
+
+        // a field is renamed if it uses the same bits
+        let (pairs, old_alone, new_alone) = match_items(
+            &old.register.fields,
+            &new.register.fields,
+            |field| &field.name,
+            |old, new| old.bits() == new.bits(),
+        );
+        for o in old_alone {
+            let field = &old.register.fields[o];
+            self.changes.push(
+                Change::new(ChangeKind::Removed, interface, Some(register), Some(&field.name))
+                    .values(Some(field_bits(field)), None),
+            );
+        }
+        for (o, n) in pairs {
+            self.compare_fields(interface, register, &old.register.fields[o], &new.register.fields[n]);
+        }
+        for n in new_alone {
+            let field = &new.register.fields[n];
+            self.changes.push(
+                Change::new(ChangeKind::Added, interface, Some(register), Some(&field.name))
+                    .values(None, Some(field_bits(field))),
+            );
+        }
+    }
+
+    /// compare two fields of a register
+    fn compare_fields(&mut self, interface: &str, register: &str, old: &mdf::Field, new: &mdf::Field) {
+        let change = |kind| Change::new(kind, interface, Some(register), Some(&new.name));
+
+        if old.name != new.name {
+            self.changes.push(change(ChangeKind::Renamed).values(Some(old.name.clone()), Some(new.name.clone())));
+        }
+        if old.bits() != new.bits() {
+            let kind = if old.bits().len() == new.bits().len() { ChangeKind::Moved } else { ChangeKind::Width };
+            self.changes.push(change(kind).values(Some(field_bits(old)), Some(field_bits(new))));
+        }
+        if old.access != new.access {
+            self.changes.push(change(ChangeKind::Access).values(Some(old.access.to_string()), Some(new.access.to_string())));
+        }
+        if old.reset.value != new.reset.value {
+            self.changes.push(change(ChangeKind::Reset).values(Some(old.reset.to_string()), Some(new.reset.to_string())));
+        }
+        let old_description = description_text(&None, &old.description);
+        let new_description = description_text(&None, &new.description);
+        if old_description != new_description {
+            self.changes.push(change(ChangeKind::Description).values(Some(old_description), Some(new_description)));
+        }
+    }
+
+    /// true if the models are the same
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// write the report in the given format
+    pub fn report(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Text => self.to_text(),
+            OutputFormat::Html => self.to_html(),
+            OutputFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    /// text report, with the changes grouped by interface
+    pub fn to_text(&self) -> String {
+        let mut text = format!("Changes from {} to {}\n", self.old_name, self.new_name);
+        for warning in &self.warnings {
+            text.push_str(&format!("warning: {}\n", warning));
+        }
+        if self.changes.is_empty() {
+            text.push_str("\nNo change.\n");
+        }
+
+        let mut interface: Option<&str> = None;
+        for change in &self.changes {
+            if interface != Some(&change.interface) {
+                interface = Some(&change.interface);
+                text.push_str(&format!("\nInterface {}\n", change.interface));
+            }
+            text.push_str(&format!("  - {}\n", change.description()));
+            if change.kind == ChangeKind::Description {
+                for (label, value) in [("was", &change.old), ("now", &change.new)] {
+                    let value = value.as_deref().unwrap_or_default().replace('\n', " ");
+                    text.push_str(&format!("      {}: {}\n", label, if value.is_empty() { "(none)" } else { &value }));
+                }
+            }
+        }
+        text
+    }
+
+    /// HTML fragment with a table of the changes
+    pub fn to_html(&self) -> String {
+        let mut html = format!(
+            "<h2>Changes from {} to {}</h2>\n",
+            escape_html(&self.old_name),
+            escape_html(&self.new_name)
+        );
+        for warning in &self.warnings {
+            html.push_str(&format!("<p><em>warning: {}</em></p>\n", escape_html(warning)));
+        }
+        if self.changes.is_empty() {
+            html.push_str("<p>No change.</p>\n");
+            return html;
+        }
+
+        html.push_str("<table>\n<thead>\n<tr><th>Interface</th><th>Item</th><th>Change</th><th>Old</th><th>New</th></tr>\n</thead>\n<tbody>\n");
+        for change in &self.changes {
+            let cell = |value: &Option<String>| escape_html(value.as_deref().unwrap_or_default()).replace('\n', "<br>");
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&change.interface),
+                escape_html(&change.subject()),
+                escape_html(&change.description()),
+                cell(&change.old),
+                cell(&change.new)
+            ));
+        }
+        html.push_str("</tbody>\n</table>\n");
+        html
+    }
+}
+
+/// escape the characters with a special meaning in HTML
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// usage of the command line comparison
+pub const USAGE: &str = "usage: hdl_register_wizard_diff [--format text|html|json] <old file> <new file>
+
+Compares two register files and writes the changes on the standard output.
+The exit code is 0 when the files are equivalent, 1 when they differ and 2 on error.";
+
+/// parse the command line arguments, giving the format and the old and new file names
+pub fn parse_arguments(args: &[String]) -> Result<(OutputFormat, String, String), String> {
+    let mut format = OutputFormat::Text;
+    let mut files = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--format" || arg == "-f" {
+            let value = args.next().ok_or_else(|| format!("missing value after {}", arg))?;
+            format = value.parse().map_err(|_| format!("unknown format {}", value))?;
+        } else if let Some(value) = arg.strip_prefix("--format=") {
+            format = value.parse().map_err(|_| format!("unknown format {}", value))?;
+        } else if arg.starts_with('-') && arg.len() > 1 {
+            return Err(format!("unknown option {}", arg));
+        } else {
+            files.push(arg.clone());
+        }
+    }
+
+    match <[String; 2]>::try_from(files) {
+        Ok([old, new]) => Ok((format, old, new)),
+        Err(files) => Err(format!("expected two files, got {}", files.len())),
+    }
+}
+
+/// load a model from a file
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_file(file_name: &str) -> Result<mdf::Mdf, String> {
+    let contents = std::fs::read(file_name).map_err(|e| format!("Error while reading {}: {}", file_name, e))?;
+    mdf::Mdf::from_slice(&contents).map_err(|e| format!("Error while loading {}: {}", file_name, e))
+}
+
+/// run the comparison from the command line arguments. Returns the report and true if the
+/// models differ
+#[cfg(not(target_arch = "wasm32"))]
+pub fn command_line(args: &[String]) -> Result<(String, bool), String> {
+    let (format, old_file, new_file) = parse_arguments(args)?;
+    let diff = ModelDiff::new(&load_file(&old_file)?, &load_file(&new_file)?);
+    Ok((diff.report(format), !diff.is_empty()))
+}
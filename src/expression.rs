@@ -0,0 +1,186 @@
+//! evaluation of the simple arithmetic expressions used for the model numeric values.
+//!
+//! An expression can use integer literals (decimal, 0x hexadecimal, 0b binary, 0d decimal), the
+//! project parameters, the `+ - * / %` operators, parentheses and the `clog2(x)` function giving
+//! the number of bits needed to hold x values.
+
+use std::collections::HashMap;
+
+/// element of an expression
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Number(u128),
+    Name(String),
+    Operator(char),
+    Open,
+    Close,
+}
+
+/// tokens as shown in the error messages
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(value) => write!(f, "number {}", value),
+            Token::Name(name) => write!(f, "name '{}'", name),
+            Token::Operator(operator) => write!(f, "'{}'", operator),
+            Token::Open => write!(f, "'('"),
+            Token::Close => write!(f, "')'"),
+        }
+    }
+}
+
+/// split an expression into tokens
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            // a number or a name, read the whole word
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_ascii_alphanumeric() || c == '_' {
+                    word.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            if word.starts_with(|c: char| c.is_ascii_digit()) {
+                let value = word
+                    .parse::<crate::utils::VectorValue>()
+                    .map_err(|_| format!("invalid number '{}'", word))?;
+                tokens.push(Token::Number(value.value));
+            } else {
+                tokens.push(Token::Name(word));
+            }
+        } else {
+            chars.next();
+            tokens.push(match c {
+                '+' | '-' | '*' | '/' | '%' => Token::Operator(c),
+                '(' => Token::Open,
+                ')' => Token::Close,
+                _ => return Err(format!("unexpected character '{}'", c)),
+            });
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// recursive descent parser, evaluating the expression while it is read
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    parameters: &'a HashMap<String, u128>,
+}
+
+impl Parser<'_> {
+    /// next token, without consuming it
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    /// consume the next token
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    /// sum := product (('+' | '-') product)*
+    fn sum(&mut self) -> Result<u128, String> {
+        let mut value = self.product()?;
+        while let Some(Token::Operator(operator)) = self.peek().cloned() {
+            let result = match operator {
+                '+' => {
+                    self.next();
+                    value.checked_add(self.product()?)
+                }
+                '-' => {
+                    self.next();
+                    value.checked_sub(self.product()?)
+                }
+                _ => break,
+            };
+            value = result.ok_or("the result is out of range")?;
+        }
+        Ok(value)
+    }
+
+    /// product := atom (('*' | '/' | '%') atom)*
+    fn product(&mut self) -> Result<u128, String> {
+        let mut value = self.atom()?;
+        while let Some(Token::Operator(operator)) = self.peek().cloned() {
+            let result = match operator {
+                '*' => {
+                    self.next();
+                    value.checked_mul(self.atom()?).ok_or("the result is out of range")?
+                }
+                '/' => {
+                    self.next();
+                    value.checked_div(self.atom()?).ok_or("division by zero")?
+                }
+                '%' => {
+                    self.next();
+                    value.checked_rem(self.atom()?).ok_or("division by zero")?
+                }
+                _ => break,
+            };
+            value = result;
+        }
+        Ok(value)
+    }
+
+    /// atom := number | parameter | 'clog2' '(' sum ')' | '(' sum ')'
+    fn atom(&mut self) -> Result<u128, String> {
+        match self.next() {
+            Some(Token::Number(value)) => Ok(value),
+            Some(Token::Name(name)) if name == "clog2" => {
+                let value = self.parenthesis()?;
+                Ok((u128::BITS - value.saturating_sub(1).leading_zeros()) as u128)
+            }
+            Some(Token::Name(name)) => self
+                .parameters
+                .get(&name)
+                .copied()
+                .ok_or(format!("unknown parameter '{}'", name)),
+            Some(Token::Open) => {
+                self.position -= 1;
+                self.parenthesis()
+            }
+            Some(token) => Err(format!("unexpected {}", token)),
+            None => Err("unexpected end of expression".to_owned()),
+        }
+    }
+
+    /// '(' sum ')'
+    fn parenthesis(&mut self) -> Result<u128, String> {
+        if self.next() != Some(Token::Open) {
+            return Err("missing '('".to_owned());
+        }
+        let value = self.sum()?;
+        if self.next() != Some(Token::Close) {
+            return Err("missing ')'".to_owned());
+        }
+        Ok(value)
+    }
+}
+
+/// evaluate an expression, using the given parameter values
+pub fn evaluate(expression: &str, parameters: &HashMap<String, u128>) -> Result<u128, String> {
+    let mut parser = Parser {
+        tokens: tokenize(expression).map_err(|e| format!("expression '{}': {}", expression, e))?,
+        position: 0,
+        parameters,
+    };
+    let value = parser
+        .sum()
+        .map_err(|e| format!("expression '{}': {}", expression, e))?;
+    if parser.position < parser.tokens.len() {
+        return Err(format!("expression '{}': unexpected {}", expression, parser.tokens[parser.position]));
+    }
+    Ok(value)
+}
@@ -16,6 +16,19 @@ use strum_macros;
 pub struct Mdf {
     /// file name
     pub name: String,
+    /// project parameters, that the expressions of the numeric values can use
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<Parameter>,
+    /// product variants. Interfaces, registers and fields can be limited to some of them
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<Variant>,
+    /// product variant to generate, saved with the project. None generates the full register map
+    #[serde(rename = "activeVariant")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_variant: Option<String>,
     /// list of interfaces
     pub interfaces: Vec<Interface>,
 }
@@ -25,11 +38,49 @@ impl Default for Mdf {
     fn default() -> Mdf {
         Mdf {
             name: "New Project".to_owned(),
+            parameters: Vec::new(),
+            variants: Vec::new(),
+            active_variant: None,
             interfaces: Vec::new(),
         }
     }
 }
 
+impl Mdf {
+    /// load a model from the contents of a file. Used by every place opening a file, so that they
+    /// all accept the same files
+    pub fn from_slice(contents: &[u8]) -> Result<Mdf, String> {
+        serde_json::from_slice(contents).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+/// product variant, such as a specific SKU, built from the same register map
+pub struct Variant {
+    /// variant name
+    pub name: String,
+    /// description of the variant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+/// named project parameter, such as a number of channels
+pub struct Parameter {
+    /// parameter name, used in the expressions
+    pub name: String,
+    /// value, as an expression that can use the parameters defined before this one
+    pub value: String,
+    /// description of the parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Vec<String>>,
+    /// if true, the parameter is exported as a constant in the generated code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export: Option<bool>,
+}
+
 #[derive(
     Serialize,
     Deserialize,
@@ -52,6 +103,77 @@ pub enum InterfaceType {
     AXI4Light,
 }
 
+#[derive(
+    Serialize,
+    Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "lowercase")]
+/// unit of the addresses on the bus
+pub enum AddressingMode {
+    /// each address selects a byte, and a bus word uses several addresses
+    #[strum(serialize = "byte")]
+    Byte,
+    /// each address selects a whole bus word
+    #[strum(serialize = "word")]
+    Word,
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "camelCase")]
+/// order in which the automatic addresses are allocated
+pub enum AllocationOrder {
+    /// the registers outside of groups first, then each group as a block
+    #[strum(serialize = "by group")]
+    ByGroup,
+    /// the registers and groups in the order they are declared. A group is placed with its first register
+    #[strum(serialize = "declaration order")]
+    Declaration,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// range of addresses that the automatic allocation leaves free, for future use
+pub struct ReservedRange {
+    /// name of the range, for the documentation
+    pub name: String,
+    /// first address of the range
+    pub start: utils::VectorValue,
+    /// last address of the range
+    pub end: utils::VectorValue,
+}
+
+impl ReservedRange {
+    /// create a new range, reserving the first address
+    pub fn new() -> ReservedRange {
+        ReservedRange {
+            name: String::new(),
+            start: utils::VectorValue::new(),
+            end: utils::VectorValue::new(),
+        }
+    }
+}
+
+impl Default for ReservedRange {
+    fn default() -> Self {
+        ReservedRange::new()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 /// structure representing an interface in the model
@@ -72,9 +194,53 @@ pub struct Interface {
     /// if empty, automatically caculated from the widest register
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data_width: Option<u32>,
+    /// unit of the addresses. None means byte addressing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addressing: Option<AddressingMode>,
+    /// expression giving the width of the data bus. Replaces data_width when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_width_expr: Option<String>,
+    /// if true, the interface has byte enables (write strobes) and partial writes only update the addressed bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_byte_enables: Option<bool>,
+    /// if true, the pif runs on its own bus clock and the signals to and from the core are synchronized
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub separate_clock: Option<bool>,
+    /// number of synchronizer stages when the clock is separate. None means 2
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_stages: Option<u32>,
     /// list of registers
     #[serde(default)]
     pub registers: Vec<Register>,
+    /// list of register groups (register files). Registers refer to their group by name
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<RegisterGroup>,
+    /// list of memory windows, implemented in the core
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub memories: Vec<Memory>,
+    /// list of interrupt blocks. Each block generates its own registers after the ones in the list above
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub interrupt_blocks: Vec<InterruptBlock>,
+    /// names of the variants including the interface. Empty means all variants
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<String>,
+    /// order of the automatic address allocation. None means by group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation_order: Option<AllocationOrder>,
+    /// if true, the automatic address of a register array is aligned on its size, rounded up to a power of two
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_arrays: Option<bool>,
+    /// if true, the automatic addresses are allocated after the fixed ones, leaving the gaps between them free
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_gaps: Option<bool>,
+    /// address ranges that the automatic allocation doesn't use
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reserved: Vec<ReservedRange>,
 }
 
 impl Interface {
@@ -85,8 +251,21 @@ impl Interface {
             description: None,
             interface_type: InterfaceType::SBI,
             registers: Vec::<Register>::new(),
+            use_byte_enables: None,
+            separate_clock: None,
+            sync_stages: None,
+            groups: Vec::new(),
+            memories: Vec::new(),
+            interrupt_blocks: Vec::new(),
+            variants: Vec::new(),
+            allocation_order: None,
+            align_arrays: None,
+            keep_gaps: None,
+            reserved: Vec::new(),
             address_width: None,
             data_width: None,
+            addressing: None,
+            data_width_expr: None,
         }
     }
 }
@@ -103,8 +282,21 @@ impl Default for Interface {
 pub struct Register {
     /// register name
     pub name: String,
-    /// register address
+    /// register address. Relative to the group base address if the register is in a group
     pub address: Address,
+    /// expression giving the register address. Replaces the address value when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_expr: Option<String>,
+    /// expression giving the number of elements in the outer array dimension. Replaces the stride count
+    /// when present, and makes the register an array if it isn't one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count_expr: Option<String>,
+    /// name of the group the register belongs to, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// if true, the address is kept when the addresses are unassigned, so that it never moves
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
     /// quick description of register
     #[serde(skip_serializing_if = "Option::is_none")]
     pub summary: Option<Vec<String>>,
@@ -114,6 +306,9 @@ pub struct Register {
     /// register width. Can be None if fields are used
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<u32>,
+    /// expression giving the register width. Replaces width when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width_expr: Option<String>,
     /// read/write access type for register. Can be None if fields are used and every field has an access type
     #[serde(skip_serializing_if = "Option::is_none")]
     pub access: Option<AccessType>,
@@ -130,10 +325,29 @@ pub struct Register {
     #[serde(default)]
     #[serde(skip_serializing_if = "CoreSignalProperties::must_skip")]
     pub core_signal_properties: CoreSignalProperties,
+    /// hardware access properties, for registers in the pif that the core can also update
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HardwareAccessProperties::must_skip")]
+    pub hardware_access: HardwareAccessProperties,
+    /// if present, the register is mapped to a FIFO in the core. Only for registers without fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fifo: Option<FifoProperties>,
+    /// if present, the register is double buffered in the pif: the interface writes a shadow copy, and a
+    /// commit event copies it to the active copy seen by the core
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<ShadowProperties>,
+    /// list of named values. Must be empty if fields are used
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub enum_values: Vec<EnumValue>,
     /// list of fields elements
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<Field>,
+    /// names of the variants including the register. Empty means all variants
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<String>,
 }
 
 impl Register {
@@ -142,9 +356,14 @@ impl Register {
         Register {
             name: String::new(),
             address: Default::default(),
+            address_expr: None,
+            count_expr: None,
+            group: None,
+            pinned: None,
             summary: None,
             description: None,
             width: Some(32),
+            width_expr: None,
             access: Some(AccessType::RW),
             signal: Some(utils::SignalType::StdLogicVector),
             reset: Some(utils::VectorValue::new()),
@@ -152,8 +371,14 @@ impl Register {
             core_signal_properties: CoreSignalProperties {
                 use_read_enable: None,
                 use_write_enable: None,
+                use_read_handshake: None,
             },
+            hardware_access: Default::default(),
+            fifo: None,
+            shadow: None,
+            enum_values: Vec::new(),
             fields: Vec::new(),
+            variants: Vec::new(),
         }
     }
 }
@@ -178,8 +403,11 @@ pub struct Address {
 pub struct AddressStride {
     /// number of addresses
     pub count: utils::VectorValue,
-    /// increment between two addresses. If None, use the register size as increment
+    /// increment between two addresses. If None, use the register size as increment, or the
+    /// size of the inner dimension if there is one
     pub increment: Option<utils::VectorValue>,
+    /// inner dimension, for multi-dimensional arrays
+    pub inner: Option<Box<AddressStride>>,
 }
 
 impl Address {
@@ -196,6 +424,7 @@ impl AddressStride {
         AddressStride {
             count: Default::default(),
             increment: None,
+            inner: None,
         }
     }
 }
@@ -230,6 +459,42 @@ pub enum AccessType {
     RO,
     /// Write only
     WO,
+    /// Read only, cleared when read
+    RC,
+    /// Read, write 1 to clear bits
+    W1C,
+    /// Read, write 1 to set bits
+    W1S,
+    /// Read, write 0 to clear bits
+    W0C,
+    /// Write only, self clearing pulse
+    WP,
+}
+
+impl AccessType {
+    /// true if the master can read the contents
+    pub fn is_read(&self) -> bool {
+        !matches!(self, AccessType::WO | AccessType::WP)
+    }
+
+    /// true if the master can modify the contents with a write
+    pub fn is_write(&self) -> bool {
+        !matches!(self, AccessType::RO | AccessType::RC)
+    }
+
+    /// short description of the access type, for the documentation
+    pub fn description(&self) -> &'static str {
+        match self {
+            AccessType::RW => "read/write",
+            AccessType::RO => "read only",
+            AccessType::WO => "write only",
+            AccessType::RC => "read only, cleared when read",
+            AccessType::W1C => "read, writing a 1 clears the bit, writing a 0 has no effect",
+            AccessType::W1S => "read, writing a 1 sets the bit, writing a 0 has no effect",
+            AccessType::W0C => "read, writing a 0 clears the bit, writing a 1 has no effect",
+            AccessType::WP => "write only, the written value is only held for one clock cycle",
+        }
+    }
 }
 
 #[derive(
@@ -261,22 +526,154 @@ pub struct CoreSignalProperties {
     /// generate a signal to indicate to the core when the signal is written
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_write_enable: Option<bool>,
+    /// on a read, the pif sends a read request to the core and waits for its read valid before
+    /// answering the interface
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_read_handshake: Option<bool>,
 }
 
 impl CoreSignalProperties {
     pub fn must_skip(&self) -> bool {
-        self.use_read_enable.is_none() && self.use_write_enable.is_none()
+        self.use_read_enable.is_none() && self.use_write_enable.is_none() && self.use_read_handshake.is_none()
     }
 }
 
+#[derive(
+    Serialize,
+    Deserialize,
+    strum_macros::ToString,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "lowercase")]
+/// which update wins when software and hardware modify a signal in the same clock cycle
+pub enum HardwarePriority {
+    /// the core update has priority
+    #[strum(serialize = "hardware")]
+    Hardware,
+    /// the interface update has priority
+    #[strum(serialize = "software")]
+    Software,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// extra properties for signals located in pif that the core can also update
+pub struct HardwareAccessProperties {
+    /// the core can set bits in the signal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_set: Option<bool>,
+    /// the core can clear bits in the signal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_clear: Option<bool>,
+    /// the core can write a new value, with a valid strobe
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_write: Option<bool>,
+    /// priority when the core and the interface modify the signal in the same clock cycle. None means hardware
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<HardwarePriority>,
+}
+
+impl HardwareAccessProperties {
+    pub fn must_skip(&self) -> bool {
+        self.use_set.is_none() && self.use_clear.is_none() && self.use_write.is_none() && self.priority.is_none()
+    }
+
+    /// true if the core can update the signal in any way
+    pub fn is_used(&self) -> bool {
+        self.use_set.unwrap_or(false) || self.use_clear.unwrap_or(false) || self.use_write.unwrap_or(false)
+    }
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    strum_macros::ToString,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "lowercase")]
+/// what happens when a FIFO register is written while the FIFO is full, or read while it is empty
+pub enum FifoBlockedAccess {
+    /// the interface access waits until the FIFO is ready
+    #[strum(serialize = "stall")]
+    Stall,
+    /// the access is dropped and an overflow or underflow strobe is sent to the core
+    #[strum(serialize = "flag")]
+    Flag,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// properties for a register mapped to a FIFO in the core. A write pushes data, a read pops it
+pub struct FifoProperties {
+    /// behaviour when writing a full FIFO or reading an empty one. None means stall
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_access: Option<FifoBlockedAccess>,
+    /// the core provides the FIFO level, which is readable in the documentation and the generated records
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_level: Option<bool>,
+    /// width of the level signal in bits. None means 8 bits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level_width: Option<u32>,
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    strum_macros::ToString,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "lowercase")]
+/// event copying the shadow copy of a register to its active copy
+pub enum ShadowCommit {
+    /// a write to another register of the interface
+    #[strum(serialize = "register write")]
+    Register,
+    /// a strobe from the core
+    #[strum(serialize = "core strobe")]
+    Strobe,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// properties for a shadowed (double buffered) register
+pub struct ShadowProperties {
+    /// event committing the shadow copy. None means a strobe from the core
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<ShadowCommit>,
+    /// name of the register whose write commits the shadow copy. Only used with a register commit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_register: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 /// structure representing a field element in a register
 pub struct Field {
     /// field name
     pub name: String,
-    /// field position
+    /// field position. For an array, position of the first element
     pub position: FieldPosition,
+    /// expression giving the field lsb. Replaces the position lsb when present, keeping the field width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lsb_expr: Option<String>,
+    /// expression giving the field width. Replaces the position width when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width_expr: Option<String>,
+    /// if present, the field is repeated several times in the register
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub array: Option<FieldArray>,
     /// description of the register field
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<Vec<String>>,
@@ -292,6 +689,18 @@ pub struct Field {
     /// signal properties
     #[serde(default)]
     pub core_signal_properties: CoreSignalProperties,
+    /// hardware access properties, for fields in the pif that the core can also update
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HardwareAccessProperties::must_skip")]
+    pub hardware_access: HardwareAccessProperties,
+    /// list of named values
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub enum_values: Vec<EnumValue>,
+    /// names of the variants including the field. Empty means all variants
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<String>,
 }
 
 impl Field {
@@ -300,6 +709,9 @@ impl Field {
         Field {
             name: String::new(),
             position: FieldPosition::Single(0),
+            lsb_expr: None,
+            width_expr: None,
+            array: None,
             description: None,
             access: AccessType::RW,
             signal: utils::SignalType::StdLogic,
@@ -308,7 +720,11 @@ impl Field {
             core_signal_properties: CoreSignalProperties {
                 use_read_enable: None,
                 use_write_enable: None,
+                use_read_handshake: None,
             },
+            hardware_access: Default::default(),
+            enum_values: Vec::new(),
+            variants: Vec::new(),
         }
     }
 }
@@ -319,6 +735,212 @@ impl Default for Field {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// structure representing a group of registers (register file) in an interface. The addresses of the
+/// registers in the group are relative to the group base address, and the group can be repeated
+pub struct RegisterGroup {
+    /// group name
+    pub name: String,
+    /// description of the group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Vec<String>>,
+    /// base address of the group. If None, automatic address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<utils::VectorValue>,
+    /// if true, the base address is kept when the addresses are unassigned, so that it never moves
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
+    /// number of times the group is repeated. If None, the group is not repeated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<utils::VectorValue>,
+    /// expression giving the number of repetitions. Replaces count when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count_expr: Option<String>,
+    /// address increment between two repetitions. If None, the repetitions are next to each other
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub increment: Option<utils::VectorValue>,
+}
+
+impl RegisterGroup {
+    /// create a new group, with an automatic address and no repetition
+    pub fn new() -> RegisterGroup {
+        RegisterGroup {
+            name: String::new(),
+            description: None,
+            base: None,
+            pinned: None,
+            count: None,
+            count_expr: None,
+            increment: None,
+        }
+    }
+}
+
+impl Default for RegisterGroup {
+    fn default() -> Self {
+        RegisterGroup::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// structure representing a memory window in an interface. It reserves a contiguous address range, one
+/// interface word per memory word, and is implemented in the core (usually a RAM)
+pub struct Memory {
+    /// memory name
+    pub name: String,
+    /// description of the memory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Vec<String>>,
+    /// base address. If None, automatic address. The address is always aligned on the memory size, rounded
+    /// up to a power of two
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<utils::VectorValue>,
+    /// if true, the address is kept when the addresses are unassigned, so that it never moves
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
+    /// number of words in the memory
+    pub depth: u32,
+    /// expression giving the number of words. Replaces depth when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_expr: Option<String>,
+    /// width of a word in bits. If None, the interface data width is used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// memory access from the interface, only RW, RO and WO are allowed
+    pub access: AccessType,
+    /// number of clock cycles between a read enable and the read data. If None, 1 cycle
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_latency: Option<u32>,
+}
+
+impl Memory {
+    /// create a new read/write memory with 256 words and an automatic address
+    pub fn new() -> Memory {
+        Memory {
+            name: String::new(),
+            description: None,
+            address: None,
+            pinned: None,
+            depth: 256,
+            depth_expr: None,
+            width: None,
+            access: AccessType::RW,
+            read_latency: None,
+        }
+    }
+}
+
+impl Default for Memory {
+    fn default() -> Self {
+        Memory::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// structure representing an interrupt controller in an interface. It generates status (W1C), enable, pending
+/// and optionally force registers, with one bit per source, and an interrupt request output port
+pub struct InterruptBlock {
+    /// block name, used as a prefix for the generated registers
+    pub name: String,
+    /// description of the interrupt block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Vec<String>>,
+    /// list of interrupt sources, the first one is bit 0
+    #[serde(default)]
+    pub sources: Vec<InterruptSource>,
+    /// generate a register to force interrupts from software
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_force: Option<bool>,
+}
+
+impl InterruptBlock {
+    /// create a new interrupt block, without any sources
+    pub fn new() -> InterruptBlock {
+        InterruptBlock {
+            name: String::new(),
+            description: None,
+            sources: Vec::new(),
+            use_force: None,
+        }
+    }
+}
+
+impl Default for InterruptBlock {
+    fn default() -> Self {
+        InterruptBlock::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+/// structure representing an interrupt source in an interrupt block
+pub struct InterruptSource {
+    /// source name, used as the field name in the generated registers
+    pub name: String,
+    /// description of the interrupt source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// structure representing a named value for a field or a register
+pub struct EnumValue {
+    /// value name
+    pub name: String,
+    /// value itself
+    pub value: utils::VectorValue,
+    /// description of the value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Vec<String>>,
+}
+
+impl EnumValue {
+    /// create a new named value, with value 0
+    pub fn new() -> EnumValue {
+        EnumValue {
+            name: String::new(),
+            value: utils::VectorValue::new(),
+            description: None,
+        }
+    }
+}
+
+impl Default for EnumValue {
+    fn default() -> Self {
+        EnumValue::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+/// structure to represent a field repeated several times within a register
+pub struct FieldArray {
+    /// number of elements
+    pub count: u32,
+    /// number of bits between the lsb of two consecutive elements. If None, use the field width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stride: Option<u32>,
+}
+
+impl FieldArray {
+    pub fn new() -> Self {
+        FieldArray {
+            count: 2,
+            stride: None,
+        }
+    }
+}
+
+impl Default for FieldArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 /// diferent ways of defining a field position
 pub enum FieldPosition {
@@ -335,19 +957,23 @@ impl fmt::Display for Address {
             None => "auto".to_string(),
             Some(address_value) => address_value.to_string(),
         };
-        match &self.stride {
-            None => {
-                write!(f, "{}", value_str)
-            }
-            Some(stride) => match stride.increment {
+        write!(f, "{}", value_str)?;
+
+        // each dimension is added, from the outer to the inner one
+        let mut dimension = self.stride.as_ref();
+        while let Some(stride) = dimension {
+            match stride.increment {
                 None => {
-                    write!(f, "{}:stride:{}", value_str, stride.count)
+                    write!(f, ":stride:{}", stride.count)?;
                 }
                 Some(increment) => {
-                    write!(f, "{}:stride:{}:{}", value_str, stride.count, increment)
+                    write!(f, ":stride:{}:{}", stride.count, increment)?;
                 }
-            },
+            }
+            dimension = stride.inner.as_deref();
         }
+
+        Ok(())
     }
 }
 
@@ -358,24 +984,28 @@ impl Address {
             None => "auto".to_string(),
             Some(address_value) => address_value.to_string(),
         };
-        match &self.stride {
-            None => value_str,
-            Some(stride) => {
-                let count_minus_one = utils::VectorValue {
-                    value: stride.count.value - 1,
-                    radix: stride.count.radix,
-                };
-
-                match stride.increment {
-                    None => {
-                        format!("{} + (0..{})", value_str, count_minus_one)
-                    }
-                    Some(increment) => {
-                        format!("{} + (0..{})*{}", value_str, count_minus_one, increment)
-                    }
+        let mut result = value_str;
+
+        // each dimension is added, from the outer to the inner one
+        let mut dimension = self.stride.as_ref();
+        while let Some(stride) = dimension {
+            let count_minus_one = utils::VectorValue {
+                value: stride.count.value.saturating_sub(1),
+                radix: stride.count.radix,
+            };
+
+            match stride.increment {
+                None => {
+                    result = format!("{} + (0..{})", result, count_minus_one);
+                }
+                Some(increment) => {
+                    result = format!("{} + (0..{})*{}", result, count_minus_one, increment);
                 }
             }
+            dimension = stride.inner.as_deref();
         }
+
+        result
     }
 }
 
@@ -392,45 +1022,44 @@ impl Serialize for Address {
 impl std::str::FromStr for Address {
     type Err = &'static str;
 
-    /// conversion from string to address, using the format described in the mdf specification for stride addresses
+    /// conversion from string to address, using the format described in the mdf specification for stride addresses.
+    /// Several strides can follow each other for multi-dimensional arrays, the first one being the outer dimension
     fn from_str(s: &str) -> Result<Self, &'static str> {
         let elements: Vec<&str> = s.split(':').collect();
-        let (value_str, stride) = match elements.len() {
-            1 => (s, None),
-            3 => {
-                if elements[1] == "stride" {
-                    (
-                        elements[0],
-                        Some(AddressStride {
-                            count: utils::VectorValue::from_str(elements[2])
-                                .map_err(|_| "could not parse stride count")?,
-                            increment: None,
-                        }),
-                    )
-                } else {
-                    return Err("'stride' keyword expected");
-                }
+        let value_str = elements[0];
+
+        // read all the dimensions, each one is "stride:count" with an optional ":increment"
+        let mut dimensions: Vec<AddressStride> = Vec::new();
+        let mut index = 1;
+        while index < elements.len() {
+            if elements[index] != "stride" {
+                return Err("'stride' keyword expected");
             }
-            4 => {
-                if elements[1] == "stride" {
-                    (
-                        elements[0],
-                        Some(AddressStride {
-                            count: utils::VectorValue::from_str(elements[2])
-                                .map_err(|_| "could not parse stride count")?,
-                            increment: Some(
-                                utils::VectorValue::from_str(elements[3])
-                                    .map_err(|_| "could not parse stride increment")?,
-                            ),
-                        }),
-                    )
-                } else {
-                    return Err("'stride' keyword expected");
-                }
+            if index + 1 >= elements.len() {
+                return Err("bad number of arguments between ':'");
             }
+            let count = utils::VectorValue::from_str(elements[index + 1])
+                .map_err(|_| "could not parse stride count")?;
+            let increment = match elements.get(index + 2) {
+                Some(&"stride") | None => None,
+                Some(increment_str) => Some(
+                    utils::VectorValue::from_str(increment_str)
+                        .map_err(|_| "could not parse stride increment")?,
+                ),
+            };
+            index += if increment.is_some() { 3 } else { 2 };
+            dimensions.push(AddressStride {
+                count,
+                increment,
+                inner: None,
+            });
+        }
 
-            _ => return Err("bad number of arguments between ':'"),
-        };
+        // nest the dimensions, starting from the inner one
+        let stride = dimensions.into_iter().rev().fold(None, |inner, mut dimension| {
+            dimension.inner = inner.map(Box::new);
+            Some(dimension)
+        });
         let address_value = if value_str == "auto" {
             None
         } else {
@@ -80,7 +80,7 @@ pub fn Open(app_data: Signal<HdlWizardApp>, key_action : Signal<Option<KeyAction
                     let (file_name, file_folder) = file_name(&file);
 
                     // load the file
-                    open_status.set(Some((file_name, file_folder, serde_json::from_slice::<mdf::Mdf>(&file.read().await).map_err(|e| e.to_string()))));
+                    open_status.set(Some((file_name, file_folder, mdf::Mdf::from_slice(&file.read().await))));
                 }
             }
         });
@@ -25,6 +25,9 @@ impl fmt::Debug for GenError {
                 PageType::Interface(int) => format!("interface({})", int),
                 PageType::Register(int,reg, field) => format!("interface({}), register({}), field({:?})", int, reg, field),
                 PageType::Preview => "preview".to_owned(),
+                PageType::Validation => "validation".to_owned(),
+                PageType::AddressMap(int) => format!("address map({})", int),
+                PageType::Compare => "compare".to_owned(),
                 PageType::ChangeRegisterField(_,_,_) => String::new(),
                 PageType::Settings(_) => "settings".to_owned(),
             },
@@ -7,15 +7,16 @@
 
 use serde::Serialize;
 use crate::file_formats::mdf;
-use crate::settings::Settings;
+use crate::settings::{Settings, VhdlRevision, ResetPolarity, ResetType};
 use std::collections::HashMap;
 use std::error::Error;
-use super::tokenlist::{TokenList, to_vhdl_token};
+use super::tokenlist::{TokenList, to_vhdl_token, to_camel_case};
 use crate::utils;
-use crate::page::PageType;  
+use crate::page::{PageType, SettingsPageType};  
 use crate::generate::generror::GenError;
 use super::signal_list;
 use tera::Tera;
+use strum::IntoEnumIterator;
 
 /// Project model for generation
 #[derive(Serialize)]
@@ -34,21 +35,125 @@ pub struct GenModel {
     pub pkg_name : String,
     /// if true, only has one interface
     pub single_interface : bool,
+    /// if true, generate VHDL-2008 code. If false, generate VHDL-93 code. The literals and the conversions
+    /// between the fields and the bus data in GenField follow it
+    pub vhdl_2008 : bool,
+    /// name of the package function converting a boolean to std_logic, used by the VHDL-93 conversions where
+    /// VHDL-2008 has a conditional expression (only valid if vhdl_2008 = false)
+    pub bool_to_std_logic_name : String,
+    /// VHDL revision (as a string for documentation)
+    pub vhdl_revision_pretty : String,
+    /// clock signal name
+    pub clock_name : String,
+    /// reset signal name
+    pub reset_name : String,
+    /// reset active level, including quotes
+    pub reset_active_level : String,
+    /// if true, reset is active high
+    pub reset_active_high : bool,
+    /// if true, reset is asynchronous
+    pub reset_is_async : bool,
+    /// reset description for the documentation
+    pub reset_pretty : String,
+    /// project parameters, with their evaluated value
+    pub parameters : Vec<GenParameter>,
+    /// product variants defined in the project
+    pub variants : Vec<GenVariant>,
+    /// variant selected for the generation. Empty if the full register map is generated
+    pub variant : String,
+    /// if true, the project has variants and the full map is generated, so the documentation lists the variants of each element
+    pub use_variants : bool,
     /// list of interfaces
 //    #[serde(skip)]
     pub interfaces : Vec<GenInterface>,
+    /// access types used in the project, with their description
+    pub access_types : Vec<GenAccessType>,
+}
+
+/// project parameter, for the documentation and the generated constants
+#[derive(Serialize)]
+pub struct GenParameter {
+    /// parameter name, as used in the expressions
+    pub name : String,
+    /// expression defining the parameter
+    pub expression : String,
+    /// evaluated value (decimal)
+    pub value : String,
+    /// evaluated value (hexadecimal) excluding quotes
+    pub value_hex : String,
+    /// name of the VHDL constant
+    pub const_name : String,
+    /// name of the C define
+    pub c_name : String,
+    /// parameter description
+    pub description : String,
+    /// if true, the parameter is exported as a constant in the generated code
+    pub export : bool,
+}
+
+/// product variant, for the documentation
+#[derive(Serialize)]
+pub struct GenVariant {
+    /// variant name
+    pub name : String,
+    /// variant description
+    pub description : String,
+}
+
+/// list of variants including an element, for the documentation
+fn variants_pretty(variants: &[String]) -> String {
+    if variants.is_empty() {
+        "all".to_owned()
+    } else {
+        variants.join(", ")
+    }
+}
+
+/// access type description, for the documentation
+#[derive(Serialize)]
+pub struct GenAccessType {
+    /// access type name, as used in the rw_mode fields
+    pub name : String,
+    /// description of the access type behaviour
+    pub description : String,
 }
 
 impl GenModel {
-    /// take a Mdf model and convert it to a GenModel
-    pub fn from_model(model: &mdf::Mdf, settings: &Settings, templates: &Tera) -> Result<Self, Box<dyn Error>> {
+    /// take a Mdf model and convert it to a GenModel. The variant, if given, replaces the generated variant saved
+    /// in the project, so that each variant can be generated from the same file. An empty one generates them all
+    pub fn from_model(model: &mdf::Mdf, settings: &Settings, templates: &Tera, variant: Option<&str>) -> Result<Self, Box<dyn Error>> {
         let mut token_list = TokenList::new();
+        // the package function for the VHDL-93 boolean conversions has a fixed name. The list is still empty
+        let _ = token_list.add_token(BOOL_TO_STD_LOGIC);
+
+        // evaluate the expressions on a copy of the model, the generation only uses their values
+        let mut model = model.clone();
+        model.check_variants().map_err(|e| GenError::new(&PageType::Project, &e))?;
+        let variants = model.variants.iter().map(|variant| GenVariant {
+            name: variant.name.clone(),
+            description: utils::opt_vec_str_to_textarea(&variant.description),
+        }).collect::<Vec<GenVariant>>();
+        // only keep the interfaces of the selected variant. The registers and fields are selected by each interface
+        // after assigning the addresses, so that they are the same in all the variants
+        let selected = model.generated_variant(variant);
+        if let Some(variant) = &selected {
+            if !model.variants.iter().any(|known| &known.name == variant) {
+                Err(GenError::new(&PageType::Project, &format!("Unknown variant {}", variant)))?
+            }
+        }
+        let generated_interfaces: Vec<usize> = (0..model.interfaces.len())
+            .filter(|n| selected.as_ref().map_or(true, |variant| model.interfaces[*n].in_variant(variant)))
+            .collect();
+        let variant = selected.clone().unwrap_or_default();
+        let use_variants = variant.is_empty() && !variants.is_empty();
+        let parameter_values = model.parameter_values().map_err(|e| GenError::new(&PageType::Project, &e))?;
+        model.evaluate_expressions().map_err(|e| GenError::new(&PageType::Project, &e))?;
 
         let name = model.name.clone();
 
         let token_name = to_vhdl_token(&name);
 
-        let single_interface = model.interfaces.len() == 1;
+        let single_interface = generated_interfaces.len() == 1;
 
         let mut context = tera::Context::new();
         context.insert("project", &token_name);
@@ -58,13 +163,51 @@ impl GenModel {
         let core_instance = token_list.generate_token(&templates.render("gm_core_instance", &context)?);
         let pkg_name = token_list.generate_token(&templates.render("gm_pkg_name", &context)?);
 
+        // language and coding style options
+        let vhdl_2008 = settings.vhdl_revision == VhdlRevision::Vhdl2008;
+        let vhdl_revision_pretty = settings.vhdl_revision.to_string();
+        let clock_name = settings_token(&settings.clock_name, "clock", &mut token_list)?;
+        let reset_name = settings_token(&settings.reset_name, "reset", &mut token_list)?;
+        let reset_active_high = settings.reset_polarity == ResetPolarity::ActiveHigh;
+        let reset_active_level = if reset_active_high { "'1'" } else { "'0'" }.to_owned();
+        let reset_is_async = settings.reset_type == ResetType::Asynchronous;
+        let reset_pretty = format!("{}, {}", settings.reset_polarity.to_string(), settings.reset_type.to_string());
+
+        // project parameters
+        let parameters = model.parameters.iter().map(|parameter| {
+            let value = parameter_values[&parameter.name];
+            let mut context = context.clone();
+            context.insert("parameter", &to_vhdl_token(&parameter.name));
+            let const_name = token_list.generate_token(&templates.render("gm_parameter_const_name", &context)?);
+            Ok(GenParameter {
+                name: parameter.name.clone(),
+                expression: parameter.value.clone(),
+                value: value.to_string(),
+                value_hex: format!("{:x}", value),
+                c_name: const_name.to_uppercase(),
+                const_name,
+                description: utils::opt_vec_str_to_textarea(&parameter.description),
+                export: parameter.export.unwrap_or(false),
+            })
+        }).collect::<Result<Vec<GenParameter>, Box<dyn Error>>>()?;
+
         // apply a conversion to each interface
-        let interfaces = model.interfaces.iter().enumerate().map(
-            |(n, interface)| GenInterface::from_interface(
-                interface, PageType::Interface(n), settings, templates, &token_name, 
-                &single_interface, &mut token_list)
+        let interfaces = generated_interfaces.iter().map(
+            |&n| GenInterface::from_interface(
+                &model.interfaces[n], PageType::Interface(n), settings, templates, &token_name, 
+                &single_interface, selected.as_deref(), &mut token_list)
         ).collect::<Result<Vec<GenInterface>, Box<dyn Error>>>()?;
-    
+
+        // list the access types that are used somewhere in the project
+        let access_types = mdf::AccessType::iter().filter(|access| {
+            let access_name = access.to_string();
+            interfaces.iter().any(|interface| interface.registers.iter().any(
+                |register| register.fields.iter().any(|field| field.rw_mode == access_name)))
+        }).map(|access| GenAccessType {
+            name: access.to_string(),
+            description: access.description().to_owned()
+        }).collect();
+
 
         Ok(GenModel {
             name,
@@ -74,11 +217,94 @@ impl GenModel {
             core_instance,
             pkg_name,
             single_interface,
-            interfaces
+            vhdl_2008,
+            bool_to_std_logic_name: if vhdl_2008 { String::new() } else { BOOL_TO_STD_LOGIC.to_owned() },
+            vhdl_revision_pretty,
+            clock_name,
+            reset_name,
+            reset_active_level,
+            reset_active_high,
+            reset_is_async,
+            reset_pretty,
+            parameters,
+            variants,
+            variant,
+            use_variants,
+            interfaces,
+            access_types
         })
     }
 }
 
+/// format a value as a VHDL literal for the given signal type and width. The unsigned and signed literals are
+/// qualified, so that they don't get ambiguous with the std_logic_vector ones in the numeric_std operators
+pub fn vhdl_value_literal(value: u128, width: u32, signal: utils::SignalType, revision: VhdlRevision) -> String {
+    match signal {
+        utils::SignalType::Boolean => match value {
+                0 => "false".to_owned(),
+                _ => "true".to_owned()
+            },
+
+        utils::SignalType::StdLogic => match value {
+                0 => "'0'".to_owned(),
+                _ => "'1'".to_owned()
+            },
+
+        utils::SignalType::Unsigned | utils::SignalType::Signed => format!("{}'({})", signal.to_string(), vhdl_vector_literal(value, width, revision)),
+
+        utils::SignalType::StdLogicVector => vhdl_vector_literal(value, width, revision)
+    }
+}
+
+/// name of the package function converting a boolean to std_logic with VHDL-93
+const BOOL_TO_STD_LOGIC : &str = "bool_to_std_logic";
+
+/// VHDL expressions converting a signal of the given type, written {}, to the bus data bits and the bus data
+/// bits to the signal type. A boolean uses a conditional expression with VHDL-2008, only allowed on the right
+/// hand side of an assignment, and a package function with VHDL-93
+pub fn vhdl_bus_conversions(signal: utils::SignalType, revision: VhdlRevision) -> (String, String) {
+    match signal {
+        utils::SignalType::Boolean => match revision {
+                VhdlRevision::Vhdl2008 => ("'1' when {} else '0'".to_owned(), "{} = '1'".to_owned()),
+                VhdlRevision::Vhdl93 => (format!("{}({{}})", BOOL_TO_STD_LOGIC), "{} = '1'".to_owned())
+            },
+
+        utils::SignalType::StdLogic | utils::SignalType::StdLogicVector => ("{}".to_owned(), "{}".to_owned()),
+
+        utils::SignalType::Unsigned | utils::SignalType::Signed => ("std_logic_vector({})".to_owned(), format!("{}({{}})", signal.to_string()))
+    }
+}
+
+/// check that a signal name given in the settings is a valid VHDL identifier and reserve it in the token list
+fn settings_token(name: &str, usage: &str, token_list: &mut TokenList) -> Result<String, Box<dyn Error>> {
+    let page = PageType::Settings(SettingsPageType::Generation);
+
+    if to_vhdl_token(name) != name {
+        Err(GenError::new(&page, &format!("{} signal name '{}' is not a valid VHDL identifier", usage, name)))?
+    }
+    if token_list.add_token(name).is_err() {
+        Err(GenError::new(&page, &format!("{} signal name '{}' is a reserved word or is already used", usage, name)))?
+    }
+
+    Ok(name.to_owned())
+}
+
+/// format a value as a VHDL vector literal of the given width. VHDL-2008 allows sized bit string
+/// literals. With VHDL-93 an hexadecimal literal can only be used if the width is a multiple of 4,
+/// so use a binary string otherwise
+pub fn vhdl_vector_literal(value: u128, width: u32, revision: VhdlRevision) -> String {
+    match revision {
+        VhdlRevision::Vhdl2008 => format!("{}x\"{:x}\"", width, value),
+        VhdlRevision::Vhdl93 => {
+            if width % 4 == 0 {
+                format!("x\"{:0digits$x}\"", value, digits = (width / 4) as usize)
+            } else {
+                format!("\"{:0digits$b}\"", value, digits = width as usize)
+            }
+        }
+    }
+}
+
 /// Interface model for generation
 #[derive(Serialize)]
 pub struct GenInterface {
@@ -114,10 +340,31 @@ pub struct GenInterface {
     pub address_width: u32,
     /// width of the data bus.
     pub data_width: u32,
+    /// unit of the addresses, "byte" or "word"
+    pub addressing: String,
+    /// if true, each address selects a byte. Otherwise each address selects a whole bus word
+    pub byte_addressing: bool,
+    /// address increment between two consecutive bus words
+    pub word_increment: u128,
     /// if true, some registers are arrays
     pub use_stride: bool,
     /// if true, some registers are non arrays
     pub use_not_stride: bool,
+    /// if true, some reads wait for the core with a read request / read valid handshake
+    pub use_read_handshake: bool,
+    /// if true, some accesses need wait states: read handshakes, stalling FIFOs or memory reads. On Avalon,
+    /// the waitrequest port is added to hold the bus
+    pub use_wait_states: bool,
+    /// if true, the interface has byte enables and partial writes only update the addressed bytes
+    pub use_byte_enables: bool,
+    /// number of byte enables (only valid if use_byte_enables = true)
+    pub byte_enable_width: u32,
+    /// if true, the pif runs on its own bus clock, and the records are synchronized between the clock domains
+    pub separate_clock: bool,
+    /// number of synchronizer stages (only valid if separate_clock = true)
+    pub sync_stages: u32,
+    /// synchronizers between the pif and the core clock domains (empty if separate_clock = false)
+    pub synchronizers: Vec<GenSynchronizer>,
     /// list of interface porte
     pub ports: Vec<GenIntPort>,
     // list of signals for interface as a map (with function as index and name as value)
@@ -126,6 +373,200 @@ pub struct GenInterface {
     pub regs_doc_details : bool,
     /// list of registers
     pub registers : Vec<GenRegister>,
+    /// list of interrupt blocks. Their registers are in the registers list
+    pub interrupt_blocks : Vec<GenInterruptBlock>,
+    /// list of register groups. Their registers are in the registers list, with absolute addresses
+    pub groups : Vec<GenGroup>,
+    /// list of memory windows
+    pub memories : Vec<GenMemory>,
+    /// variants including the interface. Empty if it is in all variants
+    pub variants : Vec<String>,
+    /// variants including the interface, for the documentation
+    pub variants_pretty : String,
+}
+
+/// memory window model for generation
+#[derive(Serialize)]
+pub struct GenMemory {
+    /// memory name
+    pub name : String,
+    /// name used for token generation
+    pub token_name : String,
+    /// memory description
+    pub description : String,
+    /// name used for the constant with the base address
+    pub address_const_name : String,
+    /// name used for the constant with the number of words
+    pub depth_const_name : String,
+    /// base address (hexadecimal) excluding quotes
+    pub address_hex : String,
+    /// last address used by the memory (hexadecimal) excluding quotes
+    pub high_address_hex : String,
+    /// number of words
+    pub depth : u32,
+    /// width of a word in bits
+    pub width : u32,
+    /// number of bits of the word index
+    pub index_width : u32,
+    /// access from the interface
+    pub access : String,
+    /// if true, the memory can be read
+    pub is_read : bool,
+    /// if true, the memory can be written
+    pub is_write : bool,
+    /// clock cycles between the read enable and the read data
+    pub read_latency : u32,
+    /// signals in the core2pif record
+    pub core2pif : Vec<GenStructSignal>,
+    /// signals in the pif2core record
+    pub pif2core : Vec<GenStructSignal>,
+    /// core2pif signals as a map (with function as index and name as value)
+    pub core2pif_names : HashMap<String, String>,
+    /// pif2core signals as a map (with function as index and name as value)
+    pub pif2core_names : HashMap<String, String>,
+}
+
+impl GenMemory {
+    /// take a Mdf memory and convert it to a GenMemory. The context must already have the project and interface names.
+    /// The memory address must have been assigned
+    pub fn from_memory(memory: &mdf::Memory, page: &PageType, context: &tera::Context, templates: &Tera, interface_data_width: u32, word_increment: u128, general_token_list : &mut TokenList, corfe2pif_token_list : &mut TokenList, pif2core_token_list : &mut TokenList) -> Result<Self, Box<dyn Error>> {
+        let name = memory.name.clone();
+        let token_name = to_vhdl_token(&name);
+        let address = match memory.address {
+            Some(address) => address.value,
+            None => Err(GenError::new(page, &format!("address of memory {} not defined", name)))?
+        };
+        let width = memory.width.unwrap_or(interface_data_width);
+        let index_width = memory.index_width();
+        let is_read = memory.access.is_read();
+        let is_write = memory.access.is_write();
+
+        let mut context = context.clone();
+        context.insert("memory", &token_name);
+        context.insert("full_name", &name);
+
+        let address_const_name = general_token_list.generate_token(&templates.render("gmem_address_const_name", &context)?);
+        let depth_const_name = general_token_list.generate_token(&templates.render("gmem_depth_const_name", &context)?);
+
+        let data_type = format!("std_logic_vector({} downto 0)", width - 1);
+        let mut core2pif : Vec<GenStructSignal> = Default::default();
+        let mut pif2core : Vec<GenStructSignal> = Default::default();
+        pif2core.push(gen_registersignal(templates, "address", "gmem_address_name", &format!("unsigned({} downto 0)", index_width - 1), "gmem_address_description", &context, pif2core_token_list)?);
+        if is_write {
+            pif2core.push(gen_registersignal(templates, "write_data", "gmem_write_data_name", &data_type, "gmem_write_data_description", &context, pif2core_token_list)?);
+            pif2core.push(gen_registersignal(templates, "write_enable", "gmem_write_enable_name", "std_logic", "gmem_write_enable_description", &context, pif2core_token_list)?);
+        }
+        if is_read {
+            pif2core.push(gen_registersignal(templates, "read_enable", "gmem_read_enable_name", "std_logic", "gmem_read_enable_description", &context, pif2core_token_list)?);
+            core2pif.push(gen_registersignal(templates, "read_data", "gmem_read_data_name", &data_type, "gmem_read_data_description", &context, corfe2pif_token_list)?);
+        }
+
+        Ok(GenMemory {
+            description: utils::opt_vec_str_to_textarea(&memory.description),
+            address_const_name,
+            depth_const_name,
+            address_hex: format!("{:x}", address),
+            high_address_hex: format!("{:x}", address + memory.span(word_increment) - 1),
+            depth: memory.depth,
+            width,
+            index_width,
+            access: memory.access.to_string(),
+            is_read,
+            is_write,
+            read_latency: memory.read_latency.unwrap_or(1),
+            core2pif_names: gen_names_map(&core2pif),
+            pif2core_names: gen_names_map(&pif2core),
+            core2pif,
+            pif2core,
+            name,
+            token_name,
+        })
+    }
+}
+
+/// register group model for generation
+#[derive(Serialize)]
+pub struct GenGroup {
+    /// group name
+    pub name : String,
+    /// name used for token generation
+    pub token_name : String,
+    /// group description
+    pub description : String,
+    /// base address (hexadecimal) excluding quotes
+    pub base_hex : String,
+    /// number of instances of the group
+    pub count : u32,
+    /// address increment between two instances (hexadecimal) excluding quotes
+    pub increment_hex : String,
+    /// registers in the group
+    pub registers : Vec<GenGroupRegister>,
+}
+
+/// register within a group, for generation
+#[derive(Serialize)]
+pub struct GenGroupRegister {
+    /// register name (as in GenRegister name)
+    pub name : String,
+    /// address relative to the group base (hexadecimal) excluding quotes
+    pub offset_hex : String,
+}
+
+/// interrupt block model for generation
+#[derive(Serialize)]
+pub struct GenInterruptBlock {
+    /// block name
+    pub name : String,
+    /// name used for token generation
+    pub token_name : String,
+    /// block description
+    pub description : String,
+    /// interrupt sources names, the first one is bit 0
+    pub sources : Vec<String>,
+    /// number of interrupt sources
+    pub source_count : usize,
+    /// name of the status register (as in GenRegister name)
+    pub status_register : String,
+    /// name of the enable register
+    pub enable_register : String,
+    /// name of the pending register
+    pub pending_register : String,
+    /// if true, there is a force register
+    pub use_force : bool,
+    /// name of the force register (only valid if use_force = true)
+    pub force_register : String,
+    /// names of the core2pif signals setting the status bits, in the sources order
+    pub set_signals : Vec<String>,
+    /// name of the interrupt request port
+    pub irq_name : String,
+}
+
+impl GenInterruptBlock {
+    /// take a Mdf interrupt block and convert it to a GenInterruptBlock. The context must already have the interface names
+    pub fn from_interrupt_block(block: &mdf::InterruptBlock, templates: &Tera, context: &tera::Context, general_token_list : &mut TokenList) -> Result<Self, Box<dyn Error>> {
+        let name = block.name.clone();
+        let token_name = to_vhdl_token(&name);
+        let use_force = block.use_force.unwrap_or(false);
+
+        let mut context = context.clone();
+        context.insert("block", &token_name);
+        let irq_name = general_token_list.generate_token(&templates.render("gi_irq_name", &context)?);
+
+        Ok(GenInterruptBlock {
+            description: utils::opt_vec_str_to_textarea(&block.description),
+            sources: block.sources.iter().map(|source| source.name.clone()).collect(),
+            source_count: block.sources.len(),
+            status_register: block.register_name("status"),
+            enable_register: block.register_name("enable"),
+            pending_register: block.register_name("pending"),
+            use_force,
+            force_register: if use_force { block.register_name("force") } else { String::new() },
+            set_signals: Vec::new(),
+            irq_name,
+            name,
+            token_name,
+        })
+    }
 }
 
 /// Interface port model
@@ -146,13 +587,35 @@ pub struct GenIntPort {
 }
 
 impl GenInterface {
-    /// take a Mdf interface and convert it to a GenInterface
-    pub fn from_interface(interface: &mdf::Interface, page: PageType, settings: &Settings, templates: &Tera, project_token_name : &String, single_interface : &bool, general_token_list : &mut TokenList) -> Result<Self, Box<dyn Error>> {
+    /// take a Mdf interface and convert it to a GenInterface, with only the registers and fields of the variant if one is given
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_interface(interface: &mdf::Interface, page: PageType, settings: &Settings, templates: &Tera, project_token_name : &String, single_interface : &bool, variant: Option<&str>, general_token_list : &mut TokenList) -> Result<Self, Box<dyn Error>> {
 
-        // duplicate the interface and assign an address to all registers
+        // duplicate the interface, replace the interrupt blocks with their registers and assign an address to all registers
         let mut interface = interface.clone();
+        let interrupt_blocks_list = interface.interrupt_blocks.clone();
+        interface.expand_interrupt_blocks()?;
         interface.assign_addresses()?;
 
+        // keep the group information before converting the addresses in the groups to absolute ones
+        let groups = interface.groups.iter().enumerate().map(|(n, group)| {
+            let registers = interface.registers.iter()
+                .filter(|register| register.group.as_ref() == Some(&group.name));
+            GenGroup {
+                name: group.name.clone(),
+                token_name: to_vhdl_token(&group.name),
+                description: utils::opt_vec_str_to_textarea(&group.description),
+                base_hex: format!("{:x}", group.base.map_or(0, |base| base.value)),
+                count: group.get_count() as u32,
+                increment_hex: format!("{:x}", interface.get_group_increment(n).unwrap_or(0)),
+                registers: registers.filter(|register| variant.map_or(true, |variant| register.in_variant(variant))).map(|register| GenGroupRegister {
+                    name: register.name.clone(),
+                    offset_hex: format!("{:x}", register.address.value.map_or(0, |address| address.value)),
+                }).collect(),
+            }
+        }).collect::<Vec<GenGroup>>();
+        interface.flatten_groups()?;
+
         let name = interface.name.clone();
         let token_name = if *single_interface { "".to_owned()} else {to_vhdl_token(&name)};
 
@@ -172,6 +635,18 @@ impl GenInterface {
             Some(width) => width,
             None => Err(GenError::new(&page, &format!("couldn't determine interface {} data width", name)))?
         };
+        let addressing = interface.addressing_mode();
+        let word_increment = addressing.word_increment(data_width);
+
+        // the addresses and the width are known for all the variants, now only keep the registers and fields
+        // of the generated one. The register numbers in the model are kept for the error messages
+        let register_numbers: Vec<usize> = (0..interface.registers.len())
+            .filter(|n| variant.map_or(true, |variant| interface.registers[*n].in_variant(variant)))
+            .collect();
+        if let Some(variant) = variant {
+            interface.select_variant(variant);
+        }
+
         // go through all the registers and check if at least one uses an address stride
         let use_stride = interface.registers.iter().fold(false, 
             | use_stride, reg  | { use_stride || reg.address.stride.is_some() } );
@@ -198,27 +673,130 @@ impl GenInterface {
         port_context.insert("interface", &token_name);
         port_context.insert("address_width", &address_width);
         port_context.insert("data_width", &data_width);
+        port_context.insert("strobe_width", &((data_width + 7) / 8));
 
-        let ports = signal_list::to_port_list(interface_type, &port_context, general_token_list)?;
+        let use_byte_enables = interface.use_byte_enables.unwrap_or(false);
+        let mut ports = signal_list::to_port_list(interface_type, use_byte_enables, &port_context, general_token_list)?;
 
-        // make a second ports list, a hashmap from function to name
-        let ports_names : HashMap<String, String> = ports.iter().map(
-            | signal | (signal.function.clone(), signal.name.clone())).collect();
+        // an interface with a separate clock has its own clock and reset inputs
+        let separate_clock = interface.separate_clock.unwrap_or(false);
+        let sync_stages = interface.sync_stages.unwrap_or(2).max(2);
+        if separate_clock {
+            if !interface.memories.is_empty() {
+                Err(GenError::new(&page, &format!("interface {} has memories, they need the core and the bus on the same clock", name)))?
+            }
+            ports.push(GenIntPort {
+                function: "clk".to_owned(),
+                name: general_token_list.generate_token(&templates.render("gi_clock_name", &context)?),
+                port_type: "std_logic".to_owned(),
+                direction: "in".to_owned(),
+                description: "bus clock".to_owned(),
+                xilinx_attr: String::new()
+            });
+            ports.push(GenIntPort {
+                function: "rst".to_owned(),
+                name: general_token_list.generate_token(&templates.render("gi_reset_name", &context)?),
+                port_type: "std_logic".to_owned(),
+                direction: "in".to_owned(),
+                description: "bus reset".to_owned(),
+                xilinx_attr: String::new()
+            });
+        }
+
+        // interrupt blocks, each one with its interrupt request port
+        let mut interrupt_blocks = interrupt_blocks_list.iter().map(|block| {
+            let interrupt_block = GenInterruptBlock::from_interrupt_block(block, templates, &context, general_token_list)?;
+            ports.push(GenIntPort {
+                function: format!("irq_{}", interrupt_block.token_name),
+                name: interrupt_block.irq_name.clone(),
+                port_type: "std_logic".to_owned(),
+                direction: "out".to_owned(),
+                description: format!("interrupt request for {}", interrupt_block.name),
+                xilinx_attr: String::new()
+            });
+            Ok(interrupt_block)
+        }).collect::<Result<Vec<GenInterruptBlock>, Box<dyn Error>>>()?;
 
         // go through all the registers and add them to the list
-        let registers =  match page {
+        let mut corfe2pif_token_list = TokenList::new();
+        let mut pif2core_token_list = TokenList::new();
+        let mut registers =  match page {
             PageType::Interface(int_num) => {
-                let mut corfe2pif_token_list = TokenList::new();
-                let mut pif2core_token_list = TokenList::new();
-
-                interface.registers.iter().enumerate().map(|(n, register)| GenRegister::from_register(
+                interface.registers.iter().zip(&register_numbers).map(|(register, &n)| GenRegister::from_register(
                     register, PageType::Register(int_num,n, None),
-                    settings, templates, project_token_name, &token_name, data_width,
+                    settings, templates, project_token_name, &token_name, data_width, word_increment, use_byte_enables,
                     general_token_list, &mut corfe2pif_token_list, &mut pif2core_token_list))
                     .collect::<Result<Vec<GenRegister>,  Box<dyn Error>>>()?},
             _ => Err(GenError::new(&page, "wrong value for the page parameter in register call"))?
         };
 
+        // a register committing shadowed registers must exist and be writable. It lists them for the documentation
+        for n in 0..registers.len() {
+            if registers[n].is_shadowed && !registers[n].shadow_commit_strobe {
+                let shadowed = registers[n].name.clone();
+                let commit = registers[n].shadow_commit_register.clone();
+                match registers.iter_mut().find(|reg| reg.name == commit && reg.name != shadowed) {
+                    Some(commit_register) if commit_register.fields.iter().any(|field| field.is_write) => {
+                        commit_register.commits.push(shadowed);
+                        commit_register.doc_details = true;
+                    }
+                    Some(_) => Err(GenError::new(&page, &format!("register {} is committed by register {}, which can't be written", shadowed, commit)))?,
+                    None => Err(GenError::new(&page, &format!("register {} is committed by unknown register '{}'", shadowed, commit)))?
+                }
+            }
+        }
+
+        // link the interrupt block registers, the core sets the status bits through their hw_set signals
+        for (block, interrupt_block) in interrupt_blocks_list.iter().zip(&mut interrupt_blocks) {
+            for function in ["status", "enable", "pending", "force"] {
+                if function == "force" && !interrupt_block.use_force {
+                    continue;
+                }
+                let register_name = block.register_name(function);
+                if let Some(register) = registers.iter_mut().rev().find(|reg| reg.name == register_name) {
+                    register.interrupt_block = interrupt_block.name.clone();
+                    register.interrupt_function = function.to_owned();
+                    if function == "status" {
+                        interrupt_block.set_signals = register.fields.iter()
+                            .map(|field| field.core2pif_names.get("hw_set").cloned().unwrap_or_default())
+                            .collect();
+                    }
+                }
+            }
+        }
+
+        // the memories share the core2pif and pif2core records with the registers
+        let memories = interface.memories.iter().map(|memory| GenMemory::from_memory(
+            memory, &page, &context, templates, data_width, word_increment, general_token_list, &mut corfe2pif_token_list, &mut pif2core_token_list))
+            .collect::<Result<Vec<GenMemory>, Box<dyn Error>>>()?;
+
+        // the pif needs wait states if at least one field is read with a handshake
+        let use_read_handshake = registers.iter().any(|reg| reg.fields.iter().any(|field| field.core_read_handshake));
+
+        // a FIFO access can stall and a memory read waits for the data too. The bus must be held meanwhile
+        let use_wait_states = use_read_handshake
+            || registers.iter().any(|reg| reg.is_fifo && reg.fifo_stall)
+            || memories.iter().any(|memory| memory.is_read && memory.read_latency > 0);
+        if use_wait_states {
+            ports.extend(signal_list::to_wait_port(interface_type, &port_context, general_token_list)?);
+        }
+
+        // each signal between the clock domains gets a synchronizer
+        let mut synchronizers = Vec::new();
+        if separate_clock {
+            for register in &registers {
+                GenSynchronizer::from_signals(&register.core2pif, true, &mut synchronizers);
+                for field in &register.fields {
+                    GenSynchronizer::from_signals(&field.core2pif, true, &mut synchronizers);
+                    GenSynchronizer::from_signals(&field.pif2core, false, &mut synchronizers);
+                }
+            }
+        }
+
+        // make a second ports list, a hashmap from function to name
+        let ports_names : HashMap<String, String> = ports.iter().map(
+            | signal | (signal.function.clone(), signal.name.clone())).collect();
+
         // go through all registers to see if some have some doc details
         let regs_doc_details = registers.iter().fold(false, |prev, reg| { prev || reg.doc_details} );
 
@@ -239,12 +817,27 @@ impl GenInterface {
             interface_type_pretty, 
             address_width, 
             data_width, 
+            addressing: addressing.to_string(),
+            byte_addressing: addressing == mdf::AddressingMode::Byte,
+            word_increment,
             use_stride,
             use_not_stride,
+            use_read_handshake,
+            use_wait_states,
+            use_byte_enables,
+            byte_enable_width: (data_width + 7) / 8,
+            separate_clock,
+            sync_stages,
+            synchronizers,
             ports,
             ports_names,
             regs_doc_details,
-            registers})
+            registers,
+            interrupt_blocks,
+            groups,
+            memories,
+            variants: interface.variants.clone(),
+            variants_pretty: variants_pretty(&interface.variants)})
 
     }
 }
@@ -265,6 +858,8 @@ pub struct GenRegister {
     pub address_hex : String,
     /// address for display in documentation
     pub address_pretty : String,
+    /// name of the group the register belongs to (empty if none)
+    pub group : String,
     /// if true, register is an array
     pub is_stride : bool,
     /// quick description
@@ -287,8 +882,51 @@ pub struct GenRegister {
     pub stride_increment : u32,
     /// if true, array addresses are continuous (only valid if is_stride = true)
     pub stride_continuous : bool,
+    /// array dimensions, from the outer to the inner one (empty if is_stride = false)
+    pub stride_dimensions : Vec<GenStrideDimension>,
+    /// total number of elements in the array, including all dimensions
+    pub stride_total_count : u32,
+    /// formula giving the address of an array element, for the documentation
+    pub address_formula : String,
+    /// if true, the register is mapped to a FIFO in the core
+    pub is_fifo : bool,
+    /// if true, an access to a full or empty FIFO waits. Otherwise it is dropped and flagged (only valid if is_fifo = true)
+    pub fifo_stall : bool,
+    /// if true, the register can be written with byte enables without affecting the other bytes
+    pub narrow_write : bool,
+    /// if true, the core provides the FIFO level (only valid if is_fifo = true)
+    pub fifo_use_level : bool,
+    /// width of the FIFO level signal (only valid if fifo_use_level = true)
+    pub fifo_level_width : u32,
+    /// number of bus words used by the register
+    pub word_count : u32,
+    /// if true, the register is wider than the interface and split over several addresses (word_count > 1)
+    pub is_split : bool,
+    /// bus words of a split register, low word first (empty if is_split = false)
+    pub words : Vec<GenRegisterWord>,
+    /// if true, the interface writes a shadow copy of the register, copied to the active copy on a commit
+    pub is_shadowed : bool,
+    /// if true, the shadow copy is committed by a strobe from the core. Otherwise by a write to
+    /// shadow_commit_register (only valid if is_shadowed = true)
+    pub shadow_commit_strobe : bool,
+    /// name of the register whose write commits the shadow copy (only valid if shadow_commit_strobe = false)
+    pub shadow_commit_register : String,
+    /// names of the shadowed registers committed by a write to this register
+    pub commits : Vec<String>,
+    /// name of the interrupt block the register belongs to. Empty if none
+    pub interrupt_block : String,
+    /// function of the register in its interrupt block: "status", "enable", "pending" or "force". The pending
+    /// register has no storage, its bits are the status and enable bits, and a write of 1 to a force bit sets
+    /// the status bit (only valid if interrupt_block is not empty)
+    pub interrupt_function : String,
+    /// register wide signals from the core to the pif. The data signals are in the fields
+    pub core2pif : Vec<GenStructSignal>,
     /// fields (if the register is not a bitfield, holds a single element with the register description)
-    pub fields : Vec<GenField>
+    pub fields : Vec<GenField>,
+    /// variants including the register. Empty if it is in all variants
+    pub variants : Vec<String>,
+    /// variants including the register, for the documentation
+    pub variants_pretty : String,
 }
 
 /// field model for generation
@@ -310,12 +948,34 @@ pub struct GenField {
     pub width_const_name : String,
     /// name of the constant for the field lsb (offset)
     pub offset_const_name : String,
+    /// if true, the field is repeated several times in the register
+    pub is_array : bool,
+    /// number of elements in the array (1 if not an array)
+    pub array_count : u32,
+    /// number of bits between two elements of the array
+    pub array_stride : u32,
+    /// name of the array type (only valid if is_array = true)
+    pub array_type_name : String,
+    /// name of the constant for the array stride (only valid if is_array = true)
+    pub array_stride_const_name : String,
     /// read-write mode
     pub rw_mode : String,
     /// read access
     pub is_read : bool,
     /// write access
     pub is_write : bool,
+    /// read only, cleared when read (RC)
+    pub is_read_clear : bool,
+    /// writing a 1 clears the bit (W1C)
+    pub is_write_one_clear : bool,
+    /// writing a 1 sets the bit (W1S)
+    pub is_write_one_set : bool,
+    /// writing a 0 clears the bit (W0C)
+    pub is_write_zero_clear : bool,
+    /// write only, self clearing pulse (WP)
+    pub is_write_pulse : bool,
+    /// true if a write only modifies some bits depending on the written value (W1C, W1S and W0C)
+    pub is_write_masked : bool,
     /// field type (only valid if not a bitfield)
     pub sig_type : String,
     /// complete type, including vector downto size
@@ -326,14 +986,38 @@ pub struct GenField {
     pub sig_type_is_bool : bool,
     /// true if type is a vector
     pub sig_type_is_vector : bool,
-    /// field reset value, including quotes if required
+    /// field reset value, including quotes if required. For an array, an aggregate of the array type with the
+    /// reset value in each element
     pub reset : String,
+    /// VHDL expression converting the field signal, written {}, to the bus data bits. For an array, converts one
+    /// element
+    pub to_bus : String,
+    /// VHDL expression converting the bus data bits, written {}, to the field signal type. For an array, converts
+    /// one element
+    pub from_bus : String,
     /// field location
     pub is_in_core : bool,
     /// read enable
     pub core_read_enable : bool,
+    /// if true, a read waits for the core: the pif sends a read request and the core answers with a read valid
+    pub core_read_handshake : bool,
+    /// if true, the write enable to the core comes with byte enables
+    pub core_byte_enable : bool,
+    /// first register byte covered by the field. It is also the bus byte lane, unless the register is split
+    /// over several words, which give their own byte lanes
+    pub byte_lane_first : u32,
+    /// number of register bytes covered by the field, so the width of its byte enables
+    pub byte_lane_count : u32,
     /// write enable
     pub core_write_enable : bool,    
+    /// the core can set bits, with a signal in core2pif
+    pub hw_set : bool,
+    /// the core can clear bits, with a signal in core2pif
+    pub hw_clear : bool,
+    /// the core can write a new value, with the data and a valid signal in core2pif
+    pub hw_write : bool,
+    /// if true, a core update has priority over a write from the interface in the same clock cycle
+    pub hw_has_priority : bool,
     /// if true, there is a data signal in core2pif
     pub core2pif_has_data : bool,
     /// if true, there is a data signal in pif2core
@@ -346,6 +1030,39 @@ pub struct GenField {
     pub pif2core: Vec<GenStructSignal>,
     // list of signals for core2pif as a map (with function as index and name as value)
     pub pif2core_names: HashMap<String, String>,
+    /// if true, the field has a list of named values
+    pub has_enum_values : bool,
+    /// name of the type enumerating the named values (only valid if has_enum_values = true)
+    pub enum_type_name : String,
+    /// name of the enum type in Rust (only valid if has_enum_values = true)
+    pub rust_enum_name : String,
+    /// named values
+    pub enum_values : Vec<GenEnumValue>,
+    /// variants including the field. Empty if it is in all variants
+    pub variants : Vec<String>,
+    /// variants including the field, for the documentation
+    pub variants_pretty : String,
+}
+
+/// named value model for generation
+#[derive(Serialize)]
+pub struct GenEnumValue {
+    /// value name
+    pub name : String,
+    /// name used for token generation, and as enumeration literal
+    pub token_name : String,
+    /// name of the VHDL constant with the value
+    pub const_name : String,
+    /// name of the C constant with the value
+    pub c_name : String,
+    /// name of the Rust enum variant
+    pub rust_name : String,
+    /// value (hexadecimal) excluding quotes
+    pub value_hex : String,
+    /// value as a VHDL literal of the field type
+    pub literal : String,
+    /// value description
+    pub description : String,
 }
 
 /// signal element in the core2pif and pif2core records
@@ -359,6 +1076,87 @@ pub struct GenStructSignal {
     pub signal_type: String,
     /// signal description 
     pub description: String,
+    /// synchronizer needed when the pif and the core are on different clocks: "pulse" for strobes (one per bit
+    /// for the set and clear masks), "level" for single bit values and "handshake" for multi-bit values and for
+    /// the signals crossing with their strobe
+    pub cdc: String,
+    /// function of the strobe crossing in the same handshake as the signal, the strobe itself included. Empty if
+    /// the signal crosses alone
+    pub cdc_strobe: String,
+}
+
+/// synchronizer for record signals crossing between the pif and the core clock domains
+#[derive(Serialize)]
+pub struct GenSynchronizer {
+    /// synchronizer type: "pulse", "level" or "handshake"
+    pub cdc: String,
+    /// if true, the signals go from the core to the pif. Otherwise from the pif to the core
+    pub is_core2pif: bool,
+    /// names of the record signals crossing in the synchronizer, the strobe first if there is one
+    pub signals: Vec<String>,
+    /// name of the strobe regenerated in the other domain once the handshake is done. Empty if none
+    pub strobe: String,
+}
+
+impl GenSynchronizer {
+    /// add the synchronizers for the signals of a register or field record, one per signal except for the
+    /// signals crossing with their strobe, which share one
+    pub fn from_signals(signals: &[GenStructSignal], is_core2pif: bool, synchronizers: &mut Vec<GenSynchronizer>) {
+        for signal in signals.iter().filter(|signal| signal.cdc_strobe.is_empty()) {
+            synchronizers.push(GenSynchronizer {
+                cdc: signal.cdc.clone(),
+                is_core2pif,
+                signals: vec![signal.name.clone()],
+                strobe: String::new()});
+        }
+        if let Some(strobe) = signals.iter().find(|signal| !signal.cdc_strobe.is_empty() && signal.function == signal.cdc_strobe) {
+            synchronizers.push(GenSynchronizer {
+                cdc: strobe.cdc.clone(),
+                is_core2pif,
+                signals: std::iter::once(strobe.name.clone())
+                    .chain(signals.iter().filter(|signal| !signal.cdc_strobe.is_empty() && signal.function != signal.cdc_strobe)
+                        .map(|signal| signal.name.clone()))
+                    .collect(),
+                strobe: strobe.name.clone()});
+        }
+    }
+}
+
+/// functions of the record signals that are single clock cycle strobes
+const PULSE_FUNCTIONS : [&str; 12] = ["read_enable", "write_enable", "read_request", "read_valid", "hw_set", "hw_clear",
+    "hw_valid", "shadow_commit", "fifo_push", "fifo_pop", "fifo_overflow", "fifo_underflow"];
+
+/// strobes qualifying the data of their record, by order of preference
+const DATA_STROBES : [&str; 4] = ["write_enable", "fifo_push", "read_valid", "hw_valid"];
+
+/// functions of the record signals only sampled with a strobe of the same record
+const STROBED_FUNCTIONS : [&str; 2] = ["data", "byte_enable"];
+
+/// cross the data and the byte enables of a record in the same handshake as the strobe qualifying them, so the
+/// other domain never samples them before the strobe or while they change
+pub fn bundle_strobed(signals: &mut [GenStructSignal]) {
+    if !signals.iter().any(|signal| STROBED_FUNCTIONS.contains(&signal.function.as_str())) {
+        return;
+    }
+    let strobe = DATA_STROBES.iter().find(|&&strobe| signals.iter().any(|signal| signal.function == strobe));
+    if let Some(&strobe) = strobe {
+        for signal in signals.iter_mut()
+            .filter(|signal| signal.function == strobe || STROBED_FUNCTIONS.contains(&signal.function.as_str())) {
+            signal.cdc = "handshake".to_owned();
+            signal.cdc_strobe = strobe.to_owned();
+        }
+    }
+}
+
+/// synchronizer type for a record signal when crossing clock domains
+pub fn cdc_type(function: &str, full_type: &str) -> &'static str {
+    if PULSE_FUNCTIONS.contains(&function) {
+        "pulse"
+    } else if full_type == "boolean" || full_type == "std_logic" {
+        "level"
+    } else {
+        "handshake"
+    }
 }
 
 /// create a GenStructSignal using the given templates
@@ -371,7 +1169,36 @@ pub fn gen_registersignal(templates: &Tera, function: &str, name_template : &str
         function: function.to_owned(), 
         name, 
         signal_type : full_type.to_owned(), 
-        description})
+        description,
+        cdc: cdc_type(function, full_type).to_owned(),
+        cdc_strobe: String::new()})
+}
+
+/// build the named values list for a register or a field. The given context must already have the register
+/// and field names required by the templates
+pub fn gen_enum_values(enum_values: &[mdf::EnumValue], const_name_template: &str, width: u32, signal: utils::SignalType, settings: &Settings, templates: &Tera, context: &tera::Context, general_token_list: &mut TokenList) -> Result<Vec<GenEnumValue>, Box<dyn Error>> {
+    // the value tokens must be unique within the enumeration
+    let mut values_token_list = TokenList::new();
+
+    enum_values.iter().map(|enum_value| {
+        let name = enum_value.name.clone();
+        let token_name = values_token_list.generate_token(&format!("{}*", to_vhdl_token(&name)));
+
+        let mut context = context.clone();
+        context.insert("value", &token_name);
+        let const_name = general_token_list.generate_token(&templates.render(const_name_template, &context)?);
+
+        Ok(GenEnumValue {
+            name,
+            c_name: const_name.to_uppercase(),
+            rust_name: to_camel_case(&token_name),
+            token_name,
+            const_name,
+            value_hex: format!("{:x}", enum_value.value.value),
+            literal: vhdl_value_literal(enum_value.value.value, width, signal, settings.vhdl_revision),
+            description: utils::opt_vec_str_to_textarea(&enum_value.description)
+        })
+    }).collect()
 }
 
 /// convert a list of GenStructSignal to a map of names
@@ -379,19 +1206,60 @@ pub fn gen_names_map(signals: &[GenStructSignal]) -> HashMap<String, String> {
     signals.iter().map(|signal| (signal.function.clone(), signal.name.clone())).collect()
 }
 
+/// dimension of a register array
+#[derive(Serialize)]
+pub struct GenStrideDimension {
+    /// number of elements in this dimension
+    pub count : u32,
+    /// address offset between two elements of this dimension
+    pub increment : u32,
+}
+
+/// one bus word of a register split over several addresses
+#[derive(Serialize)]
+pub struct GenRegisterWord {
+    /// word number, 0 being the low word
+    pub index : u32,
+    /// address of the word (hexadecimal) excluding quotes
+    pub address_hex : String,
+    /// offset of the word relative to the register address (hexadecimal) excluding quotes
+    pub offset_hex : String,
+    /// highest register bit held in this word
+    pub msb : u32,
+    /// lowest register bit held in this word
+    pub lsb : u32,
+    /// register byte on the first byte lane of the word, so the first bit of the register byte enables
+    /// written by this word
+    pub byte_first : u32,
+    /// number of byte lanes of the word holding register bits, from the first lane
+    pub byte_lane_count : u32,
+    /// if true, this is the high word
+    pub is_last : bool,
+}
+
 impl GenRegister {
     /// take a Mdf register and convert it to a GenRegister
-    pub fn from_register(register: &mdf::Register, page: PageType, settings: &Settings, templates: &Tera, project_token_name : &String, interface_token_name : &String, interface_data_width: u32, general_token_list : &mut TokenList, corfe2pif_token_list : &mut TokenList, pif2core_token_list : &mut TokenList) -> Result<Self, Box<dyn Error>> {
+    pub fn from_register(register: &mdf::Register, page: PageType, settings: &Settings, templates: &Tera, project_token_name : &String, interface_token_name : &String, interface_data_width: u32, word_increment: u128, use_byte_enables: bool, general_token_list : &mut TokenList, corfe2pif_token_list : &mut TokenList, pif2core_token_list : &mut TokenList) -> Result<Self, Box<dyn Error>> {
 
         let name = register.name.clone();
         let token_name = to_vhdl_token(&name);
         let address_hex = format!("{:x}", register.address.value.ok_or("address not defined")?.value);
         let address_pretty = register.address.nice_str();
+        let group = register.group.clone().unwrap_or_default();
         let is_stride = register.address.stride.is_some();
         let summary = utils::opt_vec_str_to_textarea(&register.summary);
         let description = utils::opt_vec_str_to_textarea(&register.description);
         let is_bitfield = register.signal.is_none();
-        let doc_details = is_bitfield || !description.is_empty();
+        let is_fifo = register.fifo.is_some();
+        let fifo = register.fifo.clone().unwrap_or_default();
+        let fifo_stall = fifo.blocked_access.unwrap_or(mdf::FifoBlockedAccess::Stall) == mdf::FifoBlockedAccess::Stall;
+        let fifo_use_level = fifo.use_level.unwrap_or(false);
+        let fifo_level_width = fifo.level_width.unwrap_or(8).max(1);
+        let read_handshake = register.core_signal_properties.use_read_handshake.unwrap_or(false);
+        let word_count = register.word_count(interface_data_width);
+        let is_split = word_count > 1;
+        let is_shadowed = register.shadow.is_some();
+        let doc_details = is_bitfield || is_stride || is_fifo || is_split || is_shadowed || read_handshake || !description.is_empty() || !register.enum_values.is_empty();
 
         // the fields: either a single field with the register, or a bunch of fields
         let fields = if !is_bitfield {
@@ -414,8 +1282,14 @@ impl GenRegister {
             let width_const_name = general_token_list.generate_token(&templates.render("gr_width_const_name", &context)?);
 
             let rw_mode = register.access.ok_or(GenError::new(&page,"access type needed for register"))?;
-            let is_read = rw_mode != mdf::AccessType::WO;
-            let is_write = rw_mode != mdf::AccessType::RO;
+            let is_read = rw_mode.is_read();
+            let is_write = rw_mode.is_write();
+            let is_read_clear = rw_mode == mdf::AccessType::RC;
+            let is_write_one_clear = rw_mode == mdf::AccessType::W1C;
+            let is_write_one_set = rw_mode == mdf::AccessType::W1S;
+            let is_write_zero_clear = rw_mode == mdf::AccessType::W0C;
+            let is_write_pulse = rw_mode == mdf::AccessType::WP;
+            let is_write_masked = is_write_one_clear || is_write_one_set || is_write_zero_clear;
                 // rw_mode should be a string
             let rw_mode = rw_mode.to_string();
             let sig_type = register.signal.unwrap().to_string();
@@ -430,28 +1304,34 @@ impl GenRegister {
     
             let reset = match register.reset {
                 None => Err(GenError::new(&page,"reset value not specified"))?,      // non bitfield, we must have a value
-                Some(reset_value) => match register.signal.unwrap() {
-                    // the way we format the value depends on the type
-                    utils::SignalType::Boolean => match reset_value.value {
-                            0 => "false".to_owned(),
-                            _ => "true".to_owned()
-                        },
-
-                    utils::SignalType::StdLogic => match reset_value.value {
-                            0 => "'0'".to_owned(),
-                            _ => "'1'".to_owned()
-                        },
-                        
-                    _ => format!("{}x\"{:x}\"",width,reset_value.value)
-                }
+                Some(reset_value) => vhdl_value_literal(reset_value.value, width, register.signal.unwrap(), settings.vhdl_revision)
             };
+            let (to_bus, from_bus) = vhdl_bus_conversions(register.signal.unwrap(), settings.vhdl_revision);
     
             let is_in_core = register.location.ok_or(GenError::new(&page,"location for register {} needs to be specified"))? == mdf::LocationType::Core;
     
-            let core_read_enable = register.core_signal_properties.use_read_enable.unwrap_or(false);
-            let core_write_enable = register.core_signal_properties.use_write_enable.unwrap_or(false);
+            // a register in the core needs the strobes to implement the read clear and masked write modes
+            let core_read_enable = register.core_signal_properties.use_read_enable.unwrap_or(false) || (is_in_core && is_read_clear);
+            let core_read_handshake = is_in_core && is_read && register.core_signal_properties.use_read_handshake.unwrap_or(false);
+            let core_write_enable = register.core_signal_properties.use_write_enable.unwrap_or(false) || (is_in_core && is_write_masked);
+            // byte enables come with the write enable of a core register, one per byte of the register. Each word
+            // of a split register drives its own part of them
+            let byte_lane_first = 0;
+            let byte_lane_count = width.div_ceil(8);
+            let core_byte_enable = use_byte_enables && is_in_core && core_write_enable && register.fifo.is_none();
+
+            // hardware updates from the core, only for signals in the pif
+            let hw_set = !is_in_core && register.hardware_access.use_set.unwrap_or(false);
+            let hw_clear = !is_in_core && register.hardware_access.use_clear.unwrap_or(false);
+            let hw_write = !is_in_core && register.hardware_access.use_write.unwrap_or(false);
+            let hw_has_priority = register.hardware_access.priority.unwrap_or(mdf::HardwarePriority::Hardware) == mdf::HardwarePriority::Hardware;
+            let hw_mask_type = if sig_type_is_vector {
+                format!("std_logic_vector({} downto 0)", width-1)
+            } else {
+                sig_type_complete.clone()
+            };
 
-            let core2pif_has_data = is_in_core && is_read;
+            let core2pif_has_data = (is_in_core && is_read) || hw_write;
             let pif2core_has_data = is_write;
 
             // build the core2pif and pif2core elements
@@ -467,13 +1347,64 @@ impl GenRegister {
             if core_read_enable {
                 pif2core.push(gen_registersignal(templates, "read_enable","gr_read_enable_name", "boolean", "gr_read_enable_description", &context, pif2core_token_list)?);
             }
+            if core_read_handshake {
+                pif2core.push(gen_registersignal(templates, "read_request", "gr_read_request_name", "boolean", "gr_read_request_description", &context, pif2core_token_list)?);
+                core2pif.push(gen_registersignal(templates, "read_valid", "gr_read_valid_name", "boolean", "gr_read_valid_description", &context, corfe2pif_token_list)?);
+            }
             if core_write_enable {
                 pif2core.push(gen_registersignal(templates, "write_enable", "gr_write_enable_name", "boolean", "gr_write_enable_description", &context, pif2core_token_list)?);
             }
+            if core_byte_enable {
+                pif2core.push(gen_registersignal(templates, "byte_enable", "gr_byte_enable_name", &format!("std_logic_vector({} downto 0)", byte_lane_count - 1), "gr_byte_enable_description", &context, pif2core_token_list)?);
+            }
+
+            if hw_set {
+                core2pif.push(gen_registersignal(templates, "hw_set", "gr_hw_set_name", &hw_mask_type, "gr_hw_set_description", &context, corfe2pif_token_list)?);
+            }
+            if hw_clear {
+                core2pif.push(gen_registersignal(templates, "hw_clear", "gr_hw_clear_name", &hw_mask_type, "gr_hw_clear_description", &context, corfe2pif_token_list)?);
+            }
+            if hw_write {
+                core2pif.push(gen_registersignal(templates, "hw_valid", "gr_hw_valid_name", "boolean", "gr_hw_valid_description", &context, corfe2pif_token_list)?);
+            }
+
+            // FIFO handshake, the write side pushes and the read side pops
+            if let Some(fifo) = &register.fifo {
+                if is_write {
+                    pif2core.push(gen_registersignal(templates, "fifo_push", "gr_fifo_push_name", "boolean", "gr_fifo_push_description", &context, pif2core_token_list)?);
+                    core2pif.push(gen_registersignal(templates, "fifo_full", "gr_fifo_full_name", "boolean", "gr_fifo_full_description", &context, corfe2pif_token_list)?);
+                }
+                if is_read {
+                    pif2core.push(gen_registersignal(templates, "fifo_pop", "gr_fifo_pop_name", "boolean", "gr_fifo_pop_description", &context, pif2core_token_list)?);
+                    core2pif.push(gen_registersignal(templates, "fifo_empty", "gr_fifo_empty_name", "boolean", "gr_fifo_empty_description", &context, corfe2pif_token_list)?);
+                }
+                if fifo.use_level.unwrap_or(false) {
+                    let level_type = format!("unsigned({} downto 0)", fifo.level_width.unwrap_or(8).max(1) - 1);
+                    core2pif.push(gen_registersignal(templates, "fifo_level", "gr_fifo_level_name", &level_type, "gr_fifo_level_description", &context, corfe2pif_token_list)?);
+                }
+                if fifo.blocked_access == Some(mdf::FifoBlockedAccess::Flag) {
+                    if is_write {
+                        pif2core.push(gen_registersignal(templates, "fifo_overflow", "gr_fifo_overflow_name", "boolean", "gr_fifo_overflow_description", &context, pif2core_token_list)?);
+                    }
+                    if is_read {
+                        pif2core.push(gen_registersignal(templates, "fifo_underflow", "gr_fifo_underflow_name", "boolean", "gr_fifo_underflow_description", &context, pif2core_token_list)?);
+                    }
+                }
+            }
 
+            bundle_strobed(&mut core2pif);
+            bundle_strobed(&mut pif2core);
             let core2pif_names = gen_names_map(&core2pif);
             let pif2core_names = gen_names_map(&pif2core);
 
+            // named values
+            let has_enum_values = !register.enum_values.is_empty();
+            let (enum_type_name, rust_enum_name) = if has_enum_values {
+                (general_token_list.generate_token(&templates.render("gr_enum_type_name", &context)?), to_camel_case(&token_name))
+            } else {
+                Default::default()
+            };
+            let enum_values = gen_enum_values(&register.enum_values, "gr_enum_const_name", width, register.signal.unwrap(), settings, templates, &context, general_token_list)?;
             
             let unique_field = GenField {
                 name : Default::default(),
@@ -484,24 +1415,51 @@ impl GenRegister {
                 position,
                 width_const_name,
                 offset_const_name: Default::default(),
+                is_array: false,
+                array_count: 1,
+                array_stride: width,
+                array_type_name: Default::default(),
+                array_stride_const_name: Default::default(),
                 rw_mode,
                 is_read,
                 is_write,
+                is_read_clear,
+                is_write_one_clear,
+                is_write_one_set,
+                is_write_zero_clear,
+                is_write_pulse,
+                is_write_masked,
                 sig_type,
                 sig_type_complete,
                 sig_type_is_bit,
                 sig_type_is_bool,
                 sig_type_is_vector,
                 reset,
+                to_bus,
+                from_bus,
                 is_in_core,
                 core_read_enable,
+                core_read_handshake,
+                core_byte_enable,
+                byte_lane_first,
+                byte_lane_count,
                 core_write_enable,
+                hw_set,
+                hw_clear,
+                hw_write,
+                hw_has_priority,
                 core2pif_has_data,
                 pif2core_has_data,
                 core2pif,
                 core2pif_names,
                 pif2core,
-                pif2core_names
+                pif2core_names,
+                has_enum_values,
+                enum_type_name,
+                rust_enum_name,
+                enum_values,
+                variants: register.variants.clone(),
+                variants_pretty: variants_pretty(&register.variants)
             };
 
             vec![unique_field]
@@ -512,7 +1470,7 @@ impl GenRegister {
                     register.fields.iter().enumerate().map(|(n, field)| GenField::from_field(
                         register, field, PageType::Register(int_num,reg_num, Some(n)),
                         settings, templates, project_token_name, interface_token_name, interface_data_width,
-                        &token_name, general_token_list, corfe2pif_token_list,
+                        use_byte_enables, &token_name, general_token_list, corfe2pif_token_list,
                         pif2core_token_list)).collect::<Result<Vec<GenField>,  Box<dyn Error>>>()?,
                 _ => Err(GenError::new(&page, "wrong value for the page parameter in register call"))?
             }
@@ -527,19 +1485,69 @@ impl GenRegister {
         context.insert("data_width", &interface_data_width);
         
         
-        let stride_count = match &register.address.stride {
-            None => 1,
-            Some(stride) => stride.count.value
-        } as u32;
-
-        let stride_increment = match &register.address.stride {
-            None => interface_data_width,
-            Some(stride) => match stride.increment {
-                None => interface_data_width,
-                Some(increment_value) => increment_value.value as u32
-            }
+        // array dimensions, from the outer to the inner one. An element can be several bus words wide
+        let element_span = register.element_span(interface_data_width, word_increment);
+        let mut stride_dimensions : Vec<GenStrideDimension> = Vec::new();
+        let mut dimension = register.address.stride.as_ref();
+        while let Some(stride) = dimension {
+            stride_dimensions.push(GenStrideDimension {
+                count: stride.count.value as u32,
+                increment: stride.get_increment(element_span) as u32
+            });
+            dimension = stride.inner.as_deref();
+        }
+
+        // first dimension only
+        let stride_count = stride_dimensions.first().map_or(1, |dimension| dimension.count);
+        let stride_increment = stride_dimensions.first().map_or(element_span as u32, |dimension| dimension.increment);
+
+        let (stride_total_count, stride_continuous) = match &register.address.stride {
+            None => (1, true),
+            Some(stride) => (stride.total_count() as u32, stride.span(element_span) == stride.total_count() * element_span)
+        };
+
+        // split register: the words at consecutive addresses, low word first
+        let register_address = register.address.value.ok_or("address not defined")?.value;
+        let register_width = register.get_data_width().unwrap_or(interface_data_width);
+        let words = if is_split {
+            (0..word_count).map(|index| {
+                let offset = index as u128 * word_increment;
+                let msb = u32::min(register_width, (index + 1) * interface_data_width) - 1;
+                let lsb = index * interface_data_width;
+                GenRegisterWord {
+                    index,
+                    address_hex: format!("{:x}", register_address + offset),
+                    offset_hex: format!("{:x}", offset),
+                    msb,
+                    lsb,
+                    byte_first: lsb / 8,
+                    byte_lane_count: msb / 8 - lsb / 8 + 1,
+                    is_last: index + 1 == word_count,
+                }
+            }).collect()
+        } else {
+            Vec::new()
         };
-        let stride_continuous = stride_increment == (interface_data_width + 7)/8;
+
+        // address formula for the documentation, with one index per dimension
+        let address_formula = stride_dimensions.iter().enumerate().fold(format!("0x{}", address_hex),
+            |formula, (n, dimension)| format!("{} + 0x{:x} * i{}", formula, dimension.increment, n));
+        let address_formula = stride_dimensions.iter().enumerate().fold(address_formula,
+            |formula, (n, dimension)| format!("{}, i{} = 0..{}", formula, n, dimension.count.saturating_sub(1)));
+
+        // narrow writes need every written bit to be either in the pif, or in the core with byte enables
+        let narrow_write = use_byte_enables && !is_fifo && !is_split
+            && fields.iter().any(|field| field.is_write)
+            && fields.iter().filter(|field| field.is_write).all(|field| !field.is_in_core || field.core_byte_enable);
+
+        // shadowed register: the commit is either a write to another register or a strobe from the core
+        let shadow = register.shadow.clone().unwrap_or_default();
+        let shadow_commit_strobe = is_shadowed && shadow.commit.unwrap_or(mdf::ShadowCommit::Strobe) == mdf::ShadowCommit::Strobe;
+        let shadow_commit_register = if shadow_commit_strobe { String::new() } else { shadow.commit_register.unwrap_or_default() };
+        let mut core2pif : Vec<GenStructSignal> = Default::default();
+        if shadow_commit_strobe {
+            core2pif.push(gen_registersignal(templates, "shadow_commit", "gr_shadow_commit_name", "boolean", "gr_shadow_commit_description", &context, corfe2pif_token_list)?);
+        }
 
         let address_const_name = general_token_list.generate_token(&templates.render("gr_address_const_name", &context)?);
         let stride_count_const_name = general_token_list.generate_token(&templates.render("gr_stride_count_const_name", &context)?);
@@ -552,6 +1560,7 @@ impl GenRegister {
             address_const_name,
             address_hex,
             address_pretty,
+            group,
             is_stride,
             summary,
             description,
@@ -563,14 +1572,34 @@ impl GenRegister {
             stride_count,
             stride_increment,
             stride_continuous,
-            fields})
+            stride_dimensions,
+            stride_total_count,
+            address_formula,
+            is_fifo,
+            narrow_write,
+            fifo_stall,
+            fifo_use_level,
+            fifo_level_width,
+            word_count,
+            is_split,
+            words,
+            is_shadowed,
+            shadow_commit_strobe,
+            shadow_commit_register,
+            commits: Vec::new(),
+            interrupt_block: String::new(),
+            interrupt_function: String::new(),
+            core2pif,
+            fields,
+            variants: register.variants.clone(),
+            variants_pretty: variants_pretty(&register.variants)})
     }
 
 }
 
 impl GenField {
     /// take a Mdf field and convert it to a GenField
-    pub fn from_field(register: &mdf::Register, field: &mdf::Field, page: PageType, _settings: &Settings, templates: &Tera, project_token_name : &String, interface_token_name : &String, interface_data_width: u32, register_token_name : &String, general_token_list : &mut TokenList, corfe2pif_token_list : &mut TokenList, pif2core_token_list : &mut TokenList) -> Result<Self, Box<dyn Error>> {
+    pub fn from_field(register: &mdf::Register, field: &mdf::Field, page: PageType, settings: &Settings, templates: &Tera, project_token_name : &String, interface_token_name : &String, interface_data_width: u32, use_byte_enables: bool, register_token_name : &String, general_token_list : &mut TokenList, corfe2pif_token_list : &mut TokenList, pif2core_token_list : &mut TokenList) -> Result<Self, Box<dyn Error>> {
 
 
         let name = field.name.clone();
@@ -587,10 +1616,19 @@ impl GenField {
             mdf::FieldPosition::Field(_, lsb) => lsb
         };
 
-        let position = if width == 1 {
-            (width-1).to_string()
+        // for a field array, the position covers all the elements
+        let is_array = field.array.is_some();
+        let array_count = field.array_count();
+        let array_stride = field.array_stride();
+        if array_stride < width {
+            Err(GenError::new(&page, "array stride smaller than the field width"))?
+        }
+        let (span_msb, _) = field.span();
+
+        let position = if span_msb == offset {
+            offset.to_string()
         } else {
-            format!("{}..{}", width + offset - 1, offset)
+            format!("{}..{}", span_msb, offset)
         };
 
         // use templates for names and tokens
@@ -604,10 +1642,22 @@ impl GenField {
 
         let width_const_name = general_token_list.generate_token(&templates.render("gf_width_const_name", &context)?);
         let offset_const_name = general_token_list.generate_token(&templates.render("gf_offset_const_name", &context)?);
+        let (array_type_name, array_stride_const_name) = if is_array {
+            (general_token_list.generate_token(&templates.render("gf_array_type_name", &context)?),
+                general_token_list.generate_token(&templates.render("gf_array_stride_const_name", &context)?))
+        } else {
+            Default::default()
+        };
 
         let rw_mode = field.access;
-        let is_read = rw_mode != mdf::AccessType::WO;
-        let is_write = rw_mode != mdf::AccessType::RO;
+        let is_read = rw_mode.is_read();
+        let is_write = rw_mode.is_write();
+        let is_read_clear = rw_mode == mdf::AccessType::RC;
+        let is_write_one_clear = rw_mode == mdf::AccessType::W1C;
+        let is_write_one_set = rw_mode == mdf::AccessType::W1S;
+        let is_write_zero_clear = rw_mode == mdf::AccessType::W0C;
+        let is_write_pulse = rw_mode == mdf::AccessType::WP;
+        let is_write_masked = is_write_one_clear || is_write_one_set || is_write_zero_clear;
             // rw_mode should be a string
         let rw_mode = rw_mode.to_string();
 
@@ -621,28 +1671,39 @@ impl GenField {
         let sig_type_is_bool = field.signal == utils::SignalType::Boolean;
         let sig_type_is_vector = (!sig_type_is_bit) && (!sig_type_is_bool);
 
-        let reset = match field.signal {
-            // the way we format the value depends on the type
-            utils::SignalType::Boolean => match field.reset.value {
-                    0 => "false".to_owned(),
-                    _ => "true".to_owned()
-                },
-
-            utils::SignalType::StdLogic => match field.reset.value {
-                    0 => "'0'".to_owned(),
-                    _ => "'1'".to_owned()
-                },
-                
-            _ => format!("{}x\"{:x}\"",width,field.reset.value)
-        };
+        // the reset value applies to each element, an array gets an aggregate of its array type
+        let reset = vhdl_value_literal(field.reset.value, width, field.signal, settings.vhdl_revision);
+        let reset = if is_array { format!("(others => {})", reset) } else { reset };
+        let (to_bus, from_bus) = vhdl_bus_conversions(field.signal, settings.vhdl_revision);
 
         let location = field.location.unwrap_or(register.location.ok_or(GenError::new(&page, "location needs to be defined for field or register"))?);
         let is_in_core = location == mdf::LocationType::Core;
 
-        let core_read_enable = field.core_signal_properties.use_read_enable.unwrap_or(false);
-        let core_write_enable = field.core_signal_properties.use_write_enable.unwrap_or(false);
+        // a field in the core needs the strobes to implement the read clear and masked write modes
+        let core_read_enable = field.core_signal_properties.use_read_enable.unwrap_or(false) || (is_in_core && is_read_clear);
+        let core_read_handshake = is_in_core && is_read && field.core_signal_properties.use_read_handshake.unwrap_or(false);
+        let core_write_enable = field.core_signal_properties.use_write_enable.unwrap_or(false) || (is_in_core && is_write_masked);
+        // byte enables come with the write enable of a core field, one per byte lane covered by the field
+        let byte_lane_first = offset / 8;
+        let byte_lane_count = span_msb / 8 - byte_lane_first + 1;
+        let core_byte_enable = use_byte_enables && is_in_core && core_write_enable;
+
+        // hardware updates from the core, only for signals in the pif
+        let hw_set = !is_in_core && field.hardware_access.use_set.unwrap_or(false);
+        let hw_clear = !is_in_core && field.hardware_access.use_clear.unwrap_or(false);
+        let hw_write = !is_in_core && field.hardware_access.use_write.unwrap_or(false);
+        let hw_has_priority = field.hardware_access.priority.unwrap_or(mdf::HardwarePriority::Hardware) == mdf::HardwarePriority::Hardware;
+        let hw_mask_type = if is_array {
+            array_type_name.clone()
+        } else if sig_type_is_vector {
+            format!("std_logic_vector({} downto 0)", width-1)
+        } else {
+            sig_type_complete.clone()
+        };
+        // arrays use their own type in the records
+        let record_type = if is_array { array_type_name.clone() } else { sig_type_complete.clone() };
 
-        let core2pif_has_data = is_in_core && is_read;
+        let core2pif_has_data = (is_in_core && is_read) || hw_write;
         let pif2core_has_data = is_write;
 
         // build the core2pif and pif2core elements
@@ -650,21 +1711,50 @@ impl GenField {
         let mut pif2core : Vec<GenStructSignal> = Default::default();
         
         if core2pif_has_data {
-            core2pif.push(gen_registersignal(templates, "data", "gf_data_name", &sig_type_complete, "gf_data_description", &context, corfe2pif_token_list)?);
+            core2pif.push(gen_registersignal(templates, "data", "gf_data_name", &record_type, "gf_data_description", &context, corfe2pif_token_list)?);
         }
         if pif2core_has_data {
-            pif2core.push(gen_registersignal(templates, "data", "gf_data_name", &sig_type_complete, "gf_data_description", &context, pif2core_token_list)?);
+            pif2core.push(gen_registersignal(templates, "data", "gf_data_name", &record_type, "gf_data_description", &context, pif2core_token_list)?);
         }
         if core_read_enable {
             pif2core.push(gen_registersignal(templates, "read_enable", "gf_read_enable_name", "boolean", "gf_read_enable_description", &context, pif2core_token_list)?);
         }
+        if core_read_handshake {
+            pif2core.push(gen_registersignal(templates, "read_request", "gf_read_request_name", "boolean", "gf_read_request_description", &context, pif2core_token_list)?);
+            core2pif.push(gen_registersignal(templates, "read_valid", "gf_read_valid_name", "boolean", "gf_read_valid_description", &context, corfe2pif_token_list)?);
+        }
         if core_write_enable {
             pif2core.push(gen_registersignal(templates, "write_enable", "gf_write_enable_name", "boolean", "gf_write_enable_description", &context, pif2core_token_list)?);
         }
+        if core_byte_enable {
+            pif2core.push(gen_registersignal(templates, "byte_enable", "gf_byte_enable_name", &format!("std_logic_vector({} downto 0)", byte_lane_count - 1), "gf_byte_enable_description", &context, pif2core_token_list)?);
+        }
 
+        if hw_set {
+            core2pif.push(gen_registersignal(templates, "hw_set", "gf_hw_set_name", &hw_mask_type, "gf_hw_set_description", &context, corfe2pif_token_list)?);
+        }
+        if hw_clear {
+            core2pif.push(gen_registersignal(templates, "hw_clear", "gf_hw_clear_name", &hw_mask_type, "gf_hw_clear_description", &context, corfe2pif_token_list)?);
+        }
+        if hw_write {
+            core2pif.push(gen_registersignal(templates, "hw_valid", "gf_hw_valid_name", "boolean", "gf_hw_valid_description", &context, corfe2pif_token_list)?);
+        }
+
+        bundle_strobed(&mut core2pif);
+        bundle_strobed(&mut pif2core);
         let core2pif_names = gen_names_map(&core2pif);
         let pif2core_names = gen_names_map(&pif2core);
 
+        // named values
+        let has_enum_values = !field.enum_values.is_empty();
+        let (enum_type_name, rust_enum_name) = if has_enum_values {
+            (general_token_list.generate_token(&templates.render("gf_enum_type_name", &context)?), 
+                to_camel_case(&format!("{}_{}", register_token_name, token_name)))
+        } else {
+            Default::default()
+        };
+        let enum_values = gen_enum_values(&field.enum_values, "gf_enum_const_name", width, field.signal, settings, templates, &context, general_token_list)?;
+
         Ok(GenField {
             name,
             description,
@@ -674,24 +1764,51 @@ impl GenField {
             position,
             width_const_name,
             offset_const_name,
+            is_array,
+            array_count,
+            array_stride,
+            array_type_name,
+            array_stride_const_name,
             rw_mode,
             is_read,
             is_write,
+            is_read_clear,
+            is_write_one_clear,
+            is_write_one_set,
+            is_write_zero_clear,
+            is_write_pulse,
+            is_write_masked,
             sig_type,
             sig_type_complete,
             sig_type_is_bit,
             sig_type_is_bool,
             sig_type_is_vector,
             reset,
+            to_bus,
+            from_bus,
             is_in_core,
             core_read_enable,
+            core_read_handshake,
+            core_byte_enable,
+            byte_lane_first,
+            byte_lane_count,
             core_write_enable,
+            hw_set,
+            hw_clear,
+            hw_write,
+            hw_has_priority,
             core2pif_has_data,
             pif2core_has_data,
             core2pif,
             core2pif_names,
             pif2core,
-            pif2core_names
+            pif2core_names,
+            has_enum_values,
+            enum_type_name,
+            rust_enum_name,
+            enum_values,
+            variants: field.variants.clone(),
+            variants_pretty: variants_pretty(&field.variants)
         })
     }
 }
@@ -9,6 +9,7 @@ use crate::settings::Settings;
 use crate::page::PageType;
 use crate::keys::KeyAction;
 use crate::gui_blocks;
+use crate::validation;
 use tera::Tera;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -23,6 +24,13 @@ use std::io::Write;
 #[cfg(not(target_arch = "wasm32"))]
 /// Called from the menu to generate the files
 async fn gen_all(model: Arc<Mdf>, settings: Settings, templates: Tera, mut status: Signal<Option<Result<(), String>>>, _gen_doc : bool, _gen_code : bool) {
+    // report all the problems at once instead of the first one found by the generation
+    let diagnostics = validation::validate(&model);
+    if validation::has_errors(&diagnostics) {
+        status.set(Some(Err(format!("The model has errors:\n{}", validation::error_summary(&diagnostics)))));
+        return;
+    }
+
     // open file dialog to choose file name
     let file = AsyncFileDialog::new()
         .add_filter("word document", &["html"])
@@ -38,7 +46,7 @@ async fn gen_all(model: Arc<Mdf>, settings: Settings, templates: Tera, mut statu
             Ok(mut file) => {
 
                 // create the generation model and write it directly for now
-                match GenModel::from_model(&model, &settings, &templates) {
+                match GenModel::from_model(&model, &settings, &templates, None) {
                     Ok(model) => {
                         match documentation::generate_doc(&model, &templates) {
                             Ok(result_doc) => match file.write_all(result_doc.as_bytes()) {
@@ -325,6 +325,51 @@ const AXI4L_TEMPLATES : SignalTemplates<'static> = SignalTemplates {
     template_out : "s_{{ interface }}_{{ signal }}*",
 };
 
+/// byte enable signal for SBI, an extension of the protocol
+const SBI_BYTE_ENABLE : SignalDef<'static> = SignalDef {
+    token_name : "ben",
+    type_template : "std_logic_vector({{ strobe_width - 1 }} downto 0)",
+    direction : SignalDirection::In,
+    description : "byte enables",
+    xilinx_attr : ""
+};
+
+/// write strobe signal for APB, as defined in APB4
+const APB_BYTE_ENABLE : SignalDef<'static> = SignalDef {
+    token_name : "pstrb",
+    type_template : "std_logic_vector({{ strobe_width - 1 }} downto 0)",
+    direction : SignalDirection::In,
+    description : "write strobes",
+    xilinx_attr : ""
+};
+
+/// byte enable signal for Avalon memory mapped
+const AVALON_BYTE_ENABLE : SignalDef<'static> = SignalDef {
+    token_name : "byteenable",
+    type_template : "std_logic_vector({{ strobe_width - 1 }} downto 0)",
+    direction : SignalDirection::In,
+    description : "byte enables",
+    xilinx_attr : ""
+};
+
+/// wait request signal for Avalon memory mapped, holding the bus while an access isn't complete
+const AVALON_WAIT_REQUEST : SignalDef<'static> = SignalDef {
+    token_name : "waitrequest",
+    type_template : "std_logic",
+    direction : SignalDirection::Out,
+    description : "wait request",
+    xilinx_attr : ""
+};
+
+/// write strobe signal for AXI4 light
+const AXI4L_BYTE_ENABLE : SignalDef<'static> = SignalDef {
+    token_name : "wstrb",
+    type_template : "std_logic_vector({{ strobe_width - 1 }} downto 0)",
+    direction : SignalDirection::In,
+    description : "write data channel strobes",
+    xilinx_attr : ""
+};
+
 /// generate a GenIntPort from a signal definition
 pub fn to_gen_int_port(definition: &SignalDef::<'_>, templates: &SignalTemplates::<'_>, context: &tera::Context, general_token_list : &mut tokenlist::TokenList) -> Result<genmodel::GenIntPort, Box<dyn Error>> {
     let mut new_context = context.clone();
@@ -359,16 +404,27 @@ pub fn to_gen_int_port(definition: &SignalDef::<'_>, templates: &SignalTemplates
 }
 
 /// generate a port list for the given interface type
-pub fn to_port_list(interface_type : mdf::InterfaceType, context: &tera::Context, general_token_list : &mut tokenlist::TokenList) -> Result<Vec<genmodel::GenIntPort>, Box<dyn Error>> {
+/// the byte enables signal is only added if use_byte_enables is true. The context must have the strobe_width
+pub fn to_port_list(interface_type : mdf::InterfaceType, use_byte_enables : bool, context: &tera::Context, general_token_list : &mut tokenlist::TokenList) -> Result<Vec<genmodel::GenIntPort>, Box<dyn Error>> {
     // choose the right definitions list and name templates
     
-    let (defs, templates) = match interface_type {
-        mdf::InterfaceType::SBI => (SBI_SIGNALS.iter(), &SBI_TEMPLATES),
-        mdf::InterfaceType::APB3 => (APB3_SIGNALS.iter(), &APB3_TEMPLATES),
-        mdf::InterfaceType::AvalonMm => (AVALON_SIGNALS.iter(), &AVALON_TEMPLATES),
-        mdf::InterfaceType::AXI4Light => (AXI4L_SIGNALS.iter(), &AXI4L_TEMPLATES)
+    let (defs, byte_enable, templates) = match interface_type {
+        mdf::InterfaceType::SBI => (SBI_SIGNALS.iter(), &SBI_BYTE_ENABLE, &SBI_TEMPLATES),
+        mdf::InterfaceType::APB3 => (APB3_SIGNALS.iter(), &APB_BYTE_ENABLE, &APB3_TEMPLATES),
+        mdf::InterfaceType::AvalonMm => (AVALON_SIGNALS.iter(), &AVALON_BYTE_ENABLE, &AVALON_TEMPLATES),
+        mdf::InterfaceType::AXI4Light => (AXI4L_SIGNALS.iter(), &AXI4L_BYTE_ENABLE, &AXI4L_TEMPLATES)
     };
+    let byte_enable = if use_byte_enables { Some(byte_enable) } else { None };
     
     // apply the templates to the signal list and return it
-    defs.map(|x| to_gen_int_port(x, templates, &context, general_token_list)).collect()
+    defs.chain(byte_enable).map(|x| to_gen_int_port(x, templates, &context, general_token_list)).collect()
+}
+
+/// generate the port needed to hold the bus during an access with wait states, if the interface type doesn't
+/// already have one in its signals list. Only Avalon memory mapped needs it
+pub fn to_wait_port(interface_type : mdf::InterfaceType, context: &tera::Context, general_token_list : &mut tokenlist::TokenList) -> Result<Option<genmodel::GenIntPort>, Box<dyn Error>> {
+    match interface_type {
+        mdf::InterfaceType::AvalonMm => Ok(Some(to_gen_int_port(&AVALON_WAIT_REQUEST, &AVALON_TEMPLATES, context, general_token_list)?)),
+        _ => Ok(None)
+    }
 }
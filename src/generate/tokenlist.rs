@@ -79,6 +79,17 @@ pub fn to_vhdl_token(unicode: &str) -> String {
     vhdl_token
 }
 
+/// convert a vhdl token to a camel case identifier, as used for type names in Rust
+pub fn to_camel_case(token: &str) -> String {
+    token.split('_').filter(|word| !word.is_empty()).map(|word| {
+        let mut chars = word.chars();
+        match chars.next() {
+            None => String::new(),
+            Some(first) => first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+        }
+    }).collect()
+}
+
 /// Holds a list of tokens, including the reserved words, to be sure to generate valid vhdl identifiers
 pub struct TokenList {
     list : HashSet<String>
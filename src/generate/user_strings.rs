@@ -13,6 +13,7 @@ pub const GM_TOP_NAME : &str = "gm_top_name";
 pub const GM_CORE_NAME : &str = "gm_core_name";
 pub const GM_CORE_INSTANCE : &str = "gm_core_instance";
 pub const GM_PKG_NAME : &str = "gm_pkg_name";
+pub const GM_PARAMETER_CONST_NAME : &str = "gm_parameter_const_name";
 pub const GI_PIF_NAME : &str = "gi_pif_name";
 pub const GI_PIF_INSTANCE : &str = "gi_pif_instance";
 pub const GI_CORE2PIF_NAME : &str = "gi_core2pif_name";
@@ -22,6 +23,9 @@ pub const GI_ADDRESS_DECODER_NAME : &str = "gi_address_decoder_name";
 pub const GI_ADDRESS_STRIDE_FUNC_NAME  : &str = "gi_address_stride_func_name";
 pub const GI_ADDRESS_WIDTH_CONST_NAME  : &str = "gi_address_width_const_name";
 pub const GI_DATA_WIDTH_CONST_NAME : &str = "gi_data_width_const_name";
+pub const GI_IRQ_NAME : &str = "gi_irq_name";
+pub const GI_CLOCK_NAME : &str = "gi_clock_name";
+pub const GI_RESET_NAME : &str = "gi_reset_name";
 pub const GR_ADDRESS_CONST_NAME  : &str = "gr_address_const_name";
 pub const GR_STRIDE_COUNT_CONST_NAME : &str = "gr_stride_count_const_name";
 pub const GR_STRIDE_OFFSET_CONST_NAME : &str = "gr_stride_offset_const_name";
@@ -31,22 +35,81 @@ pub const GR_DATA_NAME : &str = "gr_data_name";
 pub const GR_DATA_DESCRIPTION : &str = "gr_data_description";
 pub const GR_READ_ENABLE_NAME : &str = "gr_read_enable_name";
 pub const GR_READ_ENABLE_DESCRIPTION : &str = "gr_read_enable_description";
+pub const GR_READ_REQUEST_NAME : &str = "gr_read_request_name";
+pub const GR_READ_REQUEST_DESCRIPTION : &str = "gr_read_request_description";
+pub const GR_READ_VALID_NAME : &str = "gr_read_valid_name";
+pub const GR_READ_VALID_DESCRIPTION : &str = "gr_read_valid_description";
 pub const GR_WRITE_ENABLE_NAME : &str = "gr_write_enable_name";
 pub const GR_WRITE_ENABLE_DESCRIPTION : &str = "gr_write_enable_description";
+pub const GR_BYTE_ENABLE_NAME : &str = "gr_byte_enable_name";
+pub const GR_BYTE_ENABLE_DESCRIPTION : &str = "gr_byte_enable_description";
+pub const GR_HW_SET_NAME : &str = "gr_hw_set_name";
+pub const GR_HW_SET_DESCRIPTION : &str = "gr_hw_set_description";
+pub const GR_HW_CLEAR_NAME : &str = "gr_hw_clear_name";
+pub const GR_HW_CLEAR_DESCRIPTION : &str = "gr_hw_clear_description";
+pub const GR_HW_VALID_NAME : &str = "gr_hw_valid_name";
+pub const GR_HW_VALID_DESCRIPTION : &str = "gr_hw_valid_description";
+pub const GR_FIFO_PUSH_NAME : &str = "gr_fifo_push_name";
+pub const GR_FIFO_PUSH_DESCRIPTION : &str = "gr_fifo_push_description";
+pub const GR_FIFO_POP_NAME : &str = "gr_fifo_pop_name";
+pub const GR_FIFO_POP_DESCRIPTION : &str = "gr_fifo_pop_description";
+pub const GR_FIFO_FULL_NAME : &str = "gr_fifo_full_name";
+pub const GR_FIFO_FULL_DESCRIPTION : &str = "gr_fifo_full_description";
+pub const GR_FIFO_EMPTY_NAME : &str = "gr_fifo_empty_name";
+pub const GR_FIFO_EMPTY_DESCRIPTION : &str = "gr_fifo_empty_description";
+pub const GR_FIFO_LEVEL_NAME : &str = "gr_fifo_level_name";
+pub const GR_FIFO_LEVEL_DESCRIPTION : &str = "gr_fifo_level_description";
+pub const GR_FIFO_OVERFLOW_NAME : &str = "gr_fifo_overflow_name";
+pub const GR_FIFO_OVERFLOW_DESCRIPTION : &str = "gr_fifo_overflow_description";
+pub const GR_FIFO_UNDERFLOW_NAME : &str = "gr_fifo_underflow_name";
+pub const GR_FIFO_UNDERFLOW_DESCRIPTION : &str = "gr_fifo_underflow_description";
+pub const GR_SHADOW_COMMIT_NAME : &str = "gr_shadow_commit_name";
+pub const GR_SHADOW_COMMIT_DESCRIPTION : &str = "gr_shadow_commit_description";
+pub const GR_ENUM_TYPE_NAME : &str = "gr_enum_type_name";
+pub const GR_ENUM_CONST_NAME : &str = "gr_enum_const_name";
+pub const GMEM_ADDRESS_CONST_NAME : &str = "gmem_address_const_name";
+pub const GMEM_DEPTH_CONST_NAME : &str = "gmem_depth_const_name";
+pub const GMEM_ADDRESS_NAME : &str = "gmem_address_name";
+pub const GMEM_ADDRESS_DESCRIPTION : &str = "gmem_address_description";
+pub const GMEM_WRITE_DATA_NAME : &str = "gmem_write_data_name";
+pub const GMEM_WRITE_DATA_DESCRIPTION : &str = "gmem_write_data_description";
+pub const GMEM_WRITE_ENABLE_NAME : &str = "gmem_write_enable_name";
+pub const GMEM_WRITE_ENABLE_DESCRIPTION : &str = "gmem_write_enable_description";
+pub const GMEM_READ_ENABLE_NAME : &str = "gmem_read_enable_name";
+pub const GMEM_READ_ENABLE_DESCRIPTION : &str = "gmem_read_enable_description";
+pub const GMEM_READ_DATA_NAME : &str = "gmem_read_data_name";
+pub const GMEM_READ_DATA_DESCRIPTION : &str = "gmem_read_data_description";
 pub const GF_WIDTH_CONST_NAME : &str = "gf_width_const_name";
 pub const GF_OFFSET_CONST_NAME : &str = "gf_offset_const_name";
+pub const GF_ARRAY_TYPE_NAME : &str = "gf_array_type_name";
+pub const GF_ARRAY_STRIDE_CONST_NAME : &str = "gf_array_stride_const_name";
 pub const GF_DATA_NAME : &str = "gf_data_name";
 pub const GF_DATA_DESCRIPTION : &str = "gf_data_description";
 pub const GF_READ_ENABLE_NAME : &str = "gf_read_enable_name";
 pub const GF_READ_ENABLE_DESCRIPTION : &str = "gf_read_enable_description";
+pub const GF_READ_REQUEST_NAME : &str = "gf_read_request_name";
+pub const GF_READ_REQUEST_DESCRIPTION : &str = "gf_read_request_description";
+pub const GF_READ_VALID_NAME : &str = "gf_read_valid_name";
+pub const GF_READ_VALID_DESCRIPTION : &str = "gf_read_valid_description";
 pub const GF_WRITE_ENABLE_NAME : &str = "gf_write_enable_name";
 pub const GF_WRITE_ENABLE_DESCRIPTION  : &str = "gf_write_enable_description";
+pub const GF_BYTE_ENABLE_NAME : &str = "gf_byte_enable_name";
+pub const GF_BYTE_ENABLE_DESCRIPTION : &str = "gf_byte_enable_description";
+pub const GF_HW_SET_NAME : &str = "gf_hw_set_name";
+pub const GF_HW_SET_DESCRIPTION : &str = "gf_hw_set_description";
+pub const GF_HW_CLEAR_NAME : &str = "gf_hw_clear_name";
+pub const GF_HW_CLEAR_DESCRIPTION : &str = "gf_hw_clear_description";
+pub const GF_HW_VALID_NAME : &str = "gf_hw_valid_name";
+pub const GF_HW_VALID_DESCRIPTION : &str = "gf_hw_valid_description";
+pub const GF_ENUM_TYPE_NAME : &str = "gf_enum_type_name";
+pub const GF_ENUM_CONST_NAME : &str = "gf_enum_const_name";
 
-pub const USER_NAMES_SPECS : [UserStringSpec; 26] = [
+pub const USER_NAMES_SPECS : [UserStringSpec; 63] = [
     UserStringSpec { template_name: GM_TOP_NAME, label: "Top", default_value: "{{ project }}*", description: "Name of the top entity, instanciating the core and the PIFs" },
     UserStringSpec { template_name: GM_CORE_NAME, label: "Core", default_value: "{{ project }}*_core", description: "Name of the core entity, containing user code" },
     UserStringSpec { template_name: GM_CORE_INSTANCE, label: "Core instance", default_value: "i_{{ project }}*_core_0", description: "Name of the core instance in the top entity" },
     UserStringSpec { template_name: GM_PKG_NAME, label: "Package", default_value: "{{ project }}*_pkg", description: "Name of the package containing all the definitions" },
+    UserStringSpec { template_name: GM_PARAMETER_CONST_NAME, label: "Parameter", default_value: "c_{{ project }}_{{ parameter }}*", description: "Name of the constant containing an exported project parameter" },
     UserStringSpec { template_name: GI_PIF_NAME, label: "Pif", default_value: "{{ project }}_{{ interface }}*_pif", description: "Name of the pif (processor interface) entity, containing the interface generated code" },
     UserStringSpec { template_name: GI_PIF_INSTANCE, label: "Pif instance", default_value: "i_{{ project }}_{{ interface }}*_pif_0", description: "Name of the pif instance in the top entity" },
     UserStringSpec { template_name: GI_CORE2PIF_NAME, label: "Core to pif", default_value: "{{ interface }}*_core2pif", description: "Name of the record containing the signals from the core to the pif" },
@@ -56,6 +119,9 @@ pub const USER_NAMES_SPECS : [UserStringSpec; 26] = [
     UserStringSpec { template_name: GI_ADDRESS_STRIDE_FUNC_NAME, label: "Address stride", default_value: "f_{{ interface }}*_address_stride", description: "Name of the function decoding the stride number for a register" },
     UserStringSpec { template_name: GI_ADDRESS_WIDTH_CONST_NAME, label: "Address width", default_value: "c_{{ interface }}*_address_width", description: "Name of the constant containing the size of the address bus" },
     UserStringSpec { template_name: GI_DATA_WIDTH_CONST_NAME, label: "Data width", default_value: "c_{{ interface }}*_data_width", description: "Name of the constant containing the size of the data bus" },
+    UserStringSpec { template_name: GI_IRQ_NAME, label: "Interrupt request", default_value: "{{ interface }}_{{ block }}_irq*", description: "Name of the interrupt request output port of an interrupt block" },
+    UserStringSpec { template_name: GI_CLOCK_NAME, label: "Bus clock", default_value: "{{ interface }}_bus_clk*", description: "Name of the bus clock input port of an interface with a separate clock" },
+    UserStringSpec { template_name: GI_RESET_NAME, label: "Bus reset", default_value: "{{ interface }}_bus_rst*", description: "Name of the bus reset input port of an interface with a separate clock" },
     UserStringSpec { template_name: GR_ADDRESS_CONST_NAME, label: "Register address", default_value: "c_{{ project }}_{{ interface }}_{{ register }}*_addr", description: "Name of the constant containing the register address" },
     UserStringSpec { template_name: GR_STRIDE_COUNT_CONST_NAME, label: "Stride count", default_value: "c_{{ project }}_{{ interface }}_{{ register }}*_count", description: "Name of the constant containing the register stride number" },
     UserStringSpec { template_name: GR_STRIDE_OFFSET_CONST_NAME, label: "Stride offset", default_value: "c_{{ project }}_{{ interface }}_{{ register }}*_offset", description: "Name of the constant containing the register stride offset" },
@@ -63,21 +129,79 @@ pub const USER_NAMES_SPECS : [UserStringSpec; 26] = [
     UserStringSpec { template_name: GR_WIDTH_CONST_NAME, label: "Register width", default_value: "c_{{ project }}_{{ interface }}_{{ register }}*_width", description: "Name of the constant contining the register width" },
     UserStringSpec { template_name: GR_DATA_NAME, label: "Register name", default_value: "{{ register }}*", description: "Name of the register in the core2pif and pif2core records" },
     UserStringSpec { template_name: GR_READ_ENABLE_NAME, label: "Register read enable", default_value: "{{ register }}_re*", description: "Name of the register read enable signal in the pif2core record" },
+    UserStringSpec { template_name: GR_READ_REQUEST_NAME, label: "Register read request", default_value: "{{ register }}_rreq*", description: "Name of the register read request signal in the pif2core record" },
+    UserStringSpec { template_name: GR_READ_VALID_NAME, label: "Register read valid", default_value: "{{ register }}_rvld*", description: "Name of the register read valid signal in the core2pif record" },
     UserStringSpec { template_name: GR_WRITE_ENABLE_NAME, label: "Register write enable", default_value: "{{ register }}_we*", description: "Name of the register write enable signal in the pif2core record" },
+    UserStringSpec { template_name: GR_BYTE_ENABLE_NAME, label: "Register byte enables", default_value: "{{ register }}_be*", description: "Name of the register byte enables signal in the pif2core record" },
+    UserStringSpec { template_name: GR_HW_SET_NAME, label: "Register hardware set", default_value: "{{ register }}_set*", description: "Name of the signal in the core2pif record setting bits in the register" },
+    UserStringSpec { template_name: GR_HW_CLEAR_NAME, label: "Register hardware clear", default_value: "{{ register }}_clr*", description: "Name of the signal in the core2pif record clearing bits in the register" },
+    UserStringSpec { template_name: GR_HW_VALID_NAME, label: "Register hardware valid", default_value: "{{ register }}_vld*", description: "Name of the signal in the core2pif record validating a write of the register by the core" },
+    UserStringSpec { template_name: GR_FIFO_PUSH_NAME, label: "Register FIFO push", default_value: "{{ register }}_push*", description: "Name of the signal in the pif2core record pushing the written data in the FIFO" },
+    UserStringSpec { template_name: GR_FIFO_POP_NAME, label: "Register FIFO pop", default_value: "{{ register }}_pop*", description: "Name of the signal in the pif2core record popping the read data from the FIFO" },
+    UserStringSpec { template_name: GR_FIFO_FULL_NAME, label: "Register FIFO full", default_value: "{{ register }}_full*", description: "Name of the signal in the core2pif record indicating that the FIFO is full" },
+    UserStringSpec { template_name: GR_FIFO_EMPTY_NAME, label: "Register FIFO empty", default_value: "{{ register }}_empty*", description: "Name of the signal in the core2pif record indicating that the FIFO is empty" },
+    UserStringSpec { template_name: GR_FIFO_LEVEL_NAME, label: "Register FIFO level", default_value: "{{ register }}_level*", description: "Name of the signal in the core2pif record with the number of words in the FIFO" },
+    UserStringSpec { template_name: GR_FIFO_OVERFLOW_NAME, label: "Register FIFO overflow", default_value: "{{ register }}_ovf*", description: "Name of the signal in the pif2core record indicating a write to a full FIFO" },
+    UserStringSpec { template_name: GR_FIFO_UNDERFLOW_NAME, label: "Register FIFO underflow", default_value: "{{ register }}_udf*", description: "Name of the signal in the pif2core record indicating a read from an empty FIFO" },
+    UserStringSpec { template_name: GR_SHADOW_COMMIT_NAME, label: "Register shadow commit", default_value: "{{ register }}_commit*", description: "Name of the signal in the core2pif record copying the shadow copy of the register to its active copy" },
+    UserStringSpec { template_name: GR_ENUM_TYPE_NAME, label: "Register values type", default_value: "{{ project }}_{{ interface }}_{{ register }}*_values_t", description: "Name of the type enumerating the register named values" },
+    UserStringSpec { template_name: GR_ENUM_CONST_NAME, label: "Register value", default_value: "c_{{ project }}_{{ interface }}_{{ register }}_{{ value }}*", description: "Name of the constant containing a register named value" },
+    UserStringSpec { template_name: GMEM_ADDRESS_CONST_NAME, label: "Memory address", default_value: "c_{{ project }}_{{ interface }}_{{ memory }}*_addr", description: "Name of the constant containing the memory base address" },
+    UserStringSpec { template_name: GMEM_DEPTH_CONST_NAME, label: "Memory depth", default_value: "c_{{ project }}_{{ interface }}_{{ memory }}*_depth", description: "Name of the constant containing the number of words in the memory" },
+    UserStringSpec { template_name: GMEM_ADDRESS_NAME, label: "Memory address signal", default_value: "{{ memory }}_addr*", description: "Name of the memory word index signal in the pif2core record" },
+    UserStringSpec { template_name: GMEM_WRITE_DATA_NAME, label: "Memory write data", default_value: "{{ memory }}_wdata*", description: "Name of the memory write data signal in the pif2core record" },
+    UserStringSpec { template_name: GMEM_WRITE_ENABLE_NAME, label: "Memory write enable", default_value: "{{ memory }}_we*", description: "Name of the memory write enable signal in the pif2core record" },
+    UserStringSpec { template_name: GMEM_READ_ENABLE_NAME, label: "Memory read enable", default_value: "{{ memory }}_re*", description: "Name of the memory read enable signal in the pif2core record" },
+    UserStringSpec { template_name: GMEM_READ_DATA_NAME, label: "Memory read data", default_value: "{{ memory }}_rdata*", description: "Name of the memory read data signal in the core2pif record" },
     UserStringSpec { template_name: GF_WIDTH_CONST_NAME, label: "Field width", default_value: "c_{{ project }}_{{ interface }}_{{ register }}_{{ field }}*_width", description: "Name of the constant containing the field width" },
     UserStringSpec { template_name: GF_OFFSET_CONST_NAME, label: "Field offset", default_value: "c_{{ project }}_{{ interface }}_{{ register }}_{{ field }}*_offset", description: "Name of the constant containing the field offset" },
+    UserStringSpec { template_name: GF_ARRAY_TYPE_NAME, label: "Field array type", default_value: "{{ project }}_{{ interface }}_{{ register }}_{{ field }}*_array_t", description: "Name of the type for a field array" },
+    UserStringSpec { template_name: GF_ARRAY_STRIDE_CONST_NAME, label: "Field array stride", default_value: "c_{{ project }}_{{ interface }}_{{ register }}_{{ field }}*_stride", description: "Name of the constant containing the number of bits between two elements of a field array" },
     UserStringSpec { template_name: GF_DATA_NAME, label: "Field name", default_value: "{{ field }}*", description: "Name of the field in the register record" },
     UserStringSpec { template_name: GF_READ_ENABLE_NAME, label: "Field read enable", default_value: "{{ field }}_re*", description: "Name of the field read enable signal" },
+    UserStringSpec { template_name: GF_READ_REQUEST_NAME, label: "Field read request", default_value: "{{ field }}_rreq*", description: "Name of the field read request signal" },
+    UserStringSpec { template_name: GF_READ_VALID_NAME, label: "Field read valid", default_value: "{{ field }}_rvld*", description: "Name of the field read valid signal" },
     UserStringSpec { template_name: GF_WRITE_ENABLE_NAME, label: "Field write enable", default_value: "{{ field }}_we*", description: "Name of the field write enable signal" },
+    UserStringSpec { template_name: GF_BYTE_ENABLE_NAME, label: "Field byte enables", default_value: "{{ field }}_be*", description: "Name of the field byte enables signal in the pif2core record" },
+    UserStringSpec { template_name: GF_HW_SET_NAME, label: "Field hardware set", default_value: "{{ field }}_set*", description: "Name of the signal in the core2pif record setting bits in the field" },
+    UserStringSpec { template_name: GF_HW_CLEAR_NAME, label: "Field hardware clear", default_value: "{{ field }}_clr*", description: "Name of the signal in the core2pif record clearing bits in the field" },
+    UserStringSpec { template_name: GF_HW_VALID_NAME, label: "Field hardware valid", default_value: "{{ field }}_vld*", description: "Name of the signal in the core2pif record validating a write of the field by the core" },
+    UserStringSpec { template_name: GF_ENUM_TYPE_NAME, label: "Field values type", default_value: "{{ project }}_{{ interface }}_{{ register }}_{{ field }}*_values_t", description: "Name of the type enumerating the field named values" },
+    UserStringSpec { template_name: GF_ENUM_CONST_NAME, label: "Field value", default_value: "c_{{ project }}_{{ interface }}_{{ register }}_{{ field }}_{{ value }}*", description: "Name of the constant containing a field named value" },
 ];
 
-pub const USER_COMMENTS_SPECS : [UserStringSpec; 6] = [
+pub const USER_COMMENTS_SPECS : [UserStringSpec; 31] = [
     UserStringSpec { template_name: GR_DATA_DESCRIPTION, label: "Register description", default_value: "data for {{ full_name }}", description: "Description for register" },
     UserStringSpec { template_name: GR_READ_ENABLE_DESCRIPTION, label: "Register read enable", default_value: "signals that {{ full_name }} is being read", description: "Description for the register read enable signal" },
+    UserStringSpec { template_name: GR_READ_REQUEST_DESCRIPTION, label: "Register read request", default_value: "requests the value of {{ full_name }}", description: "Description for the register read request signal" },
+    UserStringSpec { template_name: GR_READ_VALID_DESCRIPTION, label: "Register read valid", default_value: "signals that the value of {{ full_name }} is available", description: "Description for the register read valid signal" },
     UserStringSpec { template_name: GR_WRITE_ENABLE_DESCRIPTION, label: "Register write enable", default_value: "signals that {{ full_name }} is being written", description: "Description for the write enable signal" },
+    UserStringSpec { template_name: GR_BYTE_ENABLE_DESCRIPTION, label: "Register byte enables", default_value: "bytes of {{ full_name }} being written", description: "Description for the register byte enables signal" },
+    UserStringSpec { template_name: GR_HW_SET_DESCRIPTION, label: "Register hardware set", default_value: "sets bits in {{ full_name }}", description: "Description for the register hardware set signal" },
+    UserStringSpec { template_name: GR_HW_CLEAR_DESCRIPTION, label: "Register hardware clear", default_value: "clears bits in {{ full_name }}", description: "Description for the register hardware clear signal" },
+    UserStringSpec { template_name: GR_HW_VALID_DESCRIPTION, label: "Register hardware valid", default_value: "signals that the core writes {{ full_name }}", description: "Description for the register hardware valid signal" },
+    UserStringSpec { template_name: GR_FIFO_PUSH_DESCRIPTION, label: "Register FIFO push", default_value: "pushes {{ full_name }} in the FIFO", description: "Description for the FIFO push signal" },
+    UserStringSpec { template_name: GR_FIFO_POP_DESCRIPTION, label: "Register FIFO pop", default_value: "pops {{ full_name }} from the FIFO", description: "Description for the FIFO pop signal" },
+    UserStringSpec { template_name: GR_FIFO_FULL_DESCRIPTION, label: "Register FIFO full", default_value: "signals that the FIFO for {{ full_name }} is full", description: "Description for the FIFO full signal" },
+    UserStringSpec { template_name: GR_FIFO_EMPTY_DESCRIPTION, label: "Register FIFO empty", default_value: "signals that the FIFO for {{ full_name }} is empty", description: "Description for the FIFO empty signal" },
+    UserStringSpec { template_name: GR_FIFO_LEVEL_DESCRIPTION, label: "Register FIFO level", default_value: "number of words in the FIFO for {{ full_name }}", description: "Description for the FIFO level signal" },
+    UserStringSpec { template_name: GR_FIFO_OVERFLOW_DESCRIPTION, label: "Register FIFO overflow", default_value: "signals a dropped write to the full FIFO for {{ full_name }}", description: "Description for the FIFO overflow signal" },
+    UserStringSpec { template_name: GR_FIFO_UNDERFLOW_DESCRIPTION, label: "Register FIFO underflow", default_value: "signals a dropped read from the empty FIFO for {{ full_name }}", description: "Description for the FIFO underflow signal" },
+    UserStringSpec { template_name: GR_SHADOW_COMMIT_DESCRIPTION, label: "Register shadow commit", default_value: "copies the shadow copy of {{ full_name }} to the active copy", description: "Description for the shadow commit signal" },
+    UserStringSpec { template_name: GMEM_ADDRESS_DESCRIPTION, label: "Memory address", default_value: "word index in {{ full_name }}", description: "Description for the memory word index signal" },
+    UserStringSpec { template_name: GMEM_WRITE_DATA_DESCRIPTION, label: "Memory write data", default_value: "data written to {{ full_name }}", description: "Description for the memory write data signal" },
+    UserStringSpec { template_name: GMEM_WRITE_ENABLE_DESCRIPTION, label: "Memory write enable", default_value: "signals a write to {{ full_name }}", description: "Description for the memory write enable signal" },
+    UserStringSpec { template_name: GMEM_READ_ENABLE_DESCRIPTION, label: "Memory read enable", default_value: "signals a read from {{ full_name }}", description: "Description for the memory read enable signal" },
+    UserStringSpec { template_name: GMEM_READ_DATA_DESCRIPTION, label: "Memory read data", default_value: "data read from {{ full_name }}", description: "Description for the memory read data signal" },
     UserStringSpec { template_name: GF_DATA_DESCRIPTION, label: "Field description", default_value: "data for {{ full_name }}", description: "Description for field" },
     UserStringSpec { template_name: GF_READ_ENABLE_DESCRIPTION, label: "Field read enable", default_value: "signals that {{ full_name }} is being read", description: "Description for the field read enable signal" },
+    UserStringSpec { template_name: GF_READ_REQUEST_DESCRIPTION, label: "Field read request", default_value: "requests the value of {{ full_name }}", description: "Description for the field read request signal" },
+    UserStringSpec { template_name: GF_READ_VALID_DESCRIPTION, label: "Field read valid", default_value: "signals that the value of {{ full_name }} is available", description: "Description for the field read valid signal" },
     UserStringSpec { template_name: GF_WRITE_ENABLE_DESCRIPTION, label: "Field write enable", default_value: "signals that {{ full_name }} is being written", description: "Description for the field write enable signal" },
+    UserStringSpec { template_name: GF_BYTE_ENABLE_DESCRIPTION, label: "Field byte enables", default_value: "bytes of {{ full_name }} being written", description: "Description for the field byte enables signal" },
+    UserStringSpec { template_name: GF_HW_SET_DESCRIPTION, label: "Field hardware set", default_value: "sets bits in {{ full_name }}", description: "Description for the field hardware set signal" },
+    UserStringSpec { template_name: GF_HW_CLEAR_DESCRIPTION, label: "Field hardware clear", default_value: "clears bits in {{ full_name }}", description: "Description for the field hardware clear signal" },
+    UserStringSpec { template_name: GF_HW_VALID_DESCRIPTION, label: "Field hardware valid", default_value: "signals that the core writes {{ full_name }}", description: "Description for the field hardware valid signal" },
 ];
 
 fn load_defaults_from_iter(templates_list: &mut BTreeMap<String,String>, iter: Iter<'_, UserStringSpec>) {
@@ -114,6 +114,9 @@ pub fn apply_function<F : 'static>(
         // settings... should never happen
         PageType::Settings(_) => {
         },
+        // validation, address map or compare... should never happen either
+        PageType::Validation | PageType::AddressMap(_) | PageType::Compare => {
+        },
     }
 }
 
@@ -548,6 +551,73 @@ pub fn CheckBox(props: CheckBoxProps) -> Element {
     }
 }
 
+// properties for the variants selection
+#[derive(Props, Clone, PartialEq)]
+pub struct VariantsWidgetProps {
+    app_data: Signal<HdlWizardApp>,
+    gui_label: &'static str,
+    /// names of all the project variants
+    variants: Vec<String>,
+    /// variants including the element, empty for all of them
+    value: Vec<String>,
+    undo_label: Option<&'static str>,
+    update_int: Option<EventHandler<(usize,Vec<String>)>>,
+    update_reg: Option<EventHandler<(usize,usize,Vec<String>)>>,
+    update_field: Option<EventHandler<(usize,usize,usize,Vec<String>)>>,
+}
+
+/// one checkbox per project variant. An element in all the variants stores an empty list
+pub fn VariantsWidget(props: VariantsWidgetProps) -> Element {
+    if props.variants.is_empty() {
+        return None;
+    }
+    let gui_label = props.gui_label;
+    let undo_description = props.undo_label.unwrap_or_default();
+    let app_data = props.app_data;
+    let update_int = props.update_int;
+    let update_reg = props.update_reg;
+    let update_field = props.update_field;
+
+    let selected : Vec<String> = if props.value.is_empty() { props.variants.clone() } else { props.value.clone() };
+    let checkboxes = props.variants.iter().map(|variant| {
+        let checked = selected.contains(variant);
+        let variant = variant.clone();
+        let all_variants = props.variants.clone();
+        let selected = selected.clone();
+        rsx! {
+            label { key: "{variant}", class: "checkbox mr-4",
+                input {
+                    r#type: "checkbox",
+                    onclick: move |_| {
+                        let new_selection : Vec<String> = all_variants.iter()
+                            .filter(|name| (*name == &variant) != selected.contains(name))
+                            .cloned()
+                            .collect();
+                        // an element must be in at least one variant
+                        if !new_selection.is_empty() {
+                            let value = if new_selection.len() == all_variants.len() { Vec::new() } else { new_selection };
+                            apply_function(app_data, value, undo_description, None, update_int, update_reg, update_field);
+                        }
+                    },
+                    checked: "{checked}"
+                }
+                " {variant} "
+            }
+        }
+    });
+
+    rsx! {
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", "{gui_label}" } }
+            div { class: "field-body",
+                div { class: "field",
+                    div { class: "control", {checkboxes} }
+                }
+            }
+        }
+    }
+}
+
 // entry for a menu
 #[component]
 pub fn MenuEntry(key_action : Option<Signal<Option<KeyAction>>>,
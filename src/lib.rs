@@ -25,7 +25,10 @@
 #![cfg_attr(not(debug_assertions), deny(warnings))] // Forbid warnings in release builds
 #![warn(clippy::all, rust_2018_idioms)]
 
+pub mod address_map;
+pub mod diff;
 pub mod app;
+pub mod expression;
 pub mod file_formats;
 pub mod file_io;
 pub mod gui_blocks;
@@ -36,6 +39,7 @@ pub mod page;
 pub mod settings;
 pub mod undo;
 pub mod utils;
+pub mod validation;
 pub mod generate;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod assets;
@@ -1,9 +1,12 @@
 //! functions acting on mdf data
 
 use super::file_formats::mdf;
-use crate::utils::VectorValue;
+use crate::expression;
+use crate::utils::{RadixType, VectorValue};
+use std::collections::HashMap;
 use mdf::Field;
 use mdf::Interface;
+use mdf::InterruptBlock;
 use mdf::LocationType;
 use mdf::Mdf;
 use mdf::Register;
@@ -16,48 +19,389 @@ impl Mdf {
             interface.clean();
         }
     }
+
+    /// evaluate the project parameters, in order. A parameter can use the ones defined before it
+    pub fn parameter_values(&self) -> Result<HashMap<String, u128>, String> {
+        let mut values = HashMap::new();
+        for parameter in &self.parameters {
+            let valid_name = parameter.name.starts_with(|c: char| c.is_ascii_alphabetic())
+                && parameter.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid_name || parameter.name == "clog2" {
+                return Err(format!("Invalid parameter name '{}'", parameter.name));
+            }
+            if values.contains_key(&parameter.name) {
+                return Err(format!("Parameter {} is defined twice", parameter.name));
+            }
+            let value = expression::evaluate(&parameter.value, &values)
+                .map_err(|e| format!("Parameter {}: {}", parameter.name, e))?;
+            values.insert(parameter.name.clone(), value);
+        }
+
+        Ok(values)
+    }
+
+    /// replace the numeric values that have an expression by the result of the expression
+    pub fn evaluate_expressions(&mut self) -> Result<(), String> {
+        let values = self.parameter_values()?;
+        for interface in &mut self.interfaces {
+            interface.evaluate_expressions(&values)?;
+        }
+
+        Ok(())
+    }
+
+    /// assign the addresses of an interface as the generation does, from the evaluated expressions and
+    /// with the interrupt blocks registers, and keep the resulting addresses in the interface
+    pub fn assign_interface_addresses(&mut self, int_num: usize) -> Result<(), String> {
+        let values = self.parameter_values()?;
+        let interface = match self.interfaces.get_mut(int_num) {
+            Some(interface) => interface,
+            None => return Err(format!("No interface number {}", int_num)),
+        };
+        let mut evaluated = interface.clone();
+        evaluated.evaluate_expressions(&values)?;
+        evaluated.expand_interrupt_blocks()?;
+        evaluated.assign_addresses()?;
+
+        // the interrupt blocks registers come after the interface ones, and are dropped here
+        for (register, evaluated) in interface.registers.iter_mut().zip(evaluated.registers) {
+            register.address.value = evaluated.address.value;
+        }
+        for (group, evaluated) in interface.groups.iter_mut().zip(evaluated.groups) {
+            group.base = evaluated.base;
+        }
+        for (memory, evaluated) in interface.memories.iter_mut().zip(evaluated.memories) {
+            memory.address = evaluated.address;
+        }
+
+        Ok(())
+    }
+
+    /// check that all the variants used by interfaces, registers and fields are defined
+    pub fn check_variants(&self) -> Result<(), String> {
+        let defined: Vec<String> = self.variants.iter().map(|variant| variant.name.clone()).collect();
+        for (n, name) in defined.iter().enumerate() {
+            if name.is_empty() || defined[..n].contains(name) {
+                return Err(format!("Invalid or duplicate variant name '{}'", name));
+            }
+        }
+        for interface in &self.interfaces {
+            check_variant_names(&interface.variants, &defined, &format!("Interface {}", interface.name))?;
+            for register in &interface.registers {
+                check_variant_names(&register.variants, &defined, &format!("Register {}", register.name))?;
+                for field in &register.fields {
+                    check_variant_names(
+                        &field.variants,
+                        &defined,
+                        &format!("Field {}.{}", register.name, field.name),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// keep only the interfaces, registers and fields included in the given variant
+    pub fn select_variant(&mut self, variant: &str) -> Result<(), String> {
+        if !self.variants.iter().any(|v| v.name == variant) {
+            return Err(format!("Unknown variant {}", variant));
+        }
+        self.interfaces.retain(|interface| in_variant(&interface.variants, variant));
+        for interface in &mut self.interfaces {
+            interface.select_variant(variant);
+        }
+
+        Ok(())
+    }
+
+    /// variant to generate: the given one if any, else the active variant of the project. None if all the
+    /// variants are generated together
+    pub fn generated_variant(&self, variant: Option<&str>) -> Option<String> {
+        variant.map(str::to_owned).or_else(|| self.active_variant.clone()).filter(|variant| !variant.is_empty())
+    }
+
+    /// replace the list of variants, following the renamed ones and removing the references to
+    /// the deleted ones in interfaces, registers, fields and the active variant
+    pub fn set_variants(&mut self, variants: Vec<mdf::Variant>) {
+        let renames: Vec<(String, String)> = if variants.len() == self.variants.len() {
+            self.variants
+                .iter()
+                .zip(&variants)
+                .filter(|(old, new)| old.name != new.name)
+                .map(|(old, new)| (old.name.clone(), new.name.clone()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let defined: Vec<String> = variants.iter().map(|variant| variant.name.clone()).collect();
+        for interface in &mut self.interfaces {
+            update_variant_names(&mut interface.variants, &renames, &defined);
+            for register in &mut interface.registers {
+                update_variant_names(&mut register.variants, &renames, &defined);
+                for field in &mut register.fields {
+                    update_variant_names(&mut field.variants, &renames, &defined);
+                }
+            }
+        }
+        if let Some(active) = self.active_variant.take() {
+            let active = renames.iter().find(|(old, _)| old == &active).map_or(active, |(_, new)| new.clone());
+            self.active_variant = Some(active).filter(|active| defined.contains(active));
+        }
+        self.variants = variants;
+    }
 }
 
-/// given a hashset that holds all the used addresses in a bus, add to this hashset
-/// the address(es) used by a give register
+/// check that the variants referenced by an element are all defined in the project
+fn check_variant_names(used: &[String], defined: &[String], what: &str) -> Result<(), String> {
+    match used.iter().find(|name| !defined.contains(name)) {
+        Some(name) => Err(format!("{} refers to unknown variant {}", what, name)),
+        None => Ok(()),
+    }
+}
+
+/// true if an element limited to the given variants is included in the variant
+fn in_variant(variants: &[String], variant: &str) -> bool {
+    variants.is_empty() || variants.iter().any(|name| name == variant)
+}
+
+/// rename the variant references of an element, and remove the ones to variants that don't exist anymore
+fn update_variant_names(variants: &mut Vec<String>, renames: &[(String, String)], defined: &[String]) {
+    for name in variants.iter_mut() {
+        if let Some((_, new_name)) = renames.iter().find(|(old_name, _)| old_name == name) {
+            *name = new_name.clone();
+        }
+    }
+    variants.retain(|name| defined.contains(name));
+}
+
+/// evaluate an optional expression, with the name of the model element in the error message
+fn evaluate_opt(expression: &Option<String>, values: &HashMap<String, u128>, what: &str) -> Result<Option<u128>, String> {
+    expression
+        .as_ref()
+        .map(|expression| expression::evaluate(expression, values).map_err(|e| format!("{}: {}", what, e)))
+        .transpose()
+}
+
+/// evaluate an optional expression giving a 32 bits value, such as a width
+fn evaluate_opt_u32(expression: &Option<String>, values: &HashMap<String, u128>, what: &str) -> Result<Option<u32>, String> {
+    evaluate_opt(expression, values, what)?
+        .map(|value| u32::try_from(value).map_err(|_| format!("{}: value {} is too big", what, value)))
+        .transpose()
+}
+
+/// addresses already used in a bus. The reserved ranges are kept as intervals, as they can be very large
+#[derive(Default)]
+struct UsedAddresses {
+    /// addresses of the bus words used by the registers, groups and memories
+    words: std::collections::HashSet<u128>,
+    /// reserved ranges, as their first and last addresses
+    reserved: Vec<(u128, u128)>,
+}
+
+impl UsedAddresses {
+    /// reserved range holding an address, if any
+    fn reserved_at(&self, address: u128) -> Option<(u128, u128)> {
+        self.reserved.iter().copied().find(|(first, last)| (*first..=*last).contains(&address))
+    }
+
+    /// true if the address is already in use
+    fn contains(&self, address: u128) -> bool {
+        self.words.contains(&address) || self.reserved_at(address).is_some()
+    }
+
+    /// reserve a range of addresses, if none of them is already in use
+    fn reserve(&mut self, first: u128, last: u128, what: &str) -> Result<(), String> {
+        let overlaps = self.words.iter().any(|address| (first..=last).contains(address))
+            || self.reserved.iter().any(|(other_first, other_last)| first <= *other_last && *other_first <= last);
+        if overlaps {
+            return Err(format!("{}'s address already in use", what));
+        }
+        self.reserved.push((first, last));
+        Ok(())
+    }
+}
+
+/// add to the used addresses of a bus the addresses base + offsets, if none of them is already in use
+fn add_offsets(
+    addresses: &mut UsedAddresses,
+    base: u128,
+    offsets: &[u128],
+    what: &str,
+) -> Result<(), String> {
+    // go for a complete run first to see if all addresses are available
+    let mut block = Vec::with_capacity(offsets.len());
+    for offset in offsets {
+        match base.checked_add(*offset) {
+            Some(address) if !addresses.contains(address) => block.push(address),
+            Some(_) => return Err(format!("{}'s address already in use", what)),
+            None => return Err(format!("{}'s address is out of range", what)),
+        }
+    }
+    // now add the addresses
+    addresses.words.extend(block);
+
+    Ok(())
+}
+
+/// add to the used addresses of a bus the address(es) used by a give register
 fn add_address(
-    addresses: &mut std::collections::HashSet<u128>,
+    addresses: &mut UsedAddresses,
     register: &mdf::Register,
-    interface_width_bytes: u32,
+    interface_width: u32,
+    word_increment: u128,
 ) -> Result<(), String> {
     if let Some(address) = register.address.value {
-        match &register.address.stride {
-            None => {
-                if addresses.contains(&address.value) {
-                    return Err(format!(
-                        "Register {}'s address already in use",
-                        register.name
-                    ));
-                }
-                addresses.insert(address.value);
+        add_offsets(
+            addresses,
+            address.value,
+            &register.offsets(interface_width, word_increment),
+            &format!("Register {}", register.name),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// options of the automatic address allocation, taken from the interface
+struct Allocation {
+    /// interface data width in bits
+    width_bits: u32,
+    /// address increment between two bus words
+    word_increment: u128,
+    /// align the register arrays on their size
+    align_arrays: bool,
+    /// place the automatic addresses after the fixed ones
+    keep_gaps: bool,
+}
+
+impl Allocation {
+    /// alignment of a block of addresses, given its offsets. Arrays are aligned on their size if requested
+    fn alignment(&self, offsets: &[u128], is_array: bool) -> u128 {
+        if self.align_arrays && is_array {
+            let span = offsets.iter().max().map_or(0, |max| max + self.word_increment);
+            span.max(self.word_increment).next_power_of_two()
+        } else {
+            self.word_increment
+        }
+    }
+
+    /// round up an address to an alignment larger than a bus word. Smaller alignments keep the
+    /// address as is
+    fn align(&self, address: u128, alignment: u128) -> u128 {
+        if alignment > self.word_increment {
+            (address + alignment - 1) / alignment * alignment
+        } else {
+            address
+        }
+    }
+
+    /// address where the automatic allocation of a list of registers starts: 0, or after the last fixed
+    /// register if the gaps are kept
+    fn first_address<'a>(&self, registers: impl Iterator<Item = &'a Register>) -> u128 {
+        if self.keep_gaps {
+            registers
+                .filter_map(|register| register.high_address(self.width_bits, self.word_increment))
+                .max()
+                .map_or(0, |high| high + self.word_increment)
+        } else {
+            0
+        }
+    }
+}
+
+/// find the first free location for a block of addresses, from a given address and with the given address
+/// step, and reserve it. Returns the base address of the block
+fn place_offsets(
+    addresses: &mut UsedAddresses,
+    from: u128,
+    alignment: u128,
+    offsets: &[u128],
+    what: &str,
+) -> Result<u128, String> {
+    let no_room = || format!("No free address left for {}", what);
+    let alignment = alignment.max(1);
+    let mut current_address = from;
+    loop {
+        // jump over a reserved range in one go instead of trying each address in it
+        let reserved = offsets.iter().find_map(|offset| {
+            let (_, last) = addresses.reserved_at(current_address.checked_add(*offset)?)?;
+            Some(last - offset)
+        });
+        let next_address = match reserved {
+            Some(last_base) => {
+                let skip = (last_base.checked_add(1).ok_or_else(no_room)? - from)
+                    .checked_add(alignment - 1)
+                    .ok_or_else(no_room)?;
+                from.checked_add(skip / alignment * alignment).ok_or_else(no_room)?
             }
-            Some(stride) => {
-                let increment = match stride.increment {
-                    Some(stride_increment) => stride_increment.value,
-                    None => interface_width_bytes as u128,
-                };
-                // go for a complete run first to see if all addresses are available
-                for i in 0..stride.count.value {
-                    let current_address = address.value + i * increment;
-                    if addresses.contains(&current_address) {
-                        return Err(format!(
-                            "Register {}'s address already in use",
-                            register.name
-                        ));
-                    }
-                }
-                // now add the addresses
-                for i in 0..stride.count.value {
-                    let current_address = address.value + i * increment;
-                    addresses.insert(current_address);
-                }
+            None => match add_offsets(addresses, current_address, offsets, what) {
+                Ok(()) => return Ok(current_address),
+                Err(_) => current_address.checked_add(alignment).ok_or_else(no_room)?,
+            },
+        };
+        current_address = next_address;
+    }
+}
+
+/// give an address to a register in the first free location after the current address, if it doesn't already
+/// have one, and return the address following it
+fn place_register(
+    register: &mut Register,
+    addresses: &mut UsedAddresses,
+    current_address: u128,
+    allocation: &Allocation,
+) -> Result<u128, String> {
+    match register.address.value {
+        Some(addr) => {
+            if allocation.keep_gaps {
+                Ok(current_address)
+            } else {
+                // continue after this register's address
+                Ok(addr.value.saturating_add(register.element_span(allocation.width_bits, allocation.word_increment)))
             }
         }
+        None => {
+            let offsets = register.offsets(allocation.width_bits, allocation.word_increment);
+            let alignment = allocation.alignment(&offsets, register.address.stride.is_some());
+            let address = place_offsets(addresses, allocation.align(current_address, alignment), alignment, &offsets, &register.name)?;
+            register.address.value = Some(VectorValue::from(address));
+            Ok(address.saturating_add(register.element_span(allocation.width_bits, allocation.word_increment)))
+        }
+    }
+}
+
+/// give a base address to a group in the first free location after the given address, and return the address
+/// following the group
+fn place_group(
+    group: &mut mdf::RegisterGroup,
+    offsets: &[u128],
+    addresses: &mut UsedAddresses,
+    from: u128,
+    allocation: &Allocation,
+) -> Result<u128, String> {
+    let alignment = allocation.alignment(offsets, group.get_count() > 1);
+    let base = place_offsets(addresses, allocation.align(from, alignment), alignment, offsets, &group.name)?;
+    group.base = Some(VectorValue::from(base));
+    Ok(base.saturating_add(offsets.iter().max().map_or(0, |max| max + allocation.word_increment)))
+}
+
+/// assign addresses to a list of registers, first reserving the fixed ones and then
+/// placing the others in the first free location after the previous register
+fn assign_register_list(
+    registers: &mut [&mut Register],
+    addresses: &mut UsedAddresses,
+    allocation: &Allocation,
+) -> Result<(), String> {
+    // first make a list of all used addresses, to be sure there aren't any duplicates
+    for register in registers.iter() {
+        add_address(addresses, register, allocation.width_bits, allocation.word_increment)?;
+    }
+
+    // now loop within all registers without addresses and assign one to them
+    let mut current_address = allocation.first_address(registers.iter().map(|register| &**register));
+    for register in registers.iter_mut() {
+        current_address = place_register(register, addresses, current_address, allocation)?;
     }
 
     Ok(())
@@ -70,6 +414,48 @@ impl Interface {
         for register in &mut self.registers {
             register.clean();
         }
+        for memory in &mut self.memories {
+            memory.clean();
+        }
+    }
+
+    /// true if the interface is part of the variant
+    pub fn in_variant(&self, variant: &str) -> bool {
+        in_variant(&self.variants, variant)
+    }
+
+    /// only keep the registers and fields of the variant. The addresses aren't changed, so they stay the same
+    /// in all the variants when they are assigned before
+    pub fn select_variant(&mut self, variant: &str) {
+        self.registers.retain(|register| register.in_variant(variant));
+        for register in &mut self.registers {
+            register.fields.retain(|field| in_variant(&field.variants, variant));
+        }
+    }
+
+    /// replace the numeric values of the interface, its registers, groups and memories by the result of their expression
+    pub fn evaluate_expressions(&mut self, values: &HashMap<String, u128>) -> Result<(), String> {
+        let what = format!("Interface {}", self.name);
+        if let Some(width) = evaluate_opt_u32(&self.data_width_expr, values, &what)? {
+            self.data_width = Some(width);
+        }
+        for register in &mut self.registers {
+            register.evaluate_expressions(values)?;
+        }
+        for group in &mut self.groups {
+            let what = format!("Group {}", group.name);
+            if let Some(count) = evaluate_opt(&group.count_expr, values, &what)? {
+                group.count = Some(VectorValue { value: count, radix: RadixType::Decimal });
+            }
+        }
+        for memory in &mut self.memories {
+            let what = format!("Memory {}", memory.name);
+            if let Some(depth) = evaluate_opt_u32(&memory.depth_expr, values, &what)? {
+                memory.depth = depth;
+            }
+        }
+
+        Ok(())
     }
 
     /// returns the interface data width. None if the width can't be determined
@@ -77,14 +463,19 @@ impl Interface {
         match self.data_width {
             Some(width) => Some(width),
             None => {
-                // goes through all registers to find the biggest size
-                self.registers.iter().fold(None, |width, reg| {
+                // goes through all registers and memories to find the biggest size
+                let widths = self
+                    .registers
+                    .iter()
+                    .map(|reg| reg.get_data_width())
+                    .chain(self.memories.iter().map(|memory| memory.width));
+                widths.fold(None, |width, element_width| {
                     // with two Somes, find the maximum. With one None and one Some, return the Some
-                    match reg.get_data_width() {
+                    match element_width {
                         None => width,
-                        Some(reg_width) => match width {
-                            None => Some(reg_width),
-                            Some(previous_width) => Some(u32::max(previous_width, reg_width)),
+                        Some(element_width) => match width {
+                            None => Some(element_width),
+                            Some(previous_width) => Some(u32::max(previous_width, element_width)),
                         },
                     }
                 })
@@ -92,6 +483,17 @@ impl Interface {
         }
     }
 
+    /// unit of the interface addresses
+    pub fn addressing_mode(&self) -> mdf::AddressingMode {
+        self.addressing.unwrap_or(mdf::AddressingMode::Byte)
+    }
+
+    /// address increment between two bus words: the data width in bytes with byte addressing, 1 with word
+    /// addressing. None if the data width can't be determined
+    pub fn get_word_increment(&self) -> Option<u128> {
+        self.get_data_width().map(|width| self.addressing_mode().word_increment(width))
+    }
+
     /// returns the interface address width. If automatic, only works if all addresses have been assigned. Otherwise
     /// returns None
     pub fn get_address_width(&self) -> Option<u32> {
@@ -107,7 +509,7 @@ impl Interface {
                         match high_address {
                             None => None,
                             Some(current_max) => {
-                                match reg.high_address(interface_width) {
+                                match reg.high_address(interface_width, self.addressing_mode().word_increment(interface_width)) {
                                     None => None,
                                     Some(address) => {
                                         Some(u128::max(current_max, address))
@@ -117,6 +519,14 @@ impl Interface {
                         }
                     });
 
+                    // the memories can be higher
+                    let high_address = self.memories.iter().fold(high_address, |high_address, memory| {
+                        match (high_address, memory.high_address(self.addressing_mode().word_increment(interface_width))) {
+                            (Some(current_max), Some(address)) => Some(u128::max(current_max, address)),
+                            _ => None,
+                        }
+                    });
+
                     // convert the highest address into number of bits
                     high_address.map( |address| u128::BITS - address.leading_zeros())
                     }
@@ -128,40 +538,141 @@ impl Interface {
     /// automatically assign addresses to the registers
     /// this is not a very good algorithm. it is rather brute force, but it is simple and won't be called that often any way
     /// it should still be pretty fast in standard projects
+    /// registers within a group get an address relative to the group base, and the groups are then placed
+    /// after the registers that are not in any group
     pub fn assign_addresses(&mut self) -> Result<(), String> {
-        let mut addresses: std::collections::HashSet<u128> = Default::default();
+        let mut addresses = UsedAddresses::default();
         if let Some(width_bits) = self.get_data_width() {
-            // convert width to bytes
-            let width_bytes = (width_bits + 7) / 8;
+            // address increment of a bus word
+            let word_increment = self.addressing_mode().word_increment(width_bits);
+            let allocation = Allocation {
+                width_bits,
+                word_increment,
+                align_arrays: self.align_arrays.unwrap_or(false),
+                keep_gaps: self.keep_gaps.unwrap_or(false),
+            };
 
-            // first make a list of all used addresses, to be sure there aren't any duplicates
+            // check that all the registers refer to an existing group
             for register in &self.registers {
-                add_address(&mut addresses, register, width_bytes)?;
+                if let Some(group_name) = &register.group {
+                    if !self.groups.iter().any(|group| &group.name == group_name) {
+                        return Err(format!(
+                            "Register {} refers to unknown group {}",
+                            register.name, group_name
+                        ));
+                    }
+                }
             }
 
-            // now loop within all registers without addresses and assign one to them
-            let mut current_address: u128 = 0;
-            for register in self.registers.iter_mut() {
-                match register.address.value {
-                    Some(addr) => {
-                        // update current address to after this register's address
-                        current_address = addr.value + width_bytes as u128;
+            // assign the relative addresses within each group, and list the addresses used by the whole group
+            let mut groups_offsets: Vec<Vec<u128>> = Vec::new();
+            for group in &self.groups {
+                let mut group_registers: Vec<&mut Register> = self
+                    .registers
+                    .iter_mut()
+                    .filter(|register| register.group.as_ref() == Some(&group.name))
+                    .collect();
+                assign_register_list(&mut group_registers, &mut Default::default(), &allocation)
+                    .map_err(|e| format!("Group {}: {}", group.name, e))?;
+                let block_offsets = group_block_offsets(&group_registers, width_bits, word_increment);
+                groups_offsets.push(group.offsets(&block_offsets, word_increment));
+            }
+
+            // check the memories and reserve the addresses used by the ones with a fixed address
+            for memory in &self.memories {
+                if memory.depth == 0 || memory.width == Some(0) {
+                    return Err(format!("Memory {} is empty", memory.name));
+                }
+                if memory.width.unwrap_or(width_bits) > width_bits {
+                    return Err(format!(
+                        "Memory {} is wider than interface {}",
+                        memory.name, self.name
+                    ));
+                }
+                if let Some(address) = memory.address {
+                    if address.value % memory.alignment(word_increment) != 0 {
+                        return Err(format!(
+                            "Memory {}'s address must be aligned on 0x{:x}",
+                            memory.name,
+                            memory.alignment(word_increment)
+                        ));
                     }
-                    None => {
-                        // try to add the register at the current address
-                        loop {
-                            register.address.value = Some(VectorValue::from(current_address));
-                            if add_address(&mut addresses, register, width_bytes).is_ok() {
-                                break;
-                            } else {
-                                // try the next one
-                                current_address += width_bytes as u128;
+                    add_offsets(
+                        &mut addresses,
+                        address.value,
+                        &memory.offsets(word_increment),
+                        &format!("Memory {}", memory.name),
+                    )?;
+                }
+            }
+
+            // keep the reserved ranges free
+            for range in &self.reserved {
+                if range.end.value < range.start.value {
+                    return Err(format!("Reserved range {} ends before it starts", range.name));
+                }
+                let first = range.start.value - range.start.value % word_increment;
+                addresses.reserve(first, range.end.value, &format!("Reserved range {}", range.name))?;
+            }
+
+            // reserve the addresses used by the groups with a fixed base
+            for (group, offsets) in self.groups.iter().zip(&groups_offsets) {
+                if let Some(base) = group.base {
+                    add_offsets(&mut addresses, base.value, offsets, &format!("Group {}", group.name))?;
+                }
+            }
+
+            // and the registers outside of any group with a fixed address
+            let Interface { registers, groups, allocation_order, .. } = &mut *self;
+            for register in registers.iter().filter(|register| register.group.is_none()) {
+                add_address(&mut addresses, register, width_bits, word_increment)?;
+            }
+            let first_address = allocation.first_address(registers.iter().filter(|register| register.group.is_none()));
+            let mut current_address = first_address;
+
+            match allocation_order.unwrap_or(mdf::AllocationOrder::ByGroup) {
+                mdf::AllocationOrder::ByGroup => {
+                    // registers outside of any group, then the groups without a base in the first free location
+                    for register in registers.iter_mut().filter(|register| register.group.is_none()) {
+                        current_address = place_register(register, &mut addresses, current_address, &allocation)?;
+                    }
+                }
+                mdf::AllocationOrder::Declaration => {
+                    // registers outside of any group, and each group without a base where its first register is
+                    for register in registers.iter_mut() {
+                        match register.group.clone() {
+                            None => {
+                                current_address = place_register(register, &mut addresses, current_address, &allocation)?;
+                            }
+                            Some(group_name) => {
+                                let group = groups.iter_mut().zip(&groups_offsets)
+                                    .find(|(group, _)| group.name == group_name && group.base.is_none());
+                                if let Some((group, offsets)) = group {
+                                    current_address = place_group(group, offsets, &mut addresses, current_address, &allocation)?;
+                                }
                             }
                         }
                     }
                 }
             }
 
+            // the groups still without a base, because they have no registers or are placed by group
+            for (group, offsets) in groups.iter_mut().zip(&groups_offsets) {
+                if group.base.is_none() {
+                    place_group(group, offsets, &mut addresses, first_address, &allocation)?;
+                }
+            }
+
+            // and the memories without an address in the first free aligned location
+            for memory in self.memories.iter_mut() {
+                if memory.address.is_none() {
+                    let alignment = memory.alignment(word_increment);
+                    let offsets = memory.offsets(word_increment);
+                    let address = place_offsets(&mut addresses, 0, alignment, &offsets, &memory.name)?;
+                    memory.address = Some(VectorValue::from(address));
+                }
+            }
+
             Ok(())
         } else {
             Err(format!(
@@ -171,58 +682,452 @@ impl Interface {
         }
     }
 
-    /// remove all assigned addresses to the registers
-    pub fn deassign_addresses(&mut self) -> Result<(), String> {
+    /// replace the list of groups, keeping the registers references in sync: if a group
+    /// is renamed the registers follow it, and registers in a removed group are moved out
+    pub fn set_groups(&mut self, groups: Vec<mdf::RegisterGroup>) {
+        if groups.len() == self.groups.len() {
+            for (old_group, new_group) in self.groups.iter().zip(&groups) {
+                if old_group.name != new_group.name {
+                    for register in self.registers.iter_mut() {
+                        if register.group.as_ref() == Some(&old_group.name) {
+                            register.group = Some(new_group.name.clone());
+                        }
+                    }
+                }
+            }
+        }
         for register in self.registers.iter_mut() {
+            if let Some(group_name) = &register.group {
+                if !groups.iter().any(|group| &group.name == group_name) {
+                    register.group = None;
+                }
+            }
+        }
+        self.groups = groups;
+    }
+
+    /// remove all assigned addresses to the registers, groups and memories. The pinned ones keep theirs
+    pub fn deassign_addresses(&mut self) -> Result<(), String> {
+        for register in self.registers.iter_mut().filter(|register| !register.pinned.unwrap_or(false)) {
             register.address.value = None;
         }
+        for group in self.groups.iter_mut().filter(|group| !group.pinned.unwrap_or(false)) {
+            group.base = None;
+        }
+        for memory in self.memories.iter_mut().filter(|memory| !memory.pinned.unwrap_or(false)) {
+            memory.address = None;
+        }
+
+        Ok(())
+    }
+
+    /// address increment between two instances of a group, computed from the relative addresses of its registers
+    pub fn get_group_increment(&self, group_num: usize) -> Option<u128> {
+        let group = self.groups.get(group_num)?;
+        let width_bits = self.get_data_width()?;
+        let word_increment = self.addressing_mode().word_increment(width_bits);
+        let group_registers: Vec<&Register> = self
+            .registers
+            .iter()
+            .filter(|register| register.group.as_ref() == Some(&group.name))
+            .collect();
+        let block_offsets = group_block_offsets(&group_registers, width_bits, word_increment);
+        Some(group.get_increment(&block_offsets, word_increment))
+    }
+
+    /// convert the addresses of the registers within a group, relative to the group base, to absolute
+    /// addresses. If the group is repeated, an outer stride dimension is added to its registers.
+    /// Must be called after assign_addresses
+    pub fn flatten_groups(&mut self) -> Result<(), String> {
+        let width_bits = match self.get_data_width() {
+            Some(width_bits) => width_bits,
+            None => {
+                return Err(format!(
+                    "Unable to determine the width of interface {}",
+                    self.name
+                ))
+            }
+        };
+        let word_increment = self.addressing_mode().word_increment(width_bits);
+
+        for group in &self.groups {
+            let base = match group.base {
+                Some(base) => base.value,
+                None => return Err(format!("Group {} has no base address", group.name)),
+            };
+            let mut group_registers: Vec<&mut Register> = self
+                .registers
+                .iter_mut()
+                .filter(|register| register.group.as_ref() == Some(&group.name))
+                .collect();
+            let block_offsets = group_block_offsets(&group_registers, width_bits, word_increment);
+            let count = group.get_count();
+            let increment = group.get_increment(&block_offsets, word_increment);
+            for register in group_registers.iter_mut() {
+                if let Some(address) = register.address.value {
+                    register.address.value = Some(VectorValue::from(base + address.value));
+                }
+                if count > 1 {
+                    register.address.stride = Some(mdf::AddressStride {
+                        count: VectorValue::from(count),
+                        increment: Some(VectorValue::from(increment)),
+                        inner: register.address.stride.take().map(Box::new),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// replace the interrupt blocks by the registers implementing them, added at the end of the registers list
+    pub fn expand_interrupt_blocks(&mut self) -> Result<(), String> {
+        // the width is given or inferred from the other registers, the block registers must not widen it
+        let data_width = self.get_data_width();
+        for block in &self.interrupt_blocks {
+            if let Some(width) = data_width {
+                if block.sources.len() > width as usize {
+                    return Err(format!(
+                        "Interrupt block {} has more sources than the {} bits of interface {}",
+                        block.name, width, self.name
+                    ));
+                }
+            }
+            let mut registers = block.to_registers()?;
+            self.registers.append(&mut registers);
+        }
+        self.interrupt_blocks.clear();
 
         Ok(())
     }
 }
 
+impl mdf::AddressingMode {
+    /// address increment between two consecutive bus words of the given width
+    pub fn word_increment(&self, data_width: u32) -> u128 {
+        match self {
+            mdf::AddressingMode::Byte => ((data_width + 7) / 8) as u128,
+            mdf::AddressingMode::Word => 1,
+        }
+    }
+}
+
+impl mdf::FieldPosition {
+    /// position covering all the bits between two bits, given in any order
+    pub fn from_bits(first: u32, second: u32) -> Self {
+        if first == second {
+            mdf::FieldPosition::Single(first)
+        } else {
+            mdf::FieldPosition::Field(u32::max(first, second), u32::min(first, second))
+        }
+    }
+
+    /// position moved by a number of bits. None if it would go below bit 0
+    pub fn shifted(&self, offset: i64) -> Option<Self> {
+        let shift = |bit: u32| u32::try_from(bit as i64 + offset).ok();
+        match *self {
+            mdf::FieldPosition::Single(bit) => Some(mdf::FieldPosition::Single(shift(bit)?)),
+            mdf::FieldPosition::Field(msb, lsb) => Some(mdf::FieldPosition::Field(shift(msb)?, shift(lsb)?)),
+        }
+    }
+}
+
+impl mdf::AddressStride {
+    /// increment between two elements of this dimension. If not specified, the elements are next to each other
+    pub fn get_increment(&self, word_increment: u128) -> u128 {
+        match self.increment {
+            Some(increment) => increment.value,
+            None => match &self.inner {
+                None => word_increment,
+                Some(inner) => inner.span(word_increment),
+            },
+        }
+    }
+
+    /// size of the address space used by this dimension, from the first address to the end of the last element
+    pub fn span(&self, word_increment: u128) -> u128 {
+        let element_span = match &self.inner {
+            None => word_increment,
+            Some(inner) => inner.span(word_increment),
+        };
+        self.count.value.saturating_sub(1) * self.get_increment(word_increment) + element_span
+    }
+
+    /// total number of elements, including all the dimensions
+    pub fn total_count(&self) -> u128 {
+        match &self.inner {
+            None => self.count.value,
+            Some(inner) => self.count.value * inner.total_count(),
+        }
+    }
+
+    /// address offsets of all the elements, relative to the base address. The inner dimension changes first
+    pub fn offsets(&self, word_increment: u128) -> Vec<u128> {
+        let increment = self.get_increment(word_increment);
+        let inner_offsets = match &self.inner {
+            None => vec![0],
+            Some(inner) => inner.offsets(word_increment),
+        };
+        (0..self.count.value)
+            .flat_map(|i| inner_offsets.iter().map(move |inner_offset| i * increment + inner_offset))
+            .collect()
+    }
+}
+
+/// addresses used by one instance of a group, relative to the group base
+fn group_block_offsets<R: std::ops::Deref<Target = Register>>(registers: &[R], width_bits: u32, word_increment: u128) -> Vec<u128> {
+    registers
+        .iter()
+        .filter_map(|register| {
+            register.address.value.map(|address| {
+                register
+                    .offsets(width_bits, word_increment)
+                    .into_iter()
+                    .map(move |offset| address.value + offset)
+            })
+        })
+        .flatten()
+        .collect()
+}
+
+impl mdf::RegisterGroup {
+    /// number of instances of the group
+    pub fn get_count(&self) -> u128 {
+        self.count.map_or(1, |count| count.value.max(1))
+    }
+
+    /// address increment between two instances of the group. If not specified, the instances are next to each other
+    pub fn get_increment(&self, block_offsets: &[u128], word_increment: u128) -> u128 {
+        match self.increment {
+            Some(increment) => increment.value,
+            None => block_offsets.iter().max().map_or(word_increment, |max| max + word_increment),
+        }
+    }
+
+    /// address offsets used by all the instances of the group, relative to its base
+    pub fn offsets(&self, block_offsets: &[u128], word_increment: u128) -> Vec<u128> {
+        let increment = self.get_increment(block_offsets, word_increment);
+        (0..self.get_count())
+            .flat_map(|i| block_offsets.iter().map(move |offset| i * increment + offset))
+            .collect()
+    }
+}
+
+impl mdf::Memory {
+    /// goes through the memory and removes the options that are not allowed
+    pub fn clean(&mut self) {
+        if !matches!(self.access, mdf::AccessType::RW | mdf::AccessType::RO | mdf::AccessType::WO) {
+            self.access = mdf::AccessType::RW;
+        }
+    }
+
+    /// size of the address range used by the memory, in addresses
+    pub fn span(&self, word_increment: u128) -> u128 {
+        self.depth as u128 * word_increment
+    }
+
+    /// alignment of the memory base address: the memory size rounded up to a power of two
+    pub fn alignment(&self, word_increment: u128) -> u128 {
+        self.span(word_increment).max(word_increment).next_power_of_two()
+    }
+
+    /// address offsets of all the memory words, relative to the base address
+    pub fn offsets(&self, word_increment: u128) -> Vec<u128> {
+        (0..self.depth as u128).map(|word| word * word_increment).collect()
+    }
+
+    /// number of bits needed for the word index within the memory
+    pub fn index_width(&self) -> u32 {
+        u32::max(1, u32::BITS - self.depth.saturating_sub(1).leading_zeros())
+    }
+
+    /// address of the last word of the memory (None if the address isn't assigned)
+    pub fn high_address(&self, word_increment: u128) -> Option<u128> {
+        self.address.map(|address| {
+            address.value + self.span(word_increment).saturating_sub(word_increment)
+        })
+    }
+}
+
+impl InterruptBlock {
+    /// name of the register generated for a given function (status, enable, pending or force)
+    pub fn register_name(&self, function: &str) -> String {
+        format!("{}_{}", self.name, function)
+    }
+
+    /// build one of the block registers, with one field per source
+    fn build_register(
+        &self,
+        function: &str,
+        summary: &str,
+        access: mdf::AccessType,
+        hardware_access: mdf::HardwareAccessProperties,
+    ) -> Register {
+        let fields = self
+            .sources
+            .iter()
+            .enumerate()
+            .map(|(n, source)| Field {
+                name: source.name.clone(),
+                position: mdf::FieldPosition::Single(n as u32),
+                description: source.description.clone(),
+                access,
+                hardware_access: hardware_access.clone(),
+                ..Default::default()
+            })
+            .collect();
+
+        Register {
+            name: self.register_name(function),
+            summary: Some(vec![format!("{} {}", self.name, summary)]),
+            description: self.description.clone(),
+            width: None,
+            access: None,
+            signal: None,
+            reset: None,
+            location: Some(LocationType::Pif),
+            fields,
+            ..Default::default()
+        }
+    }
+
+    /// build the registers implementing the interrupt block
+    pub fn to_registers(&self) -> Result<Vec<Register>, String> {
+        if self.sources.is_empty() {
+            return Err(format!("Interrupt block {} has no sources", self.name));
+        }
+
+        // the core sets the status bits, the software clears them
+        let hardware_set = mdf::HardwareAccessProperties {
+            use_set: Some(true),
+            ..Default::default()
+        };
+
+        let mut registers = vec![
+            self.build_register("status", "interrupt status, write 1 to clear", mdf::AccessType::W1C, hardware_set),
+            self.build_register("enable", "interrupt enable", mdf::AccessType::RW, Default::default()),
+            self.build_register("pending", "pending interrupts (status and enable)", mdf::AccessType::RO, Default::default()),
+        ];
+        if self.use_force.unwrap_or(false) {
+            registers.push(self.build_register("force", "interrupt force, write 1 to set the status", mdf::AccessType::WP, Default::default()));
+        }
+
+        Ok(registers)
+    }
+}
+
 impl Register {
+    /// true if the register is part of the variant. A bitfield register without any field in the variant isn't
+    pub fn in_variant(&self, variant: &str) -> bool {
+        in_variant(&self.variants, variant)
+            && (self.fields.is_empty() || self.fields.iter().any(|field| in_variant(&field.variants, variant)))
+    }
+
     /// goes through the register and removes all extra options that are not
     /// allowed.
     pub fn clean(&mut self) {
         // remove all core properties if register location is not in core
         let register_location = self.location;
+        // and all hardware access properties if it is
         match register_location {
             Some(LocationType::Pif) => {
                 self.core_signal_properties.use_read_enable = None;
                 self.core_signal_properties.use_write_enable = None;
+                self.core_signal_properties.use_read_handshake = None;
+            }
+            Some(LocationType::Core) => {
+                self.hardware_access = Default::default();
             }
             _ => (),
         }
 
+        // a FIFO register is always in the core, and the push and pop strobes replace the enables
+        if self.fifo.is_some() {
+            self.location = Some(LocationType::Core);
+            self.core_signal_properties = Default::default();
+            self.hardware_access = Default::default();
+            if !matches!(self.access, None | Some(mdf::AccessType::RW) | Some(mdf::AccessType::RO) | Some(mdf::AccessType::WO)) {
+                self.access = Some(mdf::AccessType::RW);
+            }
+        }
+
+        // a shadowed register keeps both copies in the pif, and only the commit updates the active copy
+        if self.fifo.is_some() || matches!(self.access, Some(mdf::AccessType::RO) | Some(mdf::AccessType::RC)) {
+            self.shadow = None;
+        }
+        if let Some(shadow) = &mut self.shadow {
+            self.location = Some(LocationType::Pif);
+            self.core_signal_properties = Default::default();
+            self.hardware_access = Default::default();
+            for field in &mut self.fields {
+                field.location = Some(LocationType::Pif);
+            }
+            if shadow.commit != Some(mdf::ShadowCommit::Register) {
+                shadow.commit_register = None;
+            }
+        }
+
         // remove register wide properties if fields are defined
         if !self.fields.is_empty() {
+            self.fifo = None;
             self.width = None;
             self.access = None;
             self.signal = None;
             self.reset = None;
             self.core_signal_properties.use_read_enable = None;
             self.core_signal_properties.use_write_enable = None;
+            self.core_signal_properties.use_read_handshake = None;
+            self.hardware_access = Default::default();
+            self.enum_values.clear();
         }
 
+        let register_location = self.location;
         for field in &mut self.fields {
             field.clean(register_location);
         }
     }
 
+    /// replace the numeric values of the register and its fields by the result of their expression
+    pub fn evaluate_expressions(&mut self, values: &HashMap<String, u128>) -> Result<(), String> {
+        let what = format!("Register {}", self.name);
+        if let Some(width) = evaluate_opt_u32(&self.width_expr, values, &what)? {
+            if self.fields.is_empty() {
+                self.width = Some(width);
+            }
+        }
+        if let Some(address) = evaluate_opt(&self.address_expr, values, &what)? {
+            self.address.value = Some(VectorValue::from(address));
+        }
+        if let Some(count) = evaluate_opt(&self.count_expr, values, &what)? {
+            if count == 0 {
+                return Err(format!("{}: the array can't be empty", what));
+            }
+            let count = VectorValue { value: count, radix: RadixType::Decimal };
+            match &mut self.address.stride {
+                Some(stride) => stride.count = count,
+                None => {
+                    self.address.stride = Some(mdf::AddressStride {
+                        count,
+                        increment: None,
+                        inner: None,
+                    })
+                }
+            }
+        }
+        for field in &mut self.fields {
+            field.evaluate_expressions(values, &what)?;
+        }
+
+        Ok(())
+    }
+
     /// returns the register data size. None means it will use the size of the interface
     pub fn get_data_width(&self) -> Option<u32> {
         match self.signal {
             None => {
                 // this is a bitfield. Find the msb within all the fields
                 let msb = self.fields.iter().fold(0, |width, field| {
-                    u32::max(
-                        width,
-                        match field.position {
-                            mdf::FieldPosition::Single(bitpos) => bitpos,
-                            mdf::FieldPosition::Field(msb, _) => msb,
-                        },
-                    )
+                    u32::max(width, field.span().0)
                 });
                 Some(msb + 1)
             }
@@ -230,35 +1135,90 @@ impl Register {
         }
     }
 
-    /// make sure all the fields are assigned to different bits
+    /// make sure all the fields are assigned to different bits. A field array is moved as a unit
     pub fn assign_fields(&mut self) -> Result<(), String> {
         let mut current_msb = 0;
         for field in self.fields.iter_mut() {
-            match field.position {
-                mdf::FieldPosition::Single(bitnum) => {
-                    if bitnum < current_msb {
-                        field.position = mdf::FieldPosition::Single(current_msb);
-                        current_msb += 1;
-                    } else {
-                        current_msb = bitnum + 1;
-                    }
+            if let mdf::FieldPosition::Field(msb, lsb) = field.position {
+                if lsb > msb {
+                    return Err(format!("Field '{}' has lsb bigger than msb", field.name));
                 }
-                mdf::FieldPosition::Field(msb, lsb) => {
-                    if lsb > msb {
-                        return Err(format!("Field '{}' has lsb bigger than msb", field.name));
+            }
+            if field.array_stride() < field.width() {
+                return Err(format!("Field '{}' has an array stride smaller than its width", field.name));
+            }
+
+            let (span_msb, span_lsb) = field.span();
+            if span_lsb < current_msb {
+                let shift = current_msb - span_lsb;
+                field.position = match field.position {
+                    mdf::FieldPosition::Single(bitnum) => mdf::FieldPosition::Single(bitnum + shift),
+                    mdf::FieldPosition::Field(msb, lsb) => mdf::FieldPosition::Field(msb + shift, lsb + shift),
+                };
+                current_msb = span_msb + shift + 1;
+            } else {
+                current_msb = span_msb + 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// list the overlapping fields, as (field, earlier field it overlaps, first common bit). Fields with
+    /// their lsb above their msb are ignored, the validation reports them separately
+    pub fn field_overlaps(&self) -> Vec<(usize, usize, u32)> {
+        let mut used_bits: HashMap<u32, usize> = HashMap::new();
+        let mut overlaps = Vec::new();
+        for (n, field) in self.fields.iter().enumerate().filter(|(_, field)| !field.is_reversed()) {
+            for bit in field.bits() {
+                match used_bits.get(&bit) {
+                    Some(&other) => {
+                        if !overlaps.iter().any(|&(f, o, _)| f == n && o == other) {
+                            overlaps.push((n, other, bit));
+                        }
                     }
-                    if lsb < current_msb {
-                        field.position =
-                            mdf::FieldPosition::Field(msb + current_msb - lsb, current_msb);
-                        current_msb += msb - lsb + 1;
-                    } else {
-                        current_msb = msb + 1;
+                    None => {
+                        used_bits.insert(bit, n);
                     }
                 }
             }
         }
+        overlaps
+    }
 
-        Ok(())
+    /// propose new positions for the fields overlapping an earlier one. The other fields stay in place,
+    /// and each moved field goes to the lowest free bits below data_width that can hold it, including all
+    /// its array elements. None means there is no room left for the field
+    pub fn resolve_overlaps(&self, data_width: u32) -> Vec<(usize, Option<mdf::FieldPosition>)> {
+        let overlapping: Vec<usize> = self.field_overlaps().iter().map(|&(n, _, _)| n).collect();
+        let mut used_bits: std::collections::HashSet<u32> = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(n, field)| !overlapping.contains(n) && !field.is_reversed())
+            .flat_map(|(_, field)| field.bits())
+            .collect();
+
+        let mut moves = Vec::new();
+        for (n, field) in self.fields.iter().enumerate() {
+            if !overlapping.contains(&n) {
+                continue;
+            }
+            // footprint of the field relative to its lsb
+            let (msb, lsb) = field.span();
+            let footprint: Vec<u32> = field.bits().iter().map(|bit| bit - lsb).collect();
+            let new_lsb = (0..data_width.saturating_sub(msb - lsb))
+                .find(|&base| footprint.iter().all(|offset| !used_bits.contains(&(base + offset))));
+            let position = new_lsb.map(|new_lsb| {
+                used_bits.extend(footprint.iter().map(|offset| new_lsb + offset));
+                match field.position {
+                    mdf::FieldPosition::Single(_) => mdf::FieldPosition::Single(new_lsb),
+                    mdf::FieldPosition::Field(msb, lsb) => mdf::FieldPosition::Field(new_lsb + msb - lsb, new_lsb),
+                }
+            });
+            moves.push((n, position));
+        }
+        moves
     }
 
     /// realign all the fields to lsb 0
@@ -281,42 +1241,140 @@ impl Register {
         Ok(())
     }
 
+    /// number of bus words used by one element of the register. A register wider than the interface
+    /// is split over consecutive addresses, low word first
+    pub fn word_count(&self, interface_width: u32) -> u32 {
+        match self.get_data_width() {
+            Some(width) if interface_width > 0 => u32::max(1, (width + interface_width - 1) / interface_width),
+            _ => 1,
+        }
+    }
+
+    /// size of the address range used by one element of the register, given the address increment
+    /// of a bus word
+    pub fn element_span(&self, interface_width: u32, word_increment: u128) -> u128 {
+        self.word_count(interface_width) as u128 * word_increment
+    }
+
+    /// address offsets of all the bus words of the register, relative to its address
+    pub fn offsets(&self, interface_width: u32, word_increment: u128) -> Vec<u128> {
+        let element_offsets = match &self.address.stride {
+            None => vec![0],
+            Some(stride) => stride.offsets(self.element_span(interface_width, word_increment)),
+        };
+        let words = self.word_count(interface_width) as u128;
+        element_offsets
+            .into_iter()
+            .flat_map(|offset| (0..words).map(move |word| offset + word * word_increment))
+            .collect()
+    }
+
     /// returns the registers highest address (None if couldn't be determined)
-    pub fn high_address(&self, interface_width : u32) -> Option<u128> {
+    pub fn high_address(&self, interface_width : u32, word_increment: u128) -> Option<u128> {
+        let element_span = self.element_span(interface_width, word_increment);
         match &self.address.value {
             None => None,
             Some(address) => match &self.address.stride {
-                None => Some(address.value), // single register
+                None => Some(address.value + element_span - word_increment), // single register
                 Some(stride) => 
-                    // multiple registers. Need to find the count (easy) and increment (can be None -> auto)
-                    Some(address.value + (stride.count.value-1) * match stride.increment {
-                        None => (interface_width/8) as u128,
-                        Some(increment) => increment.value
-                    })
+                    // multiple registers, the last element has the highest offset
+                    Some(address.value + stride.span(element_span) - word_increment)
             }
         }
     }
 }
 
 impl Field {
+    /// replace the field position by the result of the lsb and width expressions
+    pub fn evaluate_expressions(&mut self, values: &HashMap<String, u128>, register: &str) -> Result<(), String> {
+        let what = format!("{} field {}", register, self.name);
+        let (msb, lsb) = match self.position {
+            mdf::FieldPosition::Single(bit) => (bit, bit),
+            mdf::FieldPosition::Field(msb, lsb) => (msb, lsb),
+        };
+        let new_lsb = evaluate_opt_u32(&self.lsb_expr, values, &what)?;
+        let new_width = evaluate_opt_u32(&self.width_expr, values, &what)?;
+        if new_width == Some(0) {
+            return Err(format!("{}: the width can't be 0", what));
+        }
+        if new_lsb.is_some() || new_width.is_some() {
+            let width = new_width.unwrap_or(msb.saturating_sub(lsb) + 1);
+            let lsb = new_lsb.unwrap_or(lsb);
+            // the register width is msb + 1, it must fit too
+            let msb = lsb.checked_add(width).ok_or(format!("{}: field position out of range", what))? - 1;
+            self.position = if width == 1 {
+                mdf::FieldPosition::Single(lsb)
+            } else {
+                mdf::FieldPosition::Field(msb, lsb)
+            };
+        }
+
+        Ok(())
+    }
+
+    /// true if the field lsb is above its msb
+    pub fn is_reversed(&self) -> bool {
+        matches!(self.position, mdf::FieldPosition::Field(msb, lsb) if lsb > msb)
+    }
+
+    /// width of the field, or of one element for a field array
+    pub fn width(&self) -> u32 {
+        match self.position {
+            mdf::FieldPosition::Single(_) => 1,
+            mdf::FieldPosition::Field(msb, lsb) => msb.saturating_sub(lsb) + 1,
+        }
+    }
+
+    /// number of elements, 1 if the field isn't an array
+    pub fn array_count(&self) -> u32 {
+        match self.array {
+            None => 1,
+            Some(array) => array.count.max(1),
+        }
+    }
+
+    /// number of bits between two elements of an array
+    pub fn array_stride(&self) -> u32 {
+        match self.array {
+            Some(mdf::FieldArray { stride: Some(stride), .. }) => stride,
+            _ => self.width(),
+        }
+    }
+
+    /// list of all the bits used by the field, including all the elements of an array
+    pub fn bits(&self) -> Vec<u32> {
+        let (_, lsb) = self.span();
+        let width = self.width();
+        let stride = self.array_stride();
+        (0..self.array_count())
+            .flat_map(|element| (0..width).map(move |bit| lsb + element * stride + bit))
+            .collect()
+    }
+
+    /// bits used by the field in the register, as (msb, lsb), including all the elements of an array
+    pub fn span(&self) -> (u32, u32) {
+        let (msb, lsb) = match self.position {
+            mdf::FieldPosition::Single(bitpos) => (bitpos, bitpos),
+            mdf::FieldPosition::Field(msb, lsb) => (msb, lsb),
+        };
+        (msb + (self.array_count() - 1) * self.array_stride(), lsb)
+    }
+
     /// goes through the register and removes all extra options that are not
     /// allowed.
     pub fn clean(&mut self, register_location: Option<LocationType>) {
         // remove all core properties if register location is not in core
-        match self.location {
+        // and all hardware access properties if it is
+        match self.location.or(register_location) {
             Some(LocationType::Pif) => {
                 self.core_signal_properties.use_read_enable = None;
                 self.core_signal_properties.use_write_enable = None;
+                self.core_signal_properties.use_read_handshake = None;
             }
-            None => match register_location {
-                Some(LocationType::Pif) => {
-                    self.core_signal_properties.use_read_enable = None;
-                    self.core_signal_properties.use_write_enable = None;
-                }
-
-                _ => (),
-            },
-            _ => (),
+            Some(LocationType::Core) => {
+                self.hardware_access = Default::default();
+            }
+            None => (),
         }
     }
 }
@@ -8,6 +8,7 @@ use crate::keys::{KeyAction, key_event_check};
 use crate::gui_blocks;
 use dioxus::prelude::*;
 use crate::generate;
+use crate::validation::{self, Diagnostic, Severity};
 use tera::Tera;
 
 /// quit menu item for the desktop application
@@ -184,6 +185,16 @@ pub fn NavBar(app_data: Signal<HdlWizardApp>, templates: Signal<tera::Result<Ter
                             file_io::Open { app_data: app_data, key_action : key_action }
                             file_io::Save { app_data: app_data, key_action : key_action }
                             file_io::SaveAs { app_data: app_data, key_action : key_action }
+                            gui_blocks::MenuEntry {
+                                action : move |_| {
+                                    app_data
+                                        .with_mut(|data| {
+                                            data.page_type = PageType::Compare;
+                                        })
+                                },
+                                icon: "fa-code-compare",
+                                label : "Compare...",
+                            }
                             Quit { key_action : key_action }
                         }
                     }
@@ -202,6 +213,16 @@ pub fn NavBar(app_data: Signal<HdlWizardApp>, templates: Signal<tera::Result<Ter
                                 icon: "fa-signature",
                                 label : "Strings",
                             }
+                            gui_blocks::MenuEntry {
+                                action : move |_| {
+                                    app_data
+                                        .with_mut(|data| {
+                                            data.page_type = PageType::Settings(SettingsPageType::Generation);
+                                        })
+                                },
+                                icon: "fa-microchip",
+                                label : "Code generation",
+                            }
                         }
                     }
 
@@ -232,11 +253,38 @@ pub fn NavBar(app_data: Signal<HdlWizardApp>, templates: Signal<tera::Result<Ter
     }
 }
 
-/// generates a register list from the given vector list
+/// number of errors and warnings for the diagnostics matching the given filter
+fn count_diagnostics(diagnostics: &[Diagnostic], filter: impl Fn(&PageType) -> bool) -> (usize, usize) {
+    diagnostics.iter().filter(|diagnostic| filter(&diagnostic.page)).fold((0, 0), |(errors, warnings), diagnostic| {
+        match diagnostic.severity {
+            Severity::Error => (errors + 1, warnings),
+            Severity::Warning => (errors, warnings + 1),
+        }
+    })
+}
+
+/// error and warning count badges, next to a sidebar entry
 #[component]
-pub fn RegistersList(app_data: Signal<HdlWizardApp>, list: Vec<(String, PageType)>) -> Element {
+fn Badges(errors: usize, warnings: usize) -> Element {
     rsx! {
-        { list.iter().map( | (name, reg_page) | {
+        {
+            if errors > 0 {
+                rsx! { span { class: "tag is-danger is-rounded ml-1", "{errors}" } }
+            } else { None }
+        }
+        {
+            if warnings > 0 {
+                rsx! { span { class: "tag is-warning is-rounded ml-1", "{warnings}" } }
+            } else { None }
+        }
+    }
+}
+
+/// generates a register list from the given vector list, with the error and warning counts
+#[component]
+pub fn RegistersList(app_data: Signal<HdlWizardApp>, list: Vec<(String, PageType, (usize, usize))>) -> Element {
+    rsx! {
+        { list.iter().map( | (name, reg_page, (errors, warnings)) | {
             let reg_page = reg_page.to_owned();
             rsx! {
                 li {
@@ -245,6 +293,7 @@ pub fn RegistersList(app_data: Signal<HdlWizardApp>, list: Vec<(String, PageType
                             app.page_type = reg_page.clone();
                         }),
                         "{name}"
+                        Badges { errors: *errors, warnings: *warnings }
                     }
                 }
             }
@@ -255,6 +304,13 @@ pub fn RegistersList(app_data: Signal<HdlWizardApp>, list: Vec<(String, PageType
 /// Left sidebar, listing all the registers
 #[component]
 pub fn SideBar(app_data: Signal<HdlWizardApp>) -> Element {
+    // validate the model on each render, to put the badges on the interfaces and registers
+    let diagnostics = {
+        let data = &app_data.read().data;
+        validation::validate(&data.model)
+    };
+    let (total_errors, total_warnings) = count_diagnostics(&diagnostics, |_| true);
+
     // build a list of all registers, within a list of all interfaces
     let registers = app_data
         .read()
@@ -267,6 +323,7 @@ pub fn SideBar(app_data: Signal<HdlWizardApp>) -> Element {
             (
                 interface.name.clone(),
                 PageType::Interface(n_int),
+                count_diagnostics(&diagnostics, |page| *page == PageType::Interface(n_int)),
                 interface
                     .registers
                     .iter()
@@ -275,6 +332,9 @@ pub fn SideBar(app_data: Signal<HdlWizardApp>) -> Element {
                         (
                             register.name.clone(),
                             PageType::Register(n_int, n_reg, None),
+                            count_diagnostics(&diagnostics, |page| {
+                                matches!(page, PageType::Register(i, r, _) if *i == n_int && *r == n_reg)
+                            }),
                         )
                     })
                     .collect::<Vec<_>>(),
@@ -286,7 +346,7 @@ pub fn SideBar(app_data: Signal<HdlWizardApp>) -> Element {
     // If there are several interfaces, put a list of interfaces and the registers as a sublist
     let menu = match registers.len() {
         1 => {
-            let (_, _, registers_list) = &registers[0];
+            let (_, _, _, registers_list) = &registers[0];
 
             rsx! {
 
@@ -314,7 +374,7 @@ pub fn SideBar(app_data: Signal<HdlWizardApp>) -> Element {
         }
         _ => rsx! {
             { registers.iter().map(
-                | (interface_name, interface_page, registers) | {
+                | (interface_name, interface_page, (errors, warnings), registers) | {
                     let new_page = interface_page.clone();
                     rsx! {
                         li {
@@ -323,6 +383,7 @@ pub fn SideBar(app_data: Signal<HdlWizardApp>) -> Element {
                                     app.page_type = new_page.clone();
                                     }),
                                 "{interface_name}"
+                                Badges { errors: *errors, warnings: *warnings }
                             },
                             ul {
                                 RegistersList {
@@ -339,6 +400,13 @@ pub fn SideBar(app_data: Signal<HdlWizardApp>) -> Element {
     rsx! {
         aside { class: "panel ext-sticky m-5 is-link",
             p { class: "panel-heading", "Registers" }
+            a {
+                class: "panel-block",
+                onclick: move |_| app_data.with_mut(|app| app.page_type = PageType::Validation),
+                span { class: "panel-icon", i { class: "fa-solid fa-list-check" } }
+                "Validation"
+                Badges { errors: total_errors, warnings: total_warnings }
+            }
             div { class: "panel-block",
                 nav { class: "menu",
                     ul { class: "menu-list", { menu } }
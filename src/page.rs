@@ -8,7 +8,8 @@ use tera::{Tera,Result};
 
 #[derive(PartialEq, Clone)]
 pub enum SettingsPageType {
-    Strings
+    Strings,
+    Generation
 }
 
 #[derive(PartialEq, Clone)]
@@ -18,14 +19,21 @@ pub enum PageType {
     Register(usize, usize, Option<usize>),
     ChangeRegisterField(usize, usize, usize),
     Settings(SettingsPageType),
-    Preview
+    Preview,
+    Validation,
+    AddressMap(usize),
+    Compare
 }
 
+pub mod address_map;
+pub mod compare;
 pub mod interface;
 pub mod project;
 pub mod register;
 pub mod preview;
 pub mod settings_strings;
+pub mod settings_generation;
+pub mod validation;
 
 /// when saving a file on the webapp, create an URI that the user can click to download 
 #[cfg(target_arch = "wasm32")]
@@ -234,6 +242,29 @@ pub fn Content(app_data: Signal<HdlWizardApp>, templates: Signal<Result<Tera>>)
                     settings_strings::Content { app_data: app_data }
                 }
             }
+            PageType::Settings(SettingsPageType::Generation) => {
+                rsx! {
+                    settings_generation::Content { app_data: app_data }
+                }
+            }
+            PageType::Validation => {
+                rsx! {
+                    validation::Content { app_data: app_data }
+                }
+            }
+            PageType::AddressMap(interface_num) => {
+                rsx! {
+                    address_map::Content {
+                        app_data: app_data,
+                        interface_num: interface_num
+                    }
+                }
+            }
+            PageType::Compare => {
+                rsx! {
+                    compare::Content { app_data: app_data }
+                }
+            }
         },
     }
 }
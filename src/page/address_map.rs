@@ -0,0 +1,208 @@
+//! page showing the address map of an interface
+#![allow(non_snake_case)]
+
+use crate::address_map::{AddressMap, ItemKind, MapBlock};
+use crate::app::HdlWizardApp;
+use crate::page::PageType;
+use dioxus::prelude::*;
+
+/// background color of a block in the map
+fn block_class(map: &AddressMap, block: &MapBlock) -> &'static str {
+    match block.item.map(|n| &map.items[n]) {
+        None => "has-background-grey-lighter",
+        Some(item) if !item.conflicts.is_empty() => "has-background-danger",
+        Some(item) => match item.kind {
+            ItemKind::Register => "has-background-link",
+            ItemKind::Interrupt => "has-background-info",
+            ItemKind::Memory => "has-background-primary",
+            ItemKind::Reserved => "has-background-warning",
+        },
+    }
+}
+
+/// name displayed for a block
+fn block_name(map: &AddressMap, block: &MapBlock) -> String {
+    match (block.item.map(|n| &map.items[n]), block.element) {
+        (None, _) => "(free)".to_owned(),
+        (Some(item), Some(element)) => format!("{}[{}]", item.name, element),
+        (Some(item), None) if item.elements.len() > 1 => format!("{}[0..{}]", item.name, item.elements.len() - 1),
+        (Some(item), None) => item.name.clone(),
+    }
+}
+
+/// details shown when hovering a block
+fn block_details(map: &AddressMap, block: &MapBlock) -> String {
+    let mut details = format!(
+        "{}\n0x{:x} - 0x{:x} ({} words)",
+        block_name(map, block),
+        block.start,
+        block.last(),
+        block.size / u128::max(map.word_increment, 1)
+    );
+    if let Some(item) = block.item.map(|n| &map.items[n]) {
+        details.push_str(match (item.kind, item.auto) {
+            (ItemKind::Reserved, _) => "\nreserved for future use",
+            (_, true) => "\nautomatic address",
+            (_, false) => "\nfixed address",
+        });
+        if !item.conflicts.is_empty() {
+            details.push_str(&format!("\nshares addresses with {}", item.conflicts.join(", ")));
+        }
+    }
+    details
+}
+
+/// Whole page with the address map of an interface
+#[component]
+pub fn Content(app_data: Signal<HdlWizardApp>, interface_num: usize) -> Element {
+    // items whose array elements are shown separately
+    let mut expanded: Signal<Vec<usize>> = use_signal(Vec::new);
+
+    // the map is rebuilt on each render, so it follows the model edits
+    let (interface_name, map) = {
+        let model = &app_data.read().data.model;
+        match model.interfaces.get(interface_num) {
+            Some(interface) => (interface.name.clone(), AddressMap::new(model, interface_num)),
+            None => return rsx! { p { "Wrong interface" } },
+        }
+    };
+    let blocks = map.blocks(&expanded());
+
+    // overview bar, with each block taking a width proportional to its size
+    let total_size = blocks.last().map_or(0, |block| block.start.saturating_add(block.size));
+    let overview = blocks.iter().map(|block| {
+        let class = block_class(&map, block);
+        let title = block_details(&map, block);
+        let ratio = if total_size > 0 { block.size as f64 / total_size as f64 } else { 0.0 };
+        let page = block.item.map(|n| map.items[n].page.clone());
+        rsx! {
+            div {
+                class: "{class}",
+                style: "flex: {ratio} 0 2px; border-right: 1px solid white; cursor: pointer;",
+                title: "{title}",
+                onclick: move |_| if let Some(page) = &page {
+                    let page = page.clone();
+                    app_data.with_mut(|app| app.page_type = page);
+                },
+            }
+        }
+    });
+
+    // detailed list of the blocks
+    let lines = blocks.iter().enumerate().map(|(n, block)| {
+        let class = block_class(&map, block);
+        let title = block_details(&map, block);
+        let name = block_name(&map, block);
+        let range = format!("0x{:x} - 0x{:x}", block.start, block.last());
+        let words = block.size / u128::max(map.word_increment, 1);
+        let item = block.item.map(|n| map.items[n].clone());
+        let item_num = block.item;
+        let page = item.as_ref().map(|item| item.page.clone());
+
+        // arrays can be expanded or collapsed
+        let toggle = match (item_num, &item) {
+            (Some(item_num), Some(item)) if item.elements.len() > 1 => {
+                let is_expanded = expanded().contains(&item_num);
+                let icon = if is_expanded { "fa-solid fa-compress" } else { "fa-solid fa-expand" };
+                let text = if is_expanded { "collapse" } else { "expand" };
+                rsx! {
+                    button { class: "button is-small",
+                        onclick: move |_| expanded.with_mut(|list| {
+                            if list.contains(&item_num) {
+                                list.retain(|other| *other != item_num);
+                            } else {
+                                list.push(item_num);
+                            }
+                        }),
+                        span { class: "icon is-small", i { class: "{icon}" } }
+                        span { "{text}" }
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        let notes = match &item {
+            None => String::new(),
+            Some(item) => {
+                let mut notes = match item.kind {
+                    ItemKind::Reserved => Vec::new(),
+                    _ => vec![if item.auto { "automatic address" } else { "fixed address" }.to_owned()],
+                };
+                match item.kind {
+                    ItemKind::Interrupt => notes.push("interrupt block".to_owned()),
+                    ItemKind::Memory => notes.push("memory".to_owned()),
+                    ItemKind::Reserved => notes.push("reserved range".to_owned()),
+                    ItemKind::Register => (),
+                }
+                if !item.conflicts.is_empty() {
+                    notes.push(format!("shares addresses with {}", item.conflicts.join(", ")));
+                }
+                notes.join(", ")
+            }
+        };
+
+        rsx! {
+            tr { key: "{n}", title: "{title}",
+                td { class: "is-family-monospace", "{range}" }
+                td { class: "{class}", style: "width: 1em;" }
+                td {
+                    {
+                        match page {
+                            Some(page) => rsx! {
+                                a { onclick: move |_| app_data.with_mut(|app| app.page_type = page.clone()), "{name}" }
+                            },
+                            None => rsx! { span { class: "has-text-grey", "{name}" } },
+                        }
+                    }
+                }
+                td { "{words}" }
+                td { "{notes}" }
+                td { {toggle} }
+            }
+        }
+    });
+
+    rsx! {
+        div { class: "container",
+            h1 { class: "title page-title", "Address map: {interface_name}" }
+            {
+                if let Some(error) = &map.error {
+                    rsx! {
+                        article { class: "message is-warning",
+                            div { class: "message-body",
+                                "The addresses can't all be assigned, only the fixed ones are shown: {error}"
+                            }
+                        }
+                    }
+                } else {
+                    None
+                }
+            }
+            div { class: "tags",
+                span { class: "tag has-background-link has-text-white", "register" }
+                span { class: "tag has-background-info", "interrupt block" }
+                span { class: "tag has-background-primary", "memory" }
+                span { class: "tag has-background-warning", "reserved" }
+                span { class: "tag has-background-grey-lighter", "free" }
+                span { class: "tag has-background-danger has-text-white", "conflict" }
+            }
+            div { style: "display: flex; height: 2.5em; margin-bottom: 1.5em;",
+                {overview}
+            }
+            table { class: "table is-hoverable is-fullwidth",
+                thead {
+                    tr {
+                        th { "Addresses" }
+                        th {}
+                        th { "Name" }
+                        th { "Words" }
+                        th { "Notes" }
+                        th {}
+                    }
+                }
+                tbody { {lines} }
+            }
+        }
+    }
+}
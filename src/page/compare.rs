@@ -0,0 +1,219 @@
+//! page comparing two models
+#![allow(non_snake_case)]
+
+use crate::app::HdlWizardApp;
+use crate::diff::{ChangeKind, ModelDiff, OutputFormat};
+use crate::file_formats::mdf;
+use dioxus::prelude::*;
+use rfd::AsyncFileDialog;
+use strum::IntoEnumIterator;
+
+/// model loaded from a file, with the file name. None means the model currently edited
+type SideModel = Option<(String, mdf::Mdf)>;
+
+/// tag color for a kind of change
+fn kind_class(kind: ChangeKind) -> &'static str {
+    match kind {
+        ChangeKind::Added => "is-success",
+        ChangeKind::Removed => "is-danger",
+        ChangeKind::Renamed | ChangeKind::Moved => "is-warning",
+        ChangeKind::Width | ChangeKind::Count | ChangeKind::Access | ChangeKind::Reset | ChangeKind::Description => "is-info",
+    }
+}
+
+/// ask for a file and load it as one side of the comparison
+fn load_side(mut app_data: Signal<HdlWizardApp>, mut side: Signal<SideModel>) {
+    let current_path = app_data.read().data.current_path.clone();
+    spawn(async move {
+        let file = AsyncFileDialog::new()
+            .add_filter("hdl wizard", &["regwiz", "json"])
+            .add_filter("any", &["*"])
+            .set_directory(&current_path)
+            .pick_file()
+            .await;
+
+        if let Some(file) = file {
+            match mdf::Mdf::from_slice(&file.read().await) {
+                Ok(model) => side.set(Some((file.file_name(), model))),
+                Err(e) => app_data.with_mut(|app| app.error_message = Some(format!("Error while loading file: {}", e))),
+            }
+        }
+    });
+}
+
+/// ask for a file name and write the report in it
+#[cfg(not(target_arch = "wasm32"))]
+fn save_report(mut app_data: Signal<HdlWizardApp>, report: String, format: OutputFormat) {
+    let extension = match format {
+        OutputFormat::Text => "txt",
+        OutputFormat::Html => "html",
+        OutputFormat::Json => "json",
+    };
+    spawn(async move {
+        let file = AsyncFileDialog::new()
+            .add_filter(format.to_string(), &[extension])
+            .add_filter("any", &["*"])
+            .save_file()
+            .await;
+
+        if let Some(file) = file {
+            match std::fs::write(file.path(), report) {
+                Ok(_) => app_data.with_mut(|app| app.notification = Some("Report saved".to_owned())),
+                Err(e) => app_data.with_mut(|app| app.error_message = Some(format!("Error while writing report: {}", e))),
+            }
+        }
+    });
+}
+
+/// the report can only be copied from the page on the webapp
+#[cfg(target_arch = "wasm32")]
+fn save_report(_app_data: Signal<HdlWizardApp>, _report: String, _format: OutputFormat) {}
+
+/// one side of the comparison, with the buttons to choose the model
+#[component]
+fn SideChooser(app_data: Signal<HdlWizardApp>, label: &'static str, side: Signal<SideModel>) -> Element {
+    let name = match &*side.read() {
+        None => "current model".to_owned(),
+        Some((file_name, _)) => file_name.clone(),
+    };
+    let is_file = side.read().is_some();
+
+    rsx! {
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", "{label}" } }
+            div { class: "field-body",
+                div { class: "field is-grouped",
+                    p { class: "control is-expanded",
+                        input { class: "input is-static", r#type: "text", readonly: true, value: "{name}" }
+                    }
+                    p { class: "control",
+                        button { class: "button",
+                            onclick: move |_| load_side(app_data, side),
+                            span { class: "icon is-small", i { class: "fa-solid fa-folder-open" } }
+                            span { "Open file..." }
+                        }
+                    }
+                    p { class: "control",
+                        button { class: "button", disabled: !is_file,
+                            onclick: move |_| side.set(None),
+                            span { class: "icon is-small", i { class: "fa-solid fa-pen" } }
+                            span { "Use current model" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Whole page comparing two models
+#[component]
+pub fn Content(app_data: Signal<HdlWizardApp>) -> Element {
+    let mut old_side: Signal<SideModel> = use_signal(|| None);
+    let mut new_side: Signal<SideModel> = use_signal(|| None);
+    let mut format = use_signal(|| OutputFormat::Text);
+
+    // the comparison runs on each render, so it follows the edits of the current model
+    let diff = {
+        let current = app_data.read().data.model.clone();
+        let old = old_side.read();
+        let new = new_side.read();
+        let old_model = old.as_ref().map_or(current.as_ref(), |(_, model)| model);
+        let new_model = new.as_ref().map_or(current.as_ref(), |(_, model)| model);
+        ModelDiff::new(old_model, new_model)
+    };
+    let report = diff.report(format());
+
+    let lines = diff.changes.iter().enumerate().map(|(n, change)| {
+        let class = kind_class(change.kind);
+        let old = change.old.clone().unwrap_or_default();
+        let new = change.new.clone().unwrap_or_default();
+        rsx! {
+            tr { key: "{n}",
+                td { span { class: "tag {class}", "{change.kind}" } }
+                td { "{change.interface}" }
+                td { "{change.description()}" }
+                td { style: "white-space: pre-wrap;", "{old}" }
+                td { style: "white-space: pre-wrap;", "{new}" }
+            }
+        }
+    });
+
+    let options = OutputFormat::iter().map(|value| {
+        rsx!( option { selected: "{value == format()}", "{value}" } )
+    });
+
+    rsx! {
+        div { class: "container",
+            h1 { class: "title page-title", "Compare" }
+            SideChooser { app_data: app_data, label: "Old", side: old_side }
+            SideChooser { app_data: app_data, label: "New", side: new_side }
+            div { class: "buttons",
+                button { class: "button",
+                    onclick: move |_| {
+                        let old = old_side();
+                        old_side.set(new_side());
+                        new_side.set(old);
+                    },
+                    span { class: "icon is-small", i { class: "fa-solid fa-right-left" } }
+                    span { "Swap" }
+                }
+            }
+            {
+                diff.warnings.iter().map(|warning| rsx! {
+                    article { class: "message is-warning",
+                        div { class: "message-body", "{warning}" }
+                    }
+                })
+            }
+            {
+                if diff.is_empty() {
+                    rsx! { p { "No difference found between the two models." } }
+                } else {
+                    rsx! {
+                        table { class: "table is-hoverable is-fullwidth",
+                            thead {
+                                tr {
+                                    th { "Change" }
+                                    th { "Interface" }
+                                    th { "Description" }
+                                    th { "Old" }
+                                    th { "New" }
+                                }
+                            }
+                            tbody { {lines} }
+                        }
+                    }
+                }
+            }
+            h2 { class: "subtitle", "Report" }
+            div { class: "field is-grouped",
+                div { class: "control select",
+                    select {
+                        onchange: move |evt| if let Ok(value) = evt.value().parse::<OutputFormat>() {
+                            format.set(value);
+                        },
+                        {options}
+                    }
+                }
+                {
+                    if cfg!(target_arch = "wasm32") {
+                        None
+                    } else {
+                        let report = report.clone();
+                        rsx! {
+                            div { class: "control",
+                                button { class: "button is-link",
+                                    onclick: move |_| save_report(app_data, report.clone(), format()),
+                                    span { class: "icon is-small", i { class: "fa-solid fa-floppy-disk" } }
+                                    span { "Save report..." }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            pre { class: "is-family-monospace", "{report}" }
+        }
+    }
+}
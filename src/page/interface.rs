@@ -8,6 +8,7 @@ use crate::gui_blocks::callback_interface;
 use crate::page::PageType;
 use crate::utils;
 use dioxus::prelude::*;
+use std::str::FromStr;
 
 /// builds a single line in the table with all the registers
 #[component]
@@ -17,6 +18,8 @@ fn TableLine(
     register_name: String,
     #[props(!optional)] register_type: Option<utils::SignalType>,
     register_address: mdf::Address,
+    register_pinned: bool,
+    #[props(!optional)] register_group: Option<String>,
 ) -> Element {
     let page_type = app_data.read().page_type.clone();
     if let PageType::Interface(interface_number) = page_type {
@@ -32,6 +35,7 @@ fn TableLine(
         } else {
             register_name
         };
+        let display_group = register_group.unwrap_or_default();
         let display_type = match register_type {
             Some(signal_type) => signal_type.to_string(),
             None => "bitfield".to_owned(),
@@ -55,7 +59,19 @@ fn TableLine(
                         "{display_name}"
                     }
                 }
-                td { "{register_address.nice_str()}" }
+                td {
+                    "{register_address.nice_str()}"
+                    {
+                        if register_pinned {
+                            rsx! {
+                                span { class: "icon", title: "pinned address", i { class: "fa-solid fa-thumbtack" } }
+                            }
+                        } else {
+                            None
+                        }
+                    }
+                }
+                td { "{display_group}" }
                 td { "{display_type}" }
                 td {
                     div { class: "buttons are-small ext-buttons-in-table",
@@ -131,6 +147,549 @@ fn TableLine(
     }
 }
 
+/// converts the text of an address field into an optional value, empty meaning automatic
+fn text_to_value(value: &str) -> Option<utils::VectorValue> {
+    utils::VectorValue::from_str(value.trim()).ok()
+}
+
+/// table with the register groups of the interface
+#[component]
+fn RegisterGroups(app_data: Signal<HdlWizardApp>, groups: Vec<mdf::RegisterGroup>) -> Element {
+    // each modification replaces the complete list
+    let update_int = callback_interface(app_data, |interface, value| interface.set_groups(value));
+    let groups_new = groups.clone();
+
+    let lines = groups.iter().enumerate().map(|(n, group)| {
+        let base = group.base.map(|value| value.to_string()).unwrap_or_default();
+        // the count can also be an expression using the project parameters
+        let count = group.count_expr.clone().unwrap_or(group.count.map(|value| value.to_string()).unwrap_or_default());
+        let increment = group.increment.map(|value| value.to_string()).unwrap_or_default();
+        let groups_name = groups.clone();
+        let groups_base = groups.clone();
+        let groups_pinned = groups.clone();
+        let pinned = group.pinned.unwrap_or(false);
+        let groups_count = groups.clone();
+        let groups_increment = groups.clone();
+        let groups_remove = groups.clone();
+
+        rsx! {
+            tr { key: "{n}",
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "name",
+                        onchange: move |evt| {
+                            let mut new_groups = groups_name.clone();
+                            new_groups[n].name = evt.value();
+                            gui_blocks::apply_function(app_data, new_groups, "change register group name", None, Some(update_int), None, None);
+                        },
+                        value: "{group.name}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input ext-vector-field",
+                        r#type: "text",
+                        placeholder: "auto",
+                        onchange: move |evt| {
+                            let mut new_groups = groups_base.clone();
+                            new_groups[n].base = text_to_value(&evt.value());
+                            gui_blocks::apply_function(app_data, new_groups, "change register group base address", None, Some(update_int), None, None);
+                        },
+                        value: "{base}"
+                    }
+                }
+                td {
+                    label { class: "checkbox", title: "keep the base address when the addresses are unassigned",
+                        input {
+                            r#type: "checkbox",
+                            checked: "{pinned}",
+                            onchange: move |evt| {
+                                let mut new_groups = groups_pinned.clone();
+                                new_groups[n].pinned = Some(evt.value() == "true");
+                                gui_blocks::apply_function(app_data, new_groups, "change register group pin", None, Some(update_int), None, None);
+                            }
+                        }
+                    }
+                }
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "1",
+                        onchange: move |evt| {
+                            let mut new_groups = groups_count.clone();
+                            new_groups[n].count = text_to_value(&evt.value());
+                            new_groups[n].count_expr = match new_groups[n].count {
+                                Some(_) => None,
+                                None => utils::text_to_opt_expression(&evt.value()),
+                            };
+                            gui_blocks::apply_function(app_data, new_groups, "change register group count", None, Some(update_int), None, None);
+                        },
+                        value: "{count}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input ext-vector-field",
+                        r#type: "text",
+                        placeholder: "auto",
+                        onchange: move |evt| {
+                            let mut new_groups = groups_increment.clone();
+                            new_groups[n].increment = text_to_value(&evt.value());
+                            gui_blocks::apply_function(app_data, new_groups, "change register group increment", None, Some(update_int), None, None);
+                        },
+                        value: "{increment}"
+                    }
+                }
+                td {
+                    div { class: "buttons are-small ext-buttons-in-table",
+                        button {
+                            class: "button is-danger has-text-white",
+                            onclick: move |_| {
+                                let mut new_groups = groups_remove.clone();
+                                new_groups.remove(n);
+                                gui_blocks::apply_function(app_data, new_groups, "remove register group", None, Some(update_int), None, None);
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    rsx! {
+        h2 { class: "subtitle page-title", "Register groups" }
+        p { "The addresses of the registers in a group are relative to the group base address. A group can be repeated, for example once per channel." }
+        table { class: "table is-striped is-hoverable is-fullwidth",
+            thead {
+                tr {
+                    th { "Name" }
+                    th { "Base address" }
+                    th { "Pinned" }
+                    th { "Count" }
+                    th { "Increment" }
+                    th {}
+                }
+            }
+            tbody { {lines} }
+        }
+        div { class: "buttons",
+            button {
+                class: "button is-primary",
+                onclick: move |_| {
+                    let mut new_groups = groups_new.clone();
+                    new_groups.push(mdf::RegisterGroup::new());
+                    gui_blocks::apply_function(app_data, new_groups, "create register group", None, Some(update_int), None, None);
+                },
+                "New register group"
+            }
+        }
+    }
+}
+
+/// table with the address ranges reserved for future use
+#[component]
+fn ReservedRanges(app_data: Signal<HdlWizardApp>, ranges: Vec<mdf::ReservedRange>) -> Element {
+    // each modification replaces the complete list
+    let update_int = callback_interface(app_data, |interface, value| interface.reserved = value);
+    let ranges_new = ranges.clone();
+
+    let lines = ranges.iter().enumerate().map(|(n, range)| {
+        let ranges_name = ranges.clone();
+        let ranges_start = ranges.clone();
+        let ranges_end = ranges.clone();
+        let ranges_remove = ranges.clone();
+
+        rsx! {
+            tr { key: "{n}",
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "name",
+                        onchange: move |evt| {
+                            let mut new_ranges = ranges_name.clone();
+                            new_ranges[n].name = evt.value();
+                            gui_blocks::apply_function(app_data, new_ranges, "change reserved range name", None, Some(update_int), None, None);
+                        },
+                        value: "{range.name}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input ext-vector-field",
+                        r#type: "text",
+                        onchange: move |evt| {
+                            if let Some(start) = text_to_value(&evt.value()) {
+                                let mut new_ranges = ranges_start.clone();
+                                new_ranges[n].start = start;
+                                gui_blocks::apply_function(app_data, new_ranges, "change reserved range start", None, Some(update_int), None, None);
+                            }
+                        },
+                        value: "{range.start}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input ext-vector-field",
+                        r#type: "text",
+                        onchange: move |evt| {
+                            if let Some(end) = text_to_value(&evt.value()) {
+                                let mut new_ranges = ranges_end.clone();
+                                new_ranges[n].end = end;
+                                gui_blocks::apply_function(app_data, new_ranges, "change reserved range end", None, Some(update_int), None, None);
+                            }
+                        },
+                        value: "{range.end}"
+                    }
+                }
+                td {
+                    div { class: "buttons are-small ext-buttons-in-table",
+                        button {
+                            class: "button is-danger has-text-white",
+                            onclick: move |_| {
+                                let mut new_ranges = ranges_remove.clone();
+                                new_ranges.remove(n);
+                                gui_blocks::apply_function(app_data, new_ranges, "remove reserved range", None, Some(update_int), None, None);
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    rsx! {
+        h2 { class: "subtitle page-title", "Reserved ranges" }
+        p { "The automatic address allocation leaves these address ranges free, for future use. The last address is included in the range." }
+        table { class: "table is-striped is-hoverable is-fullwidth",
+            thead {
+                tr {
+                    th { "Name" }
+                    th { "First address" }
+                    th { "Last address" }
+                    th {}
+                }
+            }
+            tbody { {lines} }
+        }
+        div { class: "buttons",
+            button {
+                class: "button is-primary",
+                onclick: move |_| {
+                    let mut new_ranges = ranges_new.clone();
+                    new_ranges.push(mdf::ReservedRange::new());
+                    gui_blocks::apply_function(app_data, new_ranges, "create reserved range", None, Some(update_int), None, None);
+                },
+                "New reserved range"
+            }
+        }
+    }
+}
+
+/// table with the memory windows of the interface
+#[component]
+fn Memories(app_data: Signal<HdlWizardApp>, memories: Vec<mdf::Memory>) -> Element {
+    // each modification replaces the complete list
+    let update_int = callback_interface(app_data, |interface, value| interface.memories = value);
+    let memories_new = memories.clone();
+
+    let lines = memories.iter().enumerate().map(|(n, memory)| {
+        let address = memory.address.map(|value| value.to_string()).unwrap_or_default();
+        // the depth can also be an expression using the project parameters
+        let depth = memory.depth_expr.clone().unwrap_or(memory.depth.to_string());
+        let width = memory.width.map(|value| value.to_string()).unwrap_or_default();
+        let read_latency = memory.read_latency.map(|value| value.to_string()).unwrap_or_default();
+        let access = memory.access;
+        let access_options = [mdf::AccessType::RW, mdf::AccessType::RO, mdf::AccessType::WO].into_iter().map(|access_type| {
+            rsx!( option { selected: "{access_type == access}", "{access_type.to_string()}" } )
+        });
+        let memories_name = memories.clone();
+        let memories_address = memories.clone();
+        let memories_pinned = memories.clone();
+        let pinned = memory.pinned.unwrap_or(false);
+        let memories_depth = memories.clone();
+        let memories_width = memories.clone();
+        let memories_access = memories.clone();
+        let memories_latency = memories.clone();
+        let memories_remove = memories.clone();
+
+        rsx! {
+            tr { key: "{n}",
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "name",
+                        onchange: move |evt| {
+                            let mut new_memories = memories_name.clone();
+                            new_memories[n].name = evt.value();
+                            gui_blocks::apply_function(app_data, new_memories, "change memory name", None, Some(update_int), None, None);
+                        },
+                        value: "{memory.name}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input ext-vector-field",
+                        r#type: "text",
+                        placeholder: "auto",
+                        onchange: move |evt| {
+                            let mut new_memories = memories_address.clone();
+                            new_memories[n].address = text_to_value(&evt.value());
+                            gui_blocks::apply_function(app_data, new_memories, "change memory address", None, Some(update_int), None, None);
+                        },
+                        value: "{address}"
+                    }
+                }
+                td {
+                    label { class: "checkbox", title: "keep the address when the addresses are unassigned",
+                        input {
+                            r#type: "checkbox",
+                            checked: "{pinned}",
+                            onchange: move |evt| {
+                                let mut new_memories = memories_pinned.clone();
+                                new_memories[n].pinned = Some(evt.value() == "true");
+                                gui_blocks::apply_function(app_data, new_memories, "change memory pin", None, Some(update_int), None, None);
+                            }
+                        }
+                    }
+                }
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        onchange: move |evt| {
+                            let mut new_memories = memories_depth.clone();
+                            match u32::from_str(evt.value().trim()) {
+                                Ok(depth) => {
+                                    new_memories[n].depth = depth;
+                                    new_memories[n].depth_expr = None;
+                                }
+                                Err(_) => new_memories[n].depth_expr = utils::text_to_opt_expression(&evt.value()),
+                            }
+                            gui_blocks::apply_function(app_data, new_memories, "change memory depth", None, Some(update_int), None, None);
+                        },
+                        value: "{depth}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "auto",
+                        onchange: move |evt| {
+                            let mut new_memories = memories_width.clone();
+                            new_memories[n].width = u32::from_str(evt.value().trim()).ok();
+                            gui_blocks::apply_function(app_data, new_memories, "change memory width", None, Some(update_int), None, None);
+                        },
+                        value: "{width}"
+                    }
+                }
+                td {
+                    div { class: "control select",
+                        select {
+                            onchange: move |evt| {
+                                if let Ok(access) = mdf::AccessType::from_str(&evt.value()) {
+                                    let mut new_memories = memories_access.clone();
+                                    new_memories[n].access = access;
+                                    gui_blocks::apply_function(app_data, new_memories, "change memory access", None, Some(update_int), None, None);
+                                }
+                            },
+                            {access_options}
+                        }
+                    }
+                }
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "1",
+                        onchange: move |evt| {
+                            let mut new_memories = memories_latency.clone();
+                            new_memories[n].read_latency = u32::from_str(evt.value().trim()).ok();
+                            gui_blocks::apply_function(app_data, new_memories, "change memory read latency", None, Some(update_int), None, None);
+                        },
+                        value: "{read_latency}"
+                    }
+                }
+                td {
+                    div { class: "buttons are-small ext-buttons-in-table",
+                        button {
+                            class: "button is-danger has-text-white",
+                            onclick: move |_| {
+                                let mut new_memories = memories_remove.clone();
+                                new_memories.remove(n);
+                                gui_blocks::apply_function(app_data, new_memories, "remove memory", None, Some(update_int), None, None);
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    rsx! {
+        h2 { class: "subtitle page-title", "Memories" }
+        p { "A memory reserves a range of addresses, one word per address, implemented in the core. The pif provides the address, data and enable signals to access it." }
+        table { class: "table is-striped is-hoverable is-fullwidth",
+            thead {
+                tr {
+                    th { "Name" }
+                    th { "Address" }
+                    th { "Pinned" }
+                    th { "Depth" }
+                    th { "Width" }
+                    th { "Access" }
+                    th { "Read latency" }
+                    th {}
+                }
+            }
+            tbody { {lines} }
+        }
+        div { class: "buttons",
+            button {
+                class: "button is-primary",
+                onclick: move |_| {
+                    let mut new_memories = memories_new.clone();
+                    new_memories.push(mdf::Memory::new());
+                    gui_blocks::apply_function(app_data, new_memories, "create memory", None, Some(update_int), None, None);
+                },
+                "New memory"
+            }
+        }
+    }
+}
+
+/// converts the text area with one source name per line into a sources list, keeping the
+/// descriptions of the sources that are still there
+fn textarea_to_sources(value: &str, previous: &[mdf::InterruptSource]) -> Vec<mdf::InterruptSource> {
+    value
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| match previous.iter().find(|source| source.name == line) {
+            Some(source) => source.clone(),
+            None => mdf::InterruptSource {
+                name: line.to_owned(),
+                description: None,
+            },
+        })
+        .collect()
+}
+
+/// table with the interrupt blocks of the interface
+#[component]
+fn InterruptBlocks(app_data: Signal<HdlWizardApp>, blocks: Vec<mdf::InterruptBlock>) -> Element {
+    // each modification replaces the complete list
+    let update_int = callback_interface(app_data, |interface, value| interface.interrupt_blocks = value);
+    let blocks_new = blocks.clone();
+
+    let lines = blocks.iter().enumerate().map(|(n, block)| {
+        let sources = block
+            .sources
+            .iter()
+            .map(|source| source.name.clone())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let use_force = block.use_force.unwrap_or(false);
+        let blocks_name = blocks.clone();
+        let blocks_sources = blocks.clone();
+        let blocks_force = blocks.clone();
+        let blocks_remove = blocks.clone();
+
+        rsx! {
+            tr { key: "{n}",
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "name",
+                        onchange: move |evt| {
+                            let mut new_blocks = blocks_name.clone();
+                            new_blocks[n].name = evt.value();
+                            gui_blocks::apply_function(app_data, new_blocks, "change interrupt block name", None, Some(update_int), None, None);
+                        },
+                        value: "{block.name}"
+                    }
+                }
+                td {
+                    textarea {
+                        class: "textarea",
+                        rows: "3",
+                        placeholder: "one source per line",
+                        onchange: move |evt| {
+                            let mut new_blocks = blocks_sources.clone();
+                            new_blocks[n].sources = textarea_to_sources(&evt.value(), &blocks_sources[n].sources);
+                            gui_blocks::apply_function(app_data, new_blocks, "change interrupt sources", None, Some(update_int), None, None);
+                        },
+                        value: "{sources}"
+                    }
+                }
+                td {
+                    label { class: "checkbox",
+                        input {
+                            r#type: "checkbox",
+                            onclick: move |_| {
+                                let mut new_blocks = blocks_force.clone();
+                                new_blocks[n].use_force = Some(!use_force);
+                                gui_blocks::apply_function(app_data, new_blocks, "change interrupt force register", None, Some(update_int), None, None);
+                            },
+                            checked: "{use_force}"
+                        }
+                        " Force register"
+                    }
+                }
+                td {
+                    div { class: "buttons are-small ext-buttons-in-table",
+                        button {
+                            class: "button is-danger has-text-white",
+                            onclick: move |_| {
+                                let mut new_blocks = blocks_remove.clone();
+                                new_blocks.remove(n);
+                                gui_blocks::apply_function(app_data, new_blocks, "remove interrupt block", None, Some(update_int), None, None);
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    rsx! {
+        h2 { class: "subtitle page-title", "Interrupt blocks" }
+        p { "Each interrupt block generates status, enable, pending and optionally force registers after the registers above, with one bit per source, and an interrupt request output." }
+        table { class: "table is-striped is-hoverable is-fullwidth",
+            thead {
+                tr {
+                    th { "Name" }
+                    th { "Sources" }
+                    th { "Options" }
+                    th {}
+                }
+            }
+            tbody { {lines} }
+        }
+        div { class: "buttons",
+            button {
+                class: "button is-primary",
+                onclick: move |_| {
+                    let mut new_blocks = blocks_new.clone();
+                    new_blocks.push(mdf::InterruptBlock::new());
+                    gui_blocks::apply_function(app_data, new_blocks, "create interrupt block", None, Some(update_int), None, None);
+                },
+                "New interrupt block"
+            }
+        }
+    }
+}
+
 /// Whole page for an interface
 #[component]
 pub fn Content(app_data: Signal<HdlWizardApp>, interface_num: usize) -> Element {
@@ -142,11 +701,11 @@ pub fn Content(app_data: Signal<HdlWizardApp>, interface_num: usize) -> Element
             .registers
             .iter()
             .enumerate()
-            .map(|(n, reg)| (n, reg.name.clone(), reg.address.clone(), reg.signal.clone()))
+            .map(|(n, reg)| (n, reg.name.clone(), reg.address.clone(), reg.pinned.unwrap_or(false), reg.signal.clone(), reg.group.clone()))
             .collect::<Vec<_>>();
 
         // now build some items from that list
-        let int_items = int_list.iter().map(|(n, int_name, int_address, int_type)| {
+        let int_items = int_list.iter().map(|(n, int_name, int_address, int_pinned, int_type, int_group)| {
             rsx!(
                 TableLine {
                     app_data: app_data,
@@ -154,12 +713,15 @@ pub fn Content(app_data: Signal<HdlWizardApp>, interface_num: usize) -> Element
                     register_name: int_name.clone(),
                     register_type: *int_type,
                     register_address: int_address.clone(),
+                    register_pinned: *int_pinned,
+                    register_group: int_group.clone(),
                     key: "{int_name}{n}"
                 }
             )
         });
 
         let interface_width = interface.get_data_width();
+        let variant_names = app_data.read().data.model.variants.iter().map(|variant| variant.name.clone()).collect::<Vec<_>>();
 
         // render the page
         rsx! {
@@ -218,6 +780,83 @@ pub fn Content(app_data: Signal<HdlWizardApp>, interface_num: usize) -> Element
                     },
                     default: interface_width.unwrap_or(32)
                 }
+                gui_blocks::EnumWidget {
+                    app_data: app_data,
+                    update_int: callback_interface(app_data, |interface, value| interface.addressing = Some(value)),
+                    gui_label: "Addressing",
+                    undo_label: "change interface addressing mode",
+                    value: interface.addressing_mode()
+                }
+                gui_blocks::TextGeneric {
+                    app_data: app_data,
+                    update_int: callback_interface(app_data, |interface, value: String| interface.data_width_expr = utils::text_to_opt_expression(&value)),
+                    gui_label: "Data width expr.",
+                    undo_label: "change interface data width expression",
+                    value: interface.data_width_expr.clone().unwrap_or_default()
+                }
+                gui_blocks::CheckBox {
+                    app_data: app_data,
+                    update_int: callback_interface(app_data, |interface, value| interface.use_byte_enables = Some(value)),
+                    gui_label: "Byte enables",
+                    checkbox_label: "Use write strobes",
+                    undo_label: "change interface byte enables",
+                    value: interface.use_byte_enables.unwrap_or(false)
+                }
+                gui_blocks::CheckBox {
+                    app_data: app_data,
+                    update_int: callback_interface(app_data, |interface, value| interface.separate_clock = Some(value)),
+                    gui_label: "Clock",
+                    checkbox_label: "Separate bus clock",
+                    undo_label: "change interface clock",
+                    value: interface.separate_clock.unwrap_or(false)
+                }
+                gui_blocks::EnumWidget {
+                    app_data: app_data,
+                    update_int: callback_interface(app_data, |interface, value| interface.allocation_order = Some(value)),
+                    gui_label: "Allocation order",
+                    undo_label: "change interface allocation order",
+                    value: interface.allocation_order.unwrap_or(mdf::AllocationOrder::ByGroup)
+                }
+                gui_blocks::CheckBox {
+                    app_data: app_data,
+                    update_int: callback_interface(app_data, |interface, value| interface.align_arrays = Some(value)),
+                    gui_label: "Arrays",
+                    checkbox_label: "Align automatic arrays on their size",
+                    undo_label: "change interface array alignment",
+                    value: interface.align_arrays.unwrap_or(false)
+                }
+                gui_blocks::CheckBox {
+                    app_data: app_data,
+                    update_int: callback_interface(app_data, |interface, value| interface.keep_gaps = Some(value)),
+                    gui_label: "Gaps",
+                    checkbox_label: "Keep the gaps between fixed addresses",
+                    undo_label: "change interface gaps allocation",
+                    value: interface.keep_gaps.unwrap_or(false)
+                }
+                gui_blocks::VariantsWidget {
+                    app_data: app_data,
+                    update_int: callback_interface(app_data, |interface, value| interface.variants = value),
+                    gui_label: "Variants",
+                    undo_label: "change interface variants",
+                    variants: variant_names,
+                    value: interface.variants.clone()
+                }
+                {
+                    if interface.separate_clock.unwrap_or(false) {
+                        rsx! {
+                            gui_blocks::AutoManuText {
+                                app_data: app_data,
+                                update_int: callback_interface(app_data, |interface, value| interface.sync_stages = value),
+                                gui_label: "Synchronizer stages",
+                                undo_label: "change interface synchronizer stages",
+                                value: interface.sync_stages,
+                                default: 2
+                            }
+                        }
+                    } else {
+                        rsx! {}
+                    }
+                }
             }
             h2 { class: "subtitle page-title", "Registers" }
             table { class: "table is-striped is-hoverable is-fullwidth",
@@ -225,6 +864,7 @@ pub fn Content(app_data: Signal<HdlWizardApp>, interface_num: usize) -> Element
                     tr {
                         th { "Name" }
                         th { "Address" }
+                        th { "Group" }
                         th { "Type" }
                         th {}
                     }
@@ -257,7 +897,7 @@ pub fn Content(app_data: Signal<HdlWizardApp>, interface_num: usize) -> Element
                     onclick: move |_| {
                         app_data
                             .with_mut(|app| {
-                                let result = app.get_mut_model().interfaces[interface_num].assign_addresses();
+                                let result = app.get_mut_model().assign_interface_addresses(interface_num);
                                 app.test_result(result);
                                 app.register_undo("assign addresses")
                             })
@@ -276,6 +916,27 @@ pub fn Content(app_data: Signal<HdlWizardApp>, interface_num: usize) -> Element
                     },
                     "Unassign addresses"
                 }
+                button {
+                    class: "button is-link",
+                    onclick: move |_| app_data.with_mut(|app| app.page_type = PageType::AddressMap(interface_num)),
+                    "Address map"
+                }
+            }
+            RegisterGroups {
+                app_data: app_data,
+                groups: interface.groups.clone()
+            }
+            ReservedRanges {
+                app_data: app_data,
+                ranges: interface.reserved.clone()
+            }
+            Memories {
+                app_data: app_data,
+                memories: interface.memories.clone()
+            }
+            InterruptBlocks {
+                app_data: app_data,
+                blocks: interface.interrupt_blocks.clone()
             }
         }
     } else {
@@ -13,7 +13,7 @@ use tera::Tera;
 
 // generate the documentation as a string from the given model
 fn generate_html(model : Arc<mdf::Mdf>, settings: &settings::Settings, templates: &Tera) -> Result<String, Box<dyn Error>> {
-    let model = genmodel::GenModel::from_model(model.as_ref(), settings, templates)?;
+    let model = genmodel::GenModel::from_model(model.as_ref(), settings, templates, None)?;
     documentation::generate_doc(&model, templates)
 }
 
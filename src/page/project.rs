@@ -2,11 +2,14 @@
 #![allow(non_snake_case)]
 
 use crate::app::HdlWizardApp;
+use crate::expression;
 use crate::file_formats::mdf;
 use crate::gui_blocks;
 use crate::gui_blocks::callback_model;
 use crate::page::PageType;
+use crate::utils;
 use dioxus::prelude::*;
+use std::collections::HashMap;
 
 /// builds a line in the table with all the interfaces
 #[component]
@@ -93,10 +96,273 @@ fn TableLine(app_data: Signal<HdlWizardApp>,
     }
 }
 
+/// table with the project parameters
+#[component]
+fn Parameters(app_data: Signal<HdlWizardApp>, parameters: Vec<mdf::Parameter>) -> Element {
+    // each modification replaces the complete list
+    let update_model = callback_model(app_data, |model, value| model.parameters = value);
+    let parameters_new = parameters.clone();
+
+    // evaluate the parameters in order to display their value, each one can use the previous ones
+    let mut values: HashMap<String, u128> = HashMap::new();
+    let results = parameters.iter().map(|parameter| {
+        match expression::evaluate(&parameter.value, &values) {
+            Ok(value) => {
+                values.insert(parameter.name.clone(), value);
+                (value.to_string(), "")
+            }
+            Err(e) => (e, "has-text-danger"),
+        }
+    }).collect::<Vec<_>>();
+
+    let lines = parameters.iter().zip(results).enumerate().map(|(n, (parameter, (result, result_class)))| {
+        let description = utils::opt_vec_str_to_textarea(&parameter.description);
+        let export = parameter.export.unwrap_or(false);
+        let parameters_name = parameters.clone();
+        let parameters_value = parameters.clone();
+        let parameters_description = parameters.clone();
+        let parameters_export = parameters.clone();
+        let parameters_remove = parameters.clone();
+
+        rsx! {
+            tr { key: "{n}",
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "name",
+                        pattern: "^[A-Za-z][0-9A-Za-z_]*$",
+                        onchange: move |evt| {
+                            let mut new_parameters = parameters_name.clone();
+                            new_parameters[n].name = evt.value().trim().to_owned();
+                            gui_blocks::apply_function(app_data, new_parameters, "change parameter name", Some(update_model), None, None, None);
+                        },
+                        value: "{parameter.name}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "value",
+                        onchange: move |evt| {
+                            let mut new_parameters = parameters_value.clone();
+                            new_parameters[n].value = evt.value().trim().to_owned();
+                            gui_blocks::apply_function(app_data, new_parameters, "change parameter value", Some(update_model), None, None, None);
+                        },
+                        value: "{parameter.value}"
+                    }
+                }
+                td { class: "{result_class}", "{result}" }
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "description",
+                        onchange: move |evt| {
+                            let mut new_parameters = parameters_description.clone();
+                            new_parameters[n].description = utils::textarea_to_opt_vec_str(&evt.value());
+                            gui_blocks::apply_function(app_data, new_parameters, "change parameter description", Some(update_model), None, None, None);
+                        },
+                        value: "{description}"
+                    }
+                }
+                td {
+                    label { class: "checkbox",
+                        input {
+                            r#type: "checkbox",
+                            onclick: move |_| {
+                                let mut new_parameters = parameters_export.clone();
+                                new_parameters[n].export = if export { None } else { Some(true) };
+                                gui_blocks::apply_function(app_data, new_parameters, "change parameter export", Some(update_model), None, None, None);
+                            },
+                            checked: "{export}"
+                        }
+                    }
+                }
+                td {
+                    div { class: "buttons are-small ext-buttons-in-table",
+                        button {
+                            class: "button is-danger has-text-white",
+                            onclick: move |_| {
+                                let mut new_parameters = parameters_remove.clone();
+                                new_parameters.remove(n);
+                                gui_blocks::apply_function(app_data, new_parameters, "remove parameter", Some(update_model), None, None, None);
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    rsx! {
+        h2 { class: "subtitle page-title", "Parameters" }
+        p { "Parameters can be used in the expressions giving the data widths, register widths, addresses, array counts, field positions and memory depths. A parameter value can use the parameters defined above it." }
+        table { class: "table is-striped is-hoverable is-fullwidth",
+            thead {
+                tr {
+                    th { "Name" }
+                    th { "Value" }
+                    th { "Result" }
+                    th { "Description" }
+                    th { "Export" }
+                    th {}
+                }
+            }
+            tbody { {lines} }
+        }
+        div { class: "buttons",
+            button {
+                class: "button is-primary",
+                onclick: move |_| {
+                    let mut new_parameters = parameters_new.clone();
+                    new_parameters.push(mdf::Parameter {
+                        value: "0".to_owned(),
+                        ..Default::default()
+                    });
+                    gui_blocks::apply_function(app_data, new_parameters, "create parameter", Some(update_model), None, None, None);
+                },
+                "New parameter"
+            }
+        }
+    }
+}
+
+/// choice of the product variant to generate, saved in the project
+#[component]
+fn ActiveVariant(app_data: Signal<HdlWizardApp>, variants: Vec<mdf::Variant>, active: Option<String>) -> Element {
+    let selected = active.unwrap_or_default();
+    // keep a variant that isn't in the model anymore visible, the validation reports it
+    let mut names = variants.iter().map(|variant| variant.name.clone()).collect::<Vec<_>>();
+    if !selected.is_empty() && !names.contains(&selected) {
+        names.push(selected.clone());
+    }
+    let options = names.into_iter().map(|name| {
+        rsx!( option { selected: "{name == selected}", value: "{name}", "{name}" } )
+    });
+
+    rsx! {
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", "Generated variant" } }
+            div { class: "field-body",
+                div { class: "field",
+                    div { class: "control select",
+                        select {
+                            onchange: move |evt| {
+                                let value = evt.value();
+                                app_data.with_mut(|app| {
+                                    app.get_mut_model().active_variant = if value.is_empty() { None } else { Some(value) };
+                                    app.register_undo("change generated variant")
+                                })
+                            },
+                            option { selected: "{selected.is_empty()}", value: "", "(full register map)" }
+                            {options}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// table with the product variants
+#[component]
+fn Variants(app_data: Signal<HdlWizardApp>, variants: Vec<mdf::Variant>, active: Option<String>) -> Element {
+    // each modification replaces the complete list, renamed variants are followed in the register map
+    let update_model = callback_model(app_data, |model, value| model.set_variants(value));
+    let variants_new = variants.clone();
+
+    let lines = variants.iter().enumerate().map(|(n, variant)| {
+        let description = utils::opt_vec_str_to_textarea(&variant.description);
+        let variants_name = variants.clone();
+        let variants_description = variants.clone();
+        let variants_remove = variants.clone();
+
+        rsx! {
+            tr { key: "{n}",
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "name",
+                        onchange: move |evt| {
+                            let mut new_variants = variants_name.clone();
+                            new_variants[n].name = evt.value().trim().to_owned();
+                            gui_blocks::apply_function(app_data, new_variants, "change variant name", Some(update_model), None, None, None);
+                        },
+                        value: "{variant.name}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "description",
+                        onchange: move |evt| {
+                            let mut new_variants = variants_description.clone();
+                            new_variants[n].description = utils::textarea_to_opt_vec_str(&evt.value());
+                            gui_blocks::apply_function(app_data, new_variants, "change variant description", Some(update_model), None, None, None);
+                        },
+                        value: "{description}"
+                    }
+                }
+                td {
+                    div { class: "buttons are-small ext-buttons-in-table",
+                        button {
+                            class: "button is-danger has-text-white",
+                            onclick: move |_| {
+                                let mut new_variants = variants_remove.clone();
+                                new_variants.remove(n);
+                                gui_blocks::apply_function(app_data, new_variants, "remove variant", Some(update_model), None, None, None);
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    rsx! {
+        h2 { class: "subtitle page-title", "Variants" }
+        p { "Interfaces, registers and fields can be limited to some product variants. The generated variant is saved with the project." }
+        table { class: "table is-striped is-hoverable is-fullwidth",
+            thead {
+                tr {
+                    th { "Name" }
+                    th { "Description" }
+                    th {}
+                }
+            }
+            tbody { {lines} }
+        }
+        div { class: "buttons",
+            button {
+                class: "button is-primary",
+                onclick: move |_| {
+                    let mut new_variants = variants_new.clone();
+                    new_variants.push(mdf::Variant {
+                        name: format!("variant{}", new_variants.len() + 1),
+                        ..Default::default()
+                    });
+                    gui_blocks::apply_function(app_data, new_variants, "create variant", Some(update_model), None, None, None);
+                },
+                "New variant"
+            }
+        }
+        ActiveVariant { app_data: app_data, variants: variants.clone(), active: active }
+    }
+}
+
 /// Whole page for the project top level
 #[component]
 pub fn Content(app_data: Signal<HdlWizardApp>) -> Element {
     let project_name = app_data.read().data.model.name.clone();
+    let parameters = app_data.read().data.model.parameters.clone();
+    let variants = app_data.read().data.model.variants.clone();
+    let active_variant = app_data.read().data.model.active_variant.clone();
 
     // extract a list of interfaces and types
     let int_list = app_data
@@ -159,6 +425,8 @@ pub fn Content(app_data: Signal<HdlWizardApp>) -> Element {
                     "New interface"
                 }
             }
+            Parameters { app_data: app_data, parameters: parameters }
+            Variants { app_data: app_data, variants: variants, active: active_variant }
         }
     }
 }
@@ -12,6 +12,7 @@ use crate::utils;
 use dioxus::prelude::*;
 use std::default::Default;
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 
 /// default values for some fields when changing the signal type
 fn default_fields(interface_width: u32, register: &mut mdf::Register) {
@@ -30,6 +31,8 @@ fn default_fields(interface_width: u32, register: &mut mdf::Register) {
         register.access = None;
         register.reset = None;
         register.core_signal_properties = mdf::CoreSignalProperties::default();
+        register.hardware_access = mdf::HardwareAccessProperties::default();
+        register.enum_values = Vec::new();
     } else {
         if register.width.is_none() {
             register.width = Some(default_width);
@@ -52,6 +55,7 @@ struct GuiAddressStrideProps {
     #[props(!optional)]
     value: Option<mdf::AddressStride>,
     update_reg: Option<EventHandler<(usize,usize,Option<mdf::AddressStride>)>>,
+    gui_label: &'static str,
 }
 
 /// widget for the address stride
@@ -84,6 +88,13 @@ fn AddressStride(props: GuiAddressStrideProps) -> Element {
         Default::default()
     };
     let label_class = if is_stride { "" } else { "has-text-grey-light" };
+    let gui_label = props.gui_label;
+
+    // the inner dimension is kept when this dimension is modified
+    let inner = value.clone().and_then(|addrstr| addrstr.inner);
+    let inner_count = inner.clone();
+    let inner_option = inner.clone();
+    let inner_increment = inner;
 
     let app_data = props.app_data;
     let update_reg = props.update_reg;
@@ -108,6 +119,7 @@ fn AddressStride(props: GuiAddressStrideProps) -> Element {
                                                 radix: utils::RadixType::Decimal,
                                             },
                                             increment: None,
+                                            inner: None,
                                         })
                                     };
                                     gui_blocks::apply_function(
@@ -122,7 +134,7 @@ fn AddressStride(props: GuiAddressStrideProps) -> Element {
                                 },
                                 checked: "{is_stride}"
                             }
-                            " Stride: "
+                            " {gui_label}: "
                         }
                     }
                     div { class: "control", label { class: "{label_class}", "Count: " } }
@@ -137,6 +149,7 @@ fn AddressStride(props: GuiAddressStrideProps) -> Element {
                                     let new_stride = mdf::AddressStride {
                                         count: new_value,
                                         increment: increment_field,
+                                        inner: inner_count.clone(),
                                     };
                                     gui_blocks::apply_function(
                                         app_data,
@@ -163,11 +176,13 @@ fn AddressStride(props: GuiAddressStrideProps) -> Element {
                                             Some(mdf::AddressStride {
                                                 count: count,
                                                 increment: None,
+                                                inner: inner_option.clone(),
                                             })
                                         } else {
                                             Some(mdf::AddressStride {
                                                 count: count,
                                                 increment: Some(Default::default()),
+                                                inner: inner_option.clone(),
                                             })
                                         };
                                         gui_blocks::apply_function(
@@ -199,6 +214,7 @@ fn AddressStride(props: GuiAddressStrideProps) -> Element {
                                         let new_stride = mdf::AddressStride {
                                             count: count,
                                             increment: Some(new_value),
+                                            inner: inner_increment.clone(),
                                         };
                                         gui_blocks::apply_function(
                                             app_data,
@@ -237,6 +253,7 @@ fn CoreProperties(props: GuiCoreProps) -> Element {
     let value = props.value;
     let use_read_enable = value.use_read_enable.unwrap_or(false);
     let use_write_enable = value.use_write_enable.unwrap_or(false);
+    let use_read_handshake = value.use_read_handshake.unwrap_or(false);
 
     let app_data = props.app_data;
     let is_register = props.is_register;
@@ -269,6 +286,20 @@ fn CoreProperties(props: GuiCoreProps) -> Element {
     } else {
         None
     };
+    let handshake_update_function_reg: Option<EventHandler<(usize,usize,bool)>> = if is_register {
+        Some(callback_register(app_data, |register, value| {
+            register.core_signal_properties.use_read_handshake = Some(value)
+        }))
+    } else {
+        None
+    };
+    let handshake_update_function_field: Option<EventHandler<(usize,usize,usize, bool)>> = if !is_register {
+        Some(callback_field(app_data, |field, value| {
+            field.core_signal_properties.use_read_handshake = Some(value)
+        }))
+    } else {
+        None
+    };
 
     // render the html
     rsx! {
@@ -316,6 +347,343 @@ fn CoreProperties(props: GuiCoreProps) -> Element {
                             " Use write enable "
                         }
                     }
+                    div { class: "control",
+                        label { class: "checkbox",
+                            input {
+                                r#type: "checkbox",
+                                onclick: move |_| {
+                                    gui_blocks::apply_function(
+                                        app_data,
+                                        !use_read_handshake,
+                                        "change read handshake core property",
+                                        None,
+                                        None,
+                                        handshake_update_function_reg,
+                                        handshake_update_function_field,
+                                    )
+                                },
+                                checked: "{use_read_handshake}"
+                            }
+                            " Use read handshake "
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// props for the hardware access widget
+#[derive(Props, Clone, PartialEq)]
+struct GuiHardwareProps {
+    app_data: Signal<HdlWizardApp>,
+    #[props(!optional)]
+    value: mdf::HardwareAccessProperties,
+    is_register: bool,
+}
+
+// widget for the hardware access properties
+fn HardwareProperties(props: GuiHardwareProps) -> Element {
+    // variables to help generate the html
+    let value = props.value;
+    let use_set = value.use_set.unwrap_or(false);
+    let use_clear = value.use_clear.unwrap_or(false);
+    let use_write = value.use_write.unwrap_or(false);
+    let priority = value.priority.unwrap_or(mdf::HardwarePriority::Hardware);
+    let priority_disabled = !value.is_used();
+
+    let app_data = props.app_data;
+    let is_register = props.is_register;
+
+    // all the modifications replace the complete properties structure
+    let update_function_reg: Option<EventHandler<(usize,usize,mdf::HardwareAccessProperties)>> = if is_register {
+        Some(callback_register(app_data, |register, value| register.hardware_access = value))
+    } else {
+        None
+    };
+    let update_function_field: Option<EventHandler<(usize,usize,usize,mdf::HardwareAccessProperties)>> = if !is_register {
+        Some(callback_field(app_data, |field, value| field.hardware_access = value))
+    } else {
+        None
+    };
+
+    let value_set = value.clone();
+    let value_clear = value.clone();
+    let value_write = value.clone();
+    let value_priority = value.clone();
+
+    let options = mdf::HardwarePriority::iter().map(|enum_value| {
+        rsx!( option { selected: "{enum_value == priority}", "{enum_value.to_string()}" } )
+    });
+
+    // render the html
+    rsx! {
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", "Hardware access" } }
+            div { class: "field-body",
+                div { class: "field is-grouped is-align-items-center",
+                    div { class: "control",
+                        label { class: "checkbox",
+                            input {
+                                r#type: "checkbox",
+                                onclick: move |_| {
+                                    let mut new_value = value_set.clone();
+                                    new_value.use_set = Some(!use_set);
+                                    gui_blocks::apply_function(
+                                        app_data,
+                                        new_value,
+                                        "change hardware set property",
+                                        None,
+                                        None,
+                                        update_function_reg,
+                                        update_function_field,
+                                    )
+                                },
+                                checked: "{use_set}"
+                            }
+                            " Set "
+                        }
+                    }
+                    div { class: "control",
+                        label { class: "checkbox",
+                            input {
+                                r#type: "checkbox",
+                                onclick: move |_| {
+                                    let mut new_value = value_clear.clone();
+                                    new_value.use_clear = Some(!use_clear);
+                                    gui_blocks::apply_function(
+                                        app_data,
+                                        new_value,
+                                        "change hardware clear property",
+                                        None,
+                                        None,
+                                        update_function_reg,
+                                        update_function_field,
+                                    )
+                                },
+                                checked: "{use_clear}"
+                            }
+                            " Clear "
+                        }
+                    }
+                    div { class: "control",
+                        label { class: "checkbox",
+                            input {
+                                r#type: "checkbox",
+                                onclick: move |_| {
+                                    let mut new_value = value_write.clone();
+                                    new_value.use_write = Some(!use_write);
+                                    gui_blocks::apply_function(
+                                        app_data,
+                                        new_value,
+                                        "change hardware write property",
+                                        None,
+                                        None,
+                                        update_function_reg,
+                                        update_function_field,
+                                    )
+                                },
+                                checked: "{use_write}"
+                            }
+                            " Write with valid "
+                        }
+                    }
+                    div { class: "control", label { "Priority: " } }
+                    div { class: "control select",
+                        select {
+                            onchange: move |evt| {
+                                if let Ok(new_priority) = mdf::HardwarePriority::from_str(&evt.value()) {
+                                    let mut new_value = value_priority.clone();
+                                    new_value.priority = Some(new_priority);
+                                    gui_blocks::apply_function(
+                                        app_data,
+                                        new_value,
+                                        "change hardware priority",
+                                        None,
+                                        None,
+                                        update_function_reg,
+                                        update_function_field,
+                                    )
+                                }
+                            },
+                            disabled: "{priority_disabled}",
+                            {options}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// props for the named values widget
+#[derive(Props, Clone, PartialEq)]
+struct GuiEnumValuesProps {
+    app_data: Signal<HdlWizardApp>,
+    value: Vec<mdf::EnumValue>,
+    is_register: bool,
+}
+
+// widget for the named values list
+fn EnumValues(props: GuiEnumValuesProps) -> Element {
+    // variables to help generate the html
+    let values = props.value;
+    let values_new = values.clone();
+    let name_pattern = String::validate_pattern();
+    let value_pattern = utils::VectorValue::validate_pattern();
+
+    let app_data = props.app_data;
+    let is_register = props.is_register;
+
+    let update_function_reg: Option<EventHandler<(usize,usize,Vec<mdf::EnumValue>)>> = if is_register {
+        Some(callback_register(app_data, |register, value| register.enum_values = value))
+    } else {
+        None
+    };
+    let update_function_field: Option<EventHandler<(usize,usize,usize,Vec<mdf::EnumValue>)>> = if !is_register {
+        Some(callback_field(app_data, |field, value| field.enum_values = value))
+    } else {
+        None
+    };
+
+    // one line in the table per value, each modification replaces the complete list
+    let lines = values.iter().enumerate().map(|(n, enum_value)| {
+        let description = utils::opt_vec_str_to_textarea(&enum_value.description);
+        let values_name = values.clone();
+        let values_value = values.clone();
+        let values_description = values.clone();
+        let values_remove = values.clone();
+
+        rsx! {
+            tr { key: "{n}",
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "name",
+                        pattern: "{name_pattern}",
+                        onchange: move |evt| {
+                            let mut new_values = values_name.clone();
+                            new_values[n].name = evt.value();
+                            gui_blocks::apply_function(
+                                app_data,
+                                new_values,
+                                "change named value name",
+                                None,
+                                None,
+                                update_function_reg,
+                                update_function_field,
+                            );
+                        },
+                        value: "{enum_value.name}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input ext-vector-field",
+                        r#type: "text",
+                        placeholder: "value",
+                        pattern: "{value_pattern}",
+                        onchange: move |evt| {
+                            if let Ok(new_value) = utils::VectorValue::from_str(&evt.value()) {
+                                let mut new_values = values_value.clone();
+                                new_values[n].value = new_value;
+                                gui_blocks::apply_function(
+                                    app_data,
+                                    new_values,
+                                    "change named value",
+                                    None,
+                                    None,
+                                    update_function_reg,
+                                    update_function_field,
+                                );
+                            }
+                        },
+                        value: "{enum_value.value}"
+                    }
+                }
+                td {
+                    input {
+                        class: "input",
+                        r#type: "text",
+                        placeholder: "description",
+                        onchange: move |evt| {
+                            let mut new_values = values_description.clone();
+                            new_values[n].description = utils::textarea_to_opt_vec_str(&evt.value());
+                            gui_blocks::apply_function(
+                                app_data,
+                                new_values,
+                                "change named value description",
+                                None,
+                                None,
+                                update_function_reg,
+                                update_function_field,
+                            );
+                        },
+                        value: "{description}"
+                    }
+                }
+                td {
+                    div { class: "buttons are-small ext-buttons-in-table",
+                        button {
+                            class: "button is-danger has-text-white",
+                            onclick: move |_| {
+                                let mut new_values = values_remove.clone();
+                                new_values.remove(n);
+                                gui_blocks::apply_function(
+                                    app_data,
+                                    new_values,
+                                    "remove named value",
+                                    None,
+                                    None,
+                                    update_function_reg,
+                                    update_function_field,
+                                );
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    // render the html
+    rsx! {
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", "Named values" } }
+            div { class: "field-body",
+                div { class: "field",
+                    table { class: "table is-striped is-hoverable is-fullwidth",
+                        thead {
+                            tr {
+                                th { "Name" }
+                                th { "Value" }
+                                th { "Description" }
+                                th {}
+                            }
+                        }
+                        tbody { {lines} }
+                    }
+                    div { class: "buttons",
+                        button {
+                            class: "button is-primary",
+                            onclick: move |_| {
+                                let mut new_values = values_new.clone();
+                                new_values.push(mdf::EnumValue::new());
+                                gui_blocks::apply_function(
+                                    app_data,
+                                    new_values,
+                                    "create named value",
+                                    None,
+                                    None,
+                                    update_function_reg,
+                                    update_function_field,
+                                );
+                            },
+                            "New value"
+                        }
+                    }
                 }
             }
         }
@@ -329,9 +697,12 @@ fn TableLine(
     field_number: usize,
     field_name: String,
     field_position: mdf::FieldPosition,
+    #[props(!optional)] field_array: Option<mdf::FieldArray>,
     field_access: mdf::AccessType,
     field_type: utils::SignalType,
     is_selected: bool,
+    conflict: String,
+    conflict_is_error: bool,
 ) -> Element {
     let page_type = app_data.read().page_type.clone();
     if let PageType::Register(interface_number, register_number, _) = page_type {
@@ -349,7 +720,24 @@ fn TableLine(
             field_name
         };
 
-        let tr_class = if is_selected { "has-background-info-soft" } else { "" };
+        let display_position = match field_array {
+            None => field_position.to_string(),
+            Some(array) => format!("{} (x{})", field_position, array.count),
+        };
+
+        // highlight the fields overlapping another one or outside of the interface width
+        let tr_class = if conflict.is_empty() {
+            if is_selected { "has-background-info-soft" } else { "" }
+        } else if conflict_is_error {
+            "has-background-danger-soft"
+        } else {
+            "has-background-warning-soft"
+        };
+        let conflict_icon = if conflict_is_error {
+            "fa-solid fa-circle-xmark has-text-danger"
+        } else {
+            "fa-solid fa-triangle-exclamation has-text-warning"
+        };
 
         // render html
         rsx! {
@@ -368,7 +756,18 @@ fn TableLine(
                         "{display_name}"
                     }
                 }
-                td { "{field_position.to_string()}" }
+                td {
+                    "{display_position}"
+                    {
+                        if conflict.is_empty() {
+                            None
+                        } else {
+                            rsx! {
+                                span { class: "icon ml-1", title: "{conflict}", i { class: "{conflict_icon}" } }
+                            }
+                        }
+                    }
+                }
                 td { "{field_access.to_string()}" }
                 td { "{field_type.to_string()}" }
                 td {
@@ -391,60 +790,419 @@ fn TableLine(
                             },
                             span { class: "icon is_small", i { class: "fa-solid fa-caret-up" } }
                         }
-                        button {
-                            class: "button is-link",
-                            disabled: "{down_disabled}",
-                            onclick: move |_| {
-                                if !down_disabled {
-                                    app_data
-                                        .with_mut(|data| {
-                                            data.get_mut_model()
-                                                .interfaces[interface_number]
-                                                .registers[register_number]
-                                                .fields
-                                                .swap(field_number, field_number + 1);
-                                            data.register_undo("move field down")
-                                        })
+                        button {
+                            class: "button is-link",
+                            disabled: "{down_disabled}",
+                            onclick: move |_| {
+                                if !down_disabled {
+                                    app_data
+                                        .with_mut(|data| {
+                                            data.get_mut_model()
+                                                .interfaces[interface_number]
+                                                .registers[register_number]
+                                                .fields
+                                                .swap(field_number, field_number + 1);
+                                            data.register_undo("move field down")
+                                        })
+                                }
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-caret-down" } }
+                        }
+                        button {
+                            class: "button is-link",
+                            onclick: move |_| {
+                                app_data
+                                    .with_mut(|data| {
+                                        data
+                                            .page_type = PageType::ChangeRegisterField(
+                                            interface_number,
+                                            register_number,
+                                            field_number,
+                                        );
+                                    })
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-pen" } }
+                        }
+                        button {
+                            class: "button is-danger has-text-white",
+                            onclick: move |_| {
+                                app_data
+                                    .with_mut(|data| {
+                                        data.get_mut_model()
+                                            .interfaces[interface_number]
+                                            .registers[register_number]
+                                            .fields
+                                            .remove(field_number);
+                                        data.register_undo("remove field")
+                                    })
+                            },
+                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        rsx!{ p { "error.... not in a interface page" } }
+    }
+}
+
+// props for the FIFO widget
+#[derive(Props, Clone, PartialEq)]
+struct GuiFifoProps {
+    app_data: Signal<HdlWizardApp>,
+    #[props(!optional)]
+    value: Option<mdf::FifoProperties>,
+}
+
+// widget for the FIFO properties of a register
+fn FifoProperties(props: GuiFifoProps) -> Element {
+    // variables to help generate the html
+    let is_fifo = props.value.is_some();
+    let value = props.value.unwrap_or_default();
+    let blocked_access = value.blocked_access.unwrap_or(mdf::FifoBlockedAccess::Stall);
+    let use_level = value.use_level.unwrap_or(false);
+    let level_width = value.level_width.unwrap_or(8);
+    let label_class = if is_fifo { "" } else { "has-text-grey-light" };
+    let options_disabled = !is_fifo;
+    let width_disabled = !(is_fifo && use_level);
+    let validate_pattern = u32::validate_pattern();
+
+    let app_data = props.app_data;
+
+    // all the modifications replace the complete properties structure. A FIFO is always in the core
+    let update_reg: Option<EventHandler<(usize,usize,Option<mdf::FifoProperties>)>> = Some(callback_register(app_data, |register, value: Option<mdf::FifoProperties>| {
+        if value.is_some() {
+            register.location = Some(mdf::LocationType::Core);
+        }
+        register.fifo = value;
+        register.clean();
+    }));
+
+    let value_blocked = value.clone();
+    let value_level = value.clone();
+    let value_width = value.clone();
+
+    let options = mdf::FifoBlockedAccess::iter().map(|enum_value| {
+        rsx!( option { selected: "{enum_value == blocked_access}", "{enum_value.to_string()}" } )
+    });
+
+    // render the html
+    rsx! {
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", "FIFO" } }
+            div { class: "field-body",
+                div { class: "field is-grouped is-align-items-center",
+                    div { class: "control",
+                        label { class: "checkbox",
+                            input {
+                                r#type: "checkbox",
+                                onclick: move |_| {
+                                    let new_value = if is_fifo { None } else { Some(mdf::FifoProperties::default()) };
+                                    gui_blocks::apply_function(app_data, new_value, "change register FIFO status", None, None, update_reg, None)
+                                },
+                                checked: "{is_fifo}"
+                            }
+                            " Mapped to a FIFO "
+                        }
+                    }
+                    div { class: "control",
+                        label { class: "label {label_class}", "When blocked" }
+                    }
+                    div { class: "control select",
+                        select {
+                            disabled: "{options_disabled}",
+                            onchange: move |evt| {
+                                if let Ok(blocked_access) = mdf::FifoBlockedAccess::from_str(&evt.value()) {
+                                    let mut new_value = value_blocked.clone();
+                                    new_value.blocked_access = Some(blocked_access);
+                                    gui_blocks::apply_function(app_data, Some(new_value), "change FIFO blocked access", None, None, update_reg, None)
+                                }
+                            },
+                            {options}
+                        }
+                    }
+                    div { class: "control",
+                        label { class: "checkbox {label_class}",
+                            input {
+                                r#type: "checkbox",
+                                disabled: "{options_disabled}",
+                                onclick: move |_| {
+                                    let mut new_value = value_level.clone();
+                                    new_value.use_level = Some(!use_level);
+                                    gui_blocks::apply_function(app_data, Some(new_value), "change FIFO level property", None, None, update_reg, None)
+                                },
+                                checked: "{use_level}"
+                            }
+                            " Level width "
+                        }
+                    }
+                    div { class: "control",
+                        input {
+                            class: "input ext-vector-field",
+                            r#type: "text",
+                            disabled: "{width_disabled}",
+                            pattern: "{validate_pattern}",
+                            onchange: move |evt| {
+                                if let Ok(width) = u32::from_str(&evt.value()) {
+                                    let mut new_value = value_width.clone();
+                                    new_value.level_width = Some(width.max(1));
+                                    gui_blocks::apply_function(app_data, Some(new_value), "change FIFO level width", None, None, update_reg, None)
+                                }
+                            },
+                            value: "{level_width}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// props for the shadow widget
+#[derive(Props, Clone, PartialEq)]
+struct GuiShadowProps {
+    app_data: Signal<HdlWizardApp>,
+    registers: Vec<String>,
+    #[props(!optional)]
+    value: Option<mdf::ShadowProperties>,
+}
+
+// widget for the shadow (double buffer) properties of a register
+fn ShadowProperties(props: GuiShadowProps) -> Element {
+    // variables to help generate the html
+    let is_shadowed = props.value.is_some();
+    let value = props.value.unwrap_or_default();
+    let commit = value.commit.unwrap_or(mdf::ShadowCommit::Strobe);
+    let commit_register = value.commit_register.clone();
+    let label_class = if is_shadowed { "" } else { "has-text-grey-light" };
+    let options_disabled = !is_shadowed;
+    let register_disabled = !(is_shadowed && commit == mdf::ShadowCommit::Register);
+
+    let app_data = props.app_data;
+
+    // all the modifications replace the complete properties structure
+    let update_reg: Option<EventHandler<(usize,usize,Option<mdf::ShadowProperties>)>> = Some(callback_register(app_data, |register, value: Option<mdf::ShadowProperties>| {
+        register.shadow = value;
+        register.clean();
+    }));
+
+    let value_commit = value.clone();
+    let value_register = value.clone();
+
+    let commit_options = mdf::ShadowCommit::iter().map(|enum_value| {
+        rsx!( option { selected: "{enum_value == commit}", "{enum_value.to_string()}" } )
+    });
+    let no_register = commit_register.is_none();
+    let register_options = props.registers.iter().map(|name| {
+        let selected = commit_register.as_ref() == Some(name);
+        rsx!( option { selected: "{selected}", "{name}" } )
+    });
+
+    // render the html
+    rsx! {
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", "Shadow" } }
+            div { class: "field-body",
+                div { class: "field is-grouped is-align-items-center",
+                    div { class: "control",
+                        label { class: "checkbox",
+                            input {
+                                r#type: "checkbox",
+                                onclick: move |_| {
+                                    let new_value = if is_shadowed { None } else { Some(mdf::ShadowProperties::default()) };
+                                    gui_blocks::apply_function(app_data, new_value, "change register shadow status", None, None, update_reg, None)
+                                },
+                                checked: "{is_shadowed}"
+                            }
+                            " Double buffered "
+                        }
+                    }
+                    div { class: "control",
+                        label { class: "label {label_class}", "Commit on" }
+                    }
+                    div { class: "control select",
+                        select {
+                            disabled: "{options_disabled}",
+                            onchange: move |evt| {
+                                if let Ok(commit) = mdf::ShadowCommit::from_str(&evt.value()) {
+                                    let mut new_value = value_commit.clone();
+                                    new_value.commit = Some(commit);
+                                    gui_blocks::apply_function(app_data, Some(new_value), "change shadow commit", None, None, update_reg, None)
+                                }
+                            },
+                            {commit_options}
+                        }
+                    }
+                    div { class: "control select",
+                        select {
+                            disabled: "{register_disabled}",
+                            onchange: move |evt| {
+                                let mut new_value = value_register.clone();
+                                new_value.commit_register = if evt.value() == "(none)" { None } else { Some(evt.value()) };
+                                gui_blocks::apply_function(app_data, Some(new_value), "change shadow commit register", None, None, update_reg, None)
+                            },
+                            option { selected: "{no_register}", "(none)" }
+                            {register_options}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// props for the register group widget
+#[derive(Props, Clone, PartialEq)]
+struct GuiGroupProps {
+    app_data: Signal<HdlWizardApp>,
+    groups: Vec<String>,
+    #[props(!optional)]
+    value: Option<String>,
+    update_reg: Option<EventHandler<(usize,usize,Option<String>)>>,
+}
+
+// combobox to select the group the register belongs to
+fn GroupWidget(props: GuiGroupProps) -> Element {
+    let value = props.value;
+    let options = props.groups.iter().map(|group| {
+        let selected = value.as_ref() == Some(group);
+        rsx!( option { selected: "{selected}", "{group}" } )
+    });
+    let no_group = value.is_none();
+
+    let app_data = props.app_data;
+    let update_reg = props.update_reg;
+
+    rsx! {
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", "Group" } }
+            div { class: "field-body",
+                div { class: "field",
+                    div { class: "control select",
+                        select { onchange: move |evt| {
+                                let new_value = if evt.value() == "(none)" { None } else { Some(evt.value()) };
+                                gui_blocks::apply_function(
+                                    app_data,
+                                    new_value,
+                                    "change register group",
+                                    None,
+                                    None,
+                                    update_reg,
+                                    None,
+                                );
+                            },
+                            option { selected: "{no_group}", "(none)" }
+                            {options}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// props for the field array widget
+#[derive(Props, Clone, PartialEq)]
+struct GuiFieldArrayProps {
+    app_data: Signal<HdlWizardApp>,
+    #[props(!optional)]
+    value: Option<mdf::FieldArray>,
+    update_field: Option<EventHandler<(usize,usize,usize,Option<mdf::FieldArray>)>>,
+}
+
+// widget for the field array
+fn FieldArray(props: GuiFieldArrayProps) -> Element {
+    let validate_pattern = u32::validate_pattern();
+    let value = props.value;
+    let is_array = value.is_some();
+    let array = value.unwrap_or_default();
+    let count_string = if is_array { array.count.to_string() } else { Default::default() };
+    let stride_string = match array.stride {
+        Some(stride) if is_array => stride.to_string(),
+        _ => Default::default(),
+    };
+    let label_class = if is_array { "" } else { "has-text-grey-light" };
+
+    let app_data = props.app_data;
+    let update_field = props.update_field;
+
+    rsx!{
+        div { class: "field is-horizontal",
+            div { class: "field-label is-normal", label { class: "label", " " } }
+            div { class: "field-body",
+                div { class: "field is-grouped is-align-items-center",
+                    div { class: "control",
+                        label { class: "checkbox",
+                            input {
+                                r#type: "checkbox",
+                                onclick: move |_| {
+                                    let new_value = if is_array { None } else { Some(mdf::FieldArray::new()) };
+                                    gui_blocks::apply_function(
+                                        app_data,
+                                        new_value,
+                                        "change field array status",
+                                        None,
+                                        None,
+                                        None,
+                                        update_field,
+                                    );
+                                },
+                                checked: "{is_array}"
+                            }
+                            " Array: "
+                        }
+                    }
+                    div { class: "control", label { class: "{label_class}", "Count: " } }
+                    div { class: "control",
+                        input {
+                            class: "input ext-vector-field",
+                            r#type: "text",
+                            placeholder: "count",
+                            pattern: "{validate_pattern}",
+                            onchange: move |evt| {
+                                if let Ok(new_count) = u32::from_str(&evt.value()) {
+                                    gui_blocks::apply_function(
+                                        app_data,
+                                        Some(mdf::FieldArray { count: new_count, stride: array.stride }),
+                                        "change field array count",
+                                        None,
+                                        None,
+                                        None,
+                                        update_field,
+                                    );
                                 }
                             },
-                            span { class: "icon is_small", i { class: "fa-solid fa-caret-down" } }
-                        }
-                        button {
-                            class: "button is-link",
-                            onclick: move |_| {
-                                app_data
-                                    .with_mut(|data| {
-                                        data
-                                            .page_type = PageType::ChangeRegisterField(
-                                            interface_number,
-                                            register_number,
-                                            field_number,
-                                        );
-                                    })
-                            },
-                            span { class: "icon is_small", i { class: "fa-solid fa-pen" } }
+                            value: "{count_string}",
+                            disabled: "{!is_array}"
                         }
-                        button {
-                            class: "button is-danger has-text-white",
-                            onclick: move |_| {
-                                app_data
-                                    .with_mut(|data| {
-                                        data.get_mut_model()
-                                            .interfaces[interface_number]
-                                            .registers[register_number]
-                                            .fields
-                                            .remove(field_number);
-                                        data.register_undo("remove field")
-                                    })
+                    }
+                    div { class: "control", label { class: "{label_class}", "Stride (bits): " } }
+                    div { class: "control",
+                        input {
+                            class: "input ext-vector-field",
+                            r#type: "text",
+                            placeholder: "auto",
+                            pattern: "{validate_pattern}",
+                            onchange: move |evt| {
+                                let new_stride = u32::from_str(&evt.value()).ok();
+                                gui_blocks::apply_function(
+                                    app_data,
+                                    Some(mdf::FieldArray { count: array.count, stride: new_stride }),
+                                    "change field array stride",
+                                    None,
+                                    None,
+                                    None,
+                                    update_field,
+                                );
                             },
-                            span { class: "icon is_small", i { class: "fa-solid fa-trash" } }
+                            value: "{stride_string}",
+                            disabled: "{!is_array}"
                         }
                     }
                 }
             }
         }
-    } else {
-        rsx!{ p { "error.... not in a interface page" } }
     }
 }
 
@@ -561,6 +1319,306 @@ fn FieldPosition(props: GuiBitFieldPositionProps) -> Element {
     }
 }
 
+/// "resolve overlaps" action. It first shows the proposed moves, and only changes the fields
+/// once they are accepted
+#[component]
+fn ResolveOverlaps(
+    app_data: Signal<HdlWizardApp>,
+    interface_num: usize,
+    register_num: usize,
+    moves: Vec<(usize, String, String, Option<mdf::FieldPosition>)>,
+) -> Element {
+    let mut show_proposal = use_signal(|| false);
+
+    if !show_proposal() {
+        return rsx! {
+            div { class: "notification is-danger is-light",
+                "Some fields use the same bits. "
+                button { class: "button is-small is-danger ml-2",
+                    onclick: move |_| show_proposal.set(true),
+                    "Resolve overlaps"
+                }
+            }
+        };
+    }
+
+    let lines = moves.iter().map(|(n, name, old_position, new_position)| {
+        let new_position = match new_position {
+            Some(position) => position.to_string(),
+            None => "no free bits, not moved".to_owned(),
+        };
+        rsx! {
+            tr { key: "{n}",
+                td { "{name}" }
+                td { "{old_position}" }
+                td { "{new_position}" }
+            }
+        }
+    });
+
+    rsx! {
+        div { class: "notification is-danger is-light",
+            p { "The following fields will be moved to free bits, the other fields keep their position:" }
+            table { class: "table is-narrow",
+                thead {
+                    tr {
+                        th { "Field" }
+                        th { "Current bits" }
+                        th { "New bits" }
+                    }
+                }
+                tbody { {lines} }
+            }
+            div { class: "buttons",
+                button { class: "button is-primary",
+                    onclick: move |_| {
+                        app_data.with_mut(|app| {
+                            let fields = &mut app.get_mut_model().interfaces[interface_num].registers[register_num].fields;
+                            for (n, _, _, position) in moves.iter() {
+                                if let (Some(field), Some(position)) = (fields.get_mut(*n), position) {
+                                    field.position = position.clone();
+                                }
+                            }
+                            app.register_undo("resolve field overlaps")
+                        });
+                        show_proposal.set(false);
+                    },
+                    "Apply"
+                }
+                button { class: "button is-dark",
+                    onclick: move |_| show_proposal.set(false),
+                    "Cancel"
+                }
+            }
+        }
+    }
+}
+
+/// field as shown in the bitmap editor
+#[derive(Clone, PartialEq)]
+struct BitmapField {
+    name: String,
+    position: mdf::FieldPosition,
+    bits: Vec<u32>,
+    is_array: bool,
+}
+
+/// action done by dragging the mouse on the bitmap
+#[derive(Clone, Copy, PartialEq)]
+enum DragMode {
+    /// create a field on unused bits
+    Create,
+    /// move a field
+    Move(usize),
+    /// move the msb of a field
+    ResizeMsb(usize),
+    /// move the lsb of a field
+    ResizeLsb(usize),
+}
+
+/// drag in progress on the bitmap, with the bits where it started and where the mouse is
+#[derive(Clone, Copy, PartialEq)]
+struct DragState {
+    mode: DragMode,
+    start: u32,
+    current: u32,
+}
+
+impl DragState {
+    /// new position of the field at the end of the drag, or None if the drag gives no valid position
+    fn new_position(&self, fields: &[BitmapField]) -> Option<mdf::FieldPosition> {
+        match self.mode {
+            DragMode::Create => Some(mdf::FieldPosition::from_bits(self.start, self.current)),
+            DragMode::Move(n) => fields.get(n)?.position.shifted(self.current as i64 - self.start as i64),
+            DragMode::ResizeMsb(n) => match fields.get(n)?.position {
+                mdf::FieldPosition::Field(_, lsb) => Some(mdf::FieldPosition::from_bits(u32::max(self.current, lsb), lsb)),
+                _ => None,
+            },
+            DragMode::ResizeLsb(n) => match fields.get(n)?.position {
+                mdf::FieldPosition::Field(msb, _) => Some(mdf::FieldPosition::from_bits(msb, u32::min(self.current, msb))),
+                _ => None,
+            },
+        }
+    }
+
+    /// bits used by the field at the end of the drag, or None if the drag gives no valid position
+    fn new_bits(&self, fields: &[BitmapField]) -> Option<Vec<u32>> {
+        match self.mode {
+            // a moved array keeps its elements layout
+            DragMode::Move(n) => fields.get(n)?.bits.iter()
+                .map(|bit| u32::try_from(*bit as i64 + self.current as i64 - self.start as i64).ok())
+                .collect(),
+            _ => match self.new_position(fields)? {
+                mdf::FieldPosition::Single(bit) => Some(vec![bit]),
+                mdf::FieldPosition::Field(msb, lsb) => Some((lsb..=msb).collect()),
+            },
+        }
+    }
+}
+
+/// graphical bitmap of a bitfield register. Dragging on unused bits creates a field, dragging the edge
+/// of a field resizes it and dragging its middle (or any bit with shift) moves it
+#[component]
+fn BitmapEditor(
+    app_data: Signal<HdlWizardApp>,
+    interface_num: usize,
+    register_num: usize,
+    #[props(!optional)] selected: Option<usize>,
+    width: usize,
+    fields: Vec<BitmapField>,
+) -> Element {
+    let mut drag: Signal<Option<DragState>> = use_signal(|| None);
+
+    // build a vector with statuses for each bit, and the field using it
+    let mut bit_statuses = vec![FieldBitStatus::Unused; width];
+    let mut bit_owners: Vec<Option<usize>> = vec![None; width];
+    for (i, field) in fields.iter().enumerate() {
+        for &bit in field.bits.iter() {
+            if (bit as usize) < width {
+                bit_statuses[bit as usize] = update_status(&bit_statuses[bit as usize], i, selected);
+                bit_owners[bit as usize].get_or_insert(i);
+            }
+        }
+    }
+
+    // bits covered by the field being dragged, shown over the bitmap
+    let drag_state = drag();
+    let pending_bits = drag_state.and_then(|state| state.new_bits(&fields)).unwrap_or_default();
+
+    // apply the drag result to the model
+    let fields_end = fields.clone();
+    let end_drag = move |_| {
+        if let Some(state) = drag() {
+            drag.set(None);
+            let position = match state.new_position(&fields_end) {
+                Some(position) => position,
+                None => return,
+            };
+            // the field must stay within the bitmap
+            if !state.new_bits(&fields_end).map_or(false, |bits| bits.iter().all(|bit| (*bit as usize) < width)) {
+                return;
+            }
+            app_data.with_mut(|app| {
+                let register = &mut app.get_mut_model().interfaces[interface_num].registers[register_num];
+                match state.mode {
+                    DragMode::Create => {
+                        let mut field = mdf::Field::new();
+                        field.position = position;
+                        register.fields.push(field);
+                        let field_num = register.fields.len() - 1;
+                        app.page_type = PageType::ChangeRegisterField(interface_num, register_num, field_num);
+                        app.register_undo("create field")
+                    }
+                    DragMode::Move(n) => {
+                        if state.start == state.current {
+                            // a simple click selects the field
+                            app.page_type = PageType::ChangeRegisterField(interface_num, register_num, n);
+                        } else {
+                            // the position is now given by the drag, not by the expressions
+                            register.fields[n].position = position;
+                            register.fields[n].lsb_expr = None;
+                            app.register_undo("move field")
+                        }
+                    }
+                    DragMode::ResizeMsb(n) | DragMode::ResizeLsb(n) => {
+                        if state.start == state.current {
+                            app.page_type = PageType::ChangeRegisterField(interface_num, register_num, n);
+                        } else {
+                            register.fields[n].position = position;
+                            register.fields[n].lsb_expr = None;
+                            register.fields[n].width_expr = None;
+                            app.register_undo("resize field")
+                        }
+                    }
+                }
+            });
+        }
+    };
+
+    // build the table header. We'll have only 16 bits with displayed bit number
+    let regular_colspan = usize::max((width + 8) / 16, 1);
+    let num_headers = width / regular_colspan;
+    let first_colspan = width - regular_colspan * (num_headers - 1);
+    let table_header = (0..num_headers).map(|i| {
+        let colspan = if i == 0 {
+            first_colspan
+        } else {
+            regular_colspan
+        };
+        let display = (num_headers - 1 - i) * regular_colspan;
+        rsx! {
+            th { colspan: "{colspan}", div { class: "ext-bitfield-header", "{display.clone()}" } }
+        }
+    });
+
+    // build the table content, msb first. Cells with different background color depending on the status.
+    // Consecutive fields alternate between two colors to see their limits
+    let table_content = (0..width).rev().map(|bit| {
+        let bit_u32 = bit as u32;
+        let owner = bit_owners[bit];
+        let class = if pending_bits.contains(&bit_u32) {
+            "has-background-warning"
+        } else {
+            match (&bit_statuses[bit], owner) {
+                (FieldBitStatus::Unused, _) => "has-background-grey-light",
+                (FieldBitStatus::Used, Some(n)) if n % 2 == 1 => "has-background-info",
+                (FieldBitStatus::Used, _) => "has-background-link",
+                (FieldBitStatus::Selected, _) => "has-background-primary",
+                (FieldBitStatus::Error, _) => "has-background-danger",
+            }
+        };
+        let title = match owner {
+            Some(n) => format!("bit {}: {}", bit, fields[n].name),
+            None => format!("bit {}: unused", bit),
+        };
+        let owner_field = owner.map(|n| fields[n].clone());
+
+        rsx! {
+            td { class: "ext-bitfield-cell",
+                title: "{title}",
+                onmousedown: move |evt: MouseEvent| {
+                    let mode = match (owner, &owner_field) {
+                        (None, _) => DragMode::Create,
+                        (Some(n), Some(field)) => match field.position {
+                            mdf::FieldPosition::Field(msb, _) if msb == bit_u32 && !field.is_array && !evt.modifiers().contains(Modifiers::SHIFT) => DragMode::ResizeMsb(n),
+                            mdf::FieldPosition::Field(_, lsb) if lsb == bit_u32 && !field.is_array && !evt.modifiers().contains(Modifiers::SHIFT) => DragMode::ResizeLsb(n),
+                            _ => DragMode::Move(n),
+                        },
+                        (Some(n), None) => DragMode::Move(n),
+                    };
+                    drag.set(Some(DragState { mode, start: bit_u32, current: bit_u32 }));
+                },
+                onmouseenter: move |_| {
+                    if let Some(mut state) = drag() {
+                        state.current = bit_u32;
+                        drag.set(Some(state));
+                    }
+                },
+                div { class: "{class} ext-bitfield-contents", " " }
+            }
+        }
+    });
+
+    rsx! {
+        table {
+            class: "ext-bitfield-table",
+            style: "min-width: 100%; user-select: none;",
+            onmouseup: end_drag,
+            onmouseleave: move |_| drag.set(None),
+            thead {
+                tr {
+                    { table_header }
+                }
+            }
+            tbody {
+                tr {
+                    { table_content }
+                }
+            }
+        }
+    }
+}
+
 /// status of a single bit within the displayed bitmap
 #[derive(Clone)]
 enum FieldBitStatus {
@@ -616,6 +1674,18 @@ pub fn Content(props: ContentProps) -> Element {
         if let Some(register) = interface.registers.get(register_num) {
             let interface_data_width = interface.data_width.unwrap_or(32);
 
+            // the other registers of the interface, that can commit a shadowed register
+            let other_registers = interface
+                .registers
+                .iter()
+                .enumerate()
+                .filter(|(n, _)| *n != register_num)
+                .map(|(_, other)| other.name.clone())
+                .collect::<Vec<_>>();
+
+            // the project variants, to limit the register to some of them
+            let variant_names = readappdata.data.model.variants.iter().map(|variant| variant.name.clone()).collect::<Vec<_>>();
+
             // extract a list of fields, positions, access and types
             let fld_list = register
                 .fields
@@ -626,87 +1696,73 @@ pub fn Content(props: ContentProps) -> Element {
                         n,
                         field.name.clone(),
                         field.position.clone(),
+                        field.array,
                         field.access,
                         field.signal,
                     )
                 })
                 .collect::<Vec<_>>();
 
+            // fields overlapping another one are errors, fields beyond the interface width make a split register
+            let interface_width = interface.get_data_width().unwrap_or(32);
+            let overlaps = register.field_overlaps();
+            let conflicts = register.fields.iter().enumerate().map(|(n, field)| {
+                let messages = overlaps.iter().filter_map(|&(f, other, bit)| {
+                    if f == n {
+                        Some(format!("overlaps {} at bit {}", register.fields[other].name, bit))
+                    } else if other == n {
+                        Some(format!("overlaps {} at bit {}", register.fields[f].name, bit))
+                    } else {
+                        None
+                    }
+                }).collect::<Vec<_>>();
+                if !messages.is_empty() {
+                    (messages.join(", "), true)
+                } else if field.span().0 >= interface_width {
+                    (format!("beyond the {} bits interface width, the register is split over {} words",
+                        interface_width, register.word_count(interface_width)), false)
+                } else {
+                    (String::new(), false)
+                }
+            }).collect::<Vec<_>>();
+            // the moves stay within the words already used by the register, so it doesn't get split by them
+            let register_width = register.word_count(interface_width) * interface_width;
+            let overlap_moves = register.resolve_overlaps(register_width).into_iter().map(|(n, position)| {
+                let field = &register.fields[n];
+                (n, field.name.clone(), field.position.to_string(), position)
+            }).collect::<Vec<_>>();
+
             // now build some items from that list
             let fld_items =
                 fld_list
                     .iter()
-                    .map(|(n, fld_name, fld_pos, fld_access, fld_signal)| {
+                    .map(|(n, fld_name, fld_pos, fld_array, fld_access, fld_signal)| {
+                        let (conflict, conflict_is_error) = conflicts[*n].clone();
                         rsx!(
                             TableLine {
                                 app_data: app_data,
                                 field_number: *n,
                                 field_name: fld_name.clone(),
                                 field_position: fld_pos.clone(),
+                                field_array: *fld_array,
                                 field_access: fld_access.clone(),
                                 field_type: fld_signal.clone(),
                                 is_selected: props.field_num == Some(*n),
+                                conflict: conflict,
+                                conflict_is_error: conflict_is_error,
                                 key: "{fld_name}{n}"
                             }
                         )
                     });
 
-            // build a vector with statuses for each bit in the field to display the bitmap
-            let field_width = interface.get_data_width().unwrap_or(32) as usize;
-            let mut bit_statuses = vec![FieldBitStatus::Unused; field_width];
-
-            for (i, field) in register.fields.iter().enumerate() {
-                match field.position {
-                    mdf::FieldPosition::Single(bit) => {
-                        if (bit as usize) < field_width {
-                            bit_statuses[bit as usize] =
-                                update_status(&bit_statuses[bit as usize], i, props.field_num);
-                        }
-                    }
-                    mdf::FieldPosition::Field(msb, lsb) => {
-                        for bit in lsb..=msb {
-                            if (bit as usize) < field_width {
-                                bit_statuses[bit as usize] = update_status(
-                                    &bit_statuses[bit as usize],
-                                    i,
-                                    props.field_num,
-                                );
-                            }
-                        }
-                    }
-                };
-            }
-
-            // build the table header. We'll have only 16 bits with displayed bit number
-            let regular_colspan = usize::max((field_width + 8) / 16, 1);
-            let num_headers = field_width / regular_colspan;
-            let first_colspan = field_width - regular_colspan * (num_headers - 1);
-            let table_header = (0..num_headers).map(|i| {
-                let colspan = if i == 0 {
-                    first_colspan
-                } else {
-                    regular_colspan
-                };
-                let display = (num_headers - 1 - i) * regular_colspan;
-                rsx! {
-                    th { colspan: "{colspan}", div { class: "ext-bitfield-header", "{display.clone()}" } }
-                }
-            });
-
-            // build the table content. empty cells with different background color depending on the status
-            bit_statuses.reverse();
-            let table_content = bit_statuses.iter().map(|element| {
-                let class = match element {
-                    FieldBitStatus::Unused => "has-background-dark",
-                    FieldBitStatus::Used => "has-background-link",
-                    FieldBitStatus::Selected => "has-background-primary",
-                    FieldBitStatus::Error => "has-background-danger",
-                };
-
-                rsx! {
-                    td { class: "ext-bitfield-cell", div { class: "{class} ext-bitfield-contents", " " } }
-                }
-            });
+            // the bitmap shows all the bits of the register. A register wider than the interface shows all its words
+            let bitmap_width = register_width as usize;
+            let bitmap_fields = register.fields.iter().map(|field| BitmapField {
+                name: field.name.clone(),
+                position: field.position.clone(),
+                bits: field.bits(),
+                is_array: field.array.is_some(),
+            }).collect::<Vec<_>>();
 
             rsx! {
                 div {
@@ -745,6 +1801,29 @@ pub fn Content(props: ContentProps) -> Element {
                         undo_label: "change register description",
                         value: register.description.clone()
                     }
+                    gui_blocks::VariantsWidget {
+                        app_data: app_data,
+                        update_reg: callback_register(app_data, |register, value| register.variants = value),
+                        gui_label: "Variants",
+                        undo_label: "change register variants",
+                        variants: variant_names.clone(),
+                        value: register.variants.clone()
+                    }
+                    {
+                        // group selection, only if the interface has groups
+                        if interface.groups.is_empty() {
+                            rsx! {}
+                        } else {
+                            rsx! {
+                                GroupWidget {
+                                    app_data: app_data,
+                                    update_reg: callback_register(app_data, |register, value| register.group = value),
+                                    groups: interface.groups.iter().map(|group| group.name.clone()).collect::<Vec<_>>(),
+                                    value: register.group.clone()
+                                }
+                            }
+                        }
+                    }
                     gui_blocks::AutoManuText {
                         app_data: app_data,
                         update_reg: callback_register(app_data, |register, value| register.address.value = value),
@@ -753,10 +1832,51 @@ pub fn Content(props: ContentProps) -> Element {
                         undo_label: "change register base address",
                         value: register.address.value
                     }
+                    gui_blocks::CheckBox {
+                        app_data: app_data,
+                        update_reg: callback_register(app_data, |register, value| register.pinned = Some(value)),
+                        gui_label: "Pin address",
+                        checkbox_label: "Keep this address when the addresses are unassigned",
+                        undo_label: "change register address pinning",
+                        value: register.pinned.unwrap_or(false)
+                    }
                     AddressStride {
                         app_data: app_data,
                         update_reg: callback_register(app_data, |register, value| register.address.stride = value),
-                        value: register.address.stride.clone()
+                        value: register.address.stride.clone(),
+                        gui_label: "Stride"
+                    }
+                    {
+                        // second dimension, only available if the register already is an array
+                        match &register.address.stride {
+                            Some(stride) => rsx! {
+                                AddressStride {
+                                    app_data: app_data,
+                                    update_reg: callback_register(app_data, |register, value: Option<mdf::AddressStride>| {
+                                        if let Some(stride) = &mut register.address.stride {
+                                            stride.inner = value.map(Box::new);
+                                        }
+                                    }),
+                                    value: stride.inner.clone().map(|inner| *inner),
+                                    gui_label: "Inner stride"
+                                }
+                            },
+                            None => rsx! {}
+                        }
+                    }
+                    gui_blocks::TextGeneric {
+                        app_data: app_data,
+                        update_reg: callback_register(app_data, |register, value: String| register.address_expr = utils::text_to_opt_expression(&value)),
+                        gui_label: "Address expr.",
+                        undo_label: "change register address expression",
+                        value: register.address_expr.clone().unwrap_or_default()
+                    }
+                    gui_blocks::TextGeneric {
+                        app_data: app_data,
+                        update_reg: callback_register(app_data, |register, value: String| register.count_expr = utils::text_to_opt_expression(&value)),
+                        gui_label: "Count expr.",
+                        undo_label: "change register array count expression",
+                        value: register.count_expr.clone().unwrap_or_default()
                     }
                     gui_blocks::OptionEnumWidget {
                         app_data: app_data,
@@ -788,6 +1908,13 @@ pub fn Content(props: ContentProps) -> Element {
                                     undo_label: "change register name",
                                     value: register.width.unwrap_or_default(),
                                 },
+                                gui_blocks::TextGeneric {
+                                    app_data: app_data,
+                                    update_reg: callback_register(app_data, |register, value: String| register.width_expr = utils::text_to_opt_expression(&value)),
+                                    gui_label: "Width expr.",
+                                    undo_label: "change register width expression",
+                                    value: register.width_expr.clone().unwrap_or_default(),
+                                },
                                 gui_blocks::OptionEnumWidget {
                                     app_data: app_data,
                                     gui_label: "Access",
@@ -808,6 +1935,25 @@ pub fn Content(props: ContentProps) -> Element {
                                     value: register.core_signal_properties.clone(),
                                     is_register: true
                                 },
+                                HardwareProperties {
+                                    app_data: app_data,
+                                    value: register.hardware_access.clone(),
+                                    is_register: true
+                                },
+                                FifoProperties {
+                                    app_data: app_data,
+                                    value: register.fifo.clone()
+                                },
+                                ShadowProperties {
+                                    app_data: app_data,
+                                    registers: other_registers.clone(),
+                                    value: register.shadow.clone()
+                                },
+                                EnumValues {
+                                    app_data: app_data,
+                                    value: register.enum_values.clone(),
+                                    is_register: true
+                                },
                             }
                         } else { // signal is bitfield
 
@@ -827,20 +1973,19 @@ pub fn Content(props: ContentProps) -> Element {
                                         }
                                     })
                                 },
+                                ShadowProperties {
+                                    app_data: app_data,
+                                    registers: other_registers.clone(),
+                                    value: register.shadow.clone()
+                                },
                                 h2 { class:"subtitle page-title", "Fields"},
-                                table {
-                                    class: "ext-bitfield-table",
-                                    style: "min-width: 100%;",
-                                    thead {
-                                        tr {
-                                            { table_header }
-                                        }
-                                    }
-                                    tbody {
-                                        tr {
-                                            { table_content }
-                                        }
-                                    }
+                                BitmapEditor {
+                                    app_data: app_data,
+                                    interface_num: interface_num,
+                                    register_num: register_num,
+                                    selected: props.field_num,
+                                    width: bitmap_width,
+                                    fields: bitmap_fields
                                 }
 
                                 table {
@@ -890,6 +2035,20 @@ pub fn Content(props: ContentProps) -> Element {
                                         "Unassign bits"
                                     },
                                 },
+                                {
+                                    if overlap_moves.is_empty() {
+                                        None
+                                    } else {
+                                        rsx! {
+                                            ResolveOverlaps {
+                                                app_data: app_data,
+                                                interface_num: interface_num,
+                                                register_num: register_num,
+                                                moves: overlap_moves.clone()
+                                            }
+                                        }
+                                    }
+                                },
                                 {
                                     if let Some(field_num) = props.field_num {
                                         if let Some(field) = register.fields.get(field_num) {
@@ -912,6 +2071,25 @@ pub fn Content(props: ContentProps) -> Element {
                                                     update_field: callback_field(app_data, |field, value | field.position = value),
                                                     value: field.position.clone()
                                                 },
+                                                gui_blocks::TextGeneric {
+                                                    app_data: app_data,
+                                                    update_field: callback_field(app_data, |field, value: String| field.lsb_expr = utils::text_to_opt_expression(&value)),
+                                                    gui_label: "LSB expr.",
+                                                    undo_label: "change field lsb expression",
+                                                    value: field.lsb_expr.clone().unwrap_or_default()
+                                                },
+                                                gui_blocks::TextGeneric {
+                                                    app_data: app_data,
+                                                    update_field: callback_field(app_data, |field, value: String| field.width_expr = utils::text_to_opt_expression(&value)),
+                                                    gui_label: "Width expr.",
+                                                    undo_label: "change field width expression",
+                                                    value: field.width_expr.clone().unwrap_or_default()
+                                                },
+                                                FieldArray {
+                                                    app_data: app_data,
+                                                    update_field: callback_field(app_data, |field, value | field.array = value),
+                                                    value: field.array
+                                                },
                                                 gui_blocks::TextArea {
                                                     app_data: app_data,
                                                     update_field: callback_field(app_data, |field, value | field.description = value),
@@ -919,6 +2097,14 @@ pub fn Content(props: ContentProps) -> Element {
                                                     undo_label: "change field description",
                                                     value: field.description.clone()
                                                 },
+                                                gui_blocks::VariantsWidget {
+                                                    app_data: app_data,
+                                                    update_field: callback_field(app_data, |field, value| field.variants = value),
+                                                    gui_label: "Variants",
+                                                    undo_label: "change field variants",
+                                                    variants: variant_names.clone(),
+                                                    value: field.variants.clone()
+                                                },
                                                 gui_blocks::EnumWidget {
                                                     app_data: app_data,
                                                     gui_label: "Access",
@@ -954,6 +2140,16 @@ pub fn Content(props: ContentProps) -> Element {
                                                     value: field.core_signal_properties.clone(),
                                                     is_register: false
                                                 },
+                                                HardwareProperties {
+                                                    app_data: app_data,
+                                                    value: field.hardware_access.clone(),
+                                                    is_register: false
+                                                },
+                                                EnumValues {
+                                                    app_data: app_data,
+                                                    value: field.enum_values.clone(),
+                                                    is_register: false
+                                                },
                                             )
                                         } else {
                                             rsx!(
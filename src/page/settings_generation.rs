@@ -0,0 +1,117 @@
+//! page to edit code generation settings
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use crate::app::HdlWizardApp;
+use crate::settings::Settings;
+
+// table line with a combobox for an enum setting
+fn EnumLine<F: PartialEq + Clone + strum::IntoEnumIterator + std::string::ToString + std::str::FromStr + 'static>(
+    mut app_data: Signal<HdlWizardApp>, label: &str, description: &str, value: F, update: fn(&mut Settings, F)) -> Element {
+
+    let options = F::iter().map(|enum_value| {
+        rsx!( option { selected: "{enum_value == value}", "{enum_value.to_string()}" } )
+    });
+
+    rsx! {
+        tr {
+            td {
+                div { class: "field-label is-normal", label { class: "label", "{label}" } }
+            }
+            td {
+                div { class: "field-body",
+                    div { class: "field",
+                        div { class: "control select",
+                            select {
+                                onchange: move |evt| {
+                                    if let Ok(value) = F::from_str(&evt.value()) {
+                                        app_data.with_mut(|appdata| update(&mut appdata.data.settings, value));
+                                    }
+                                },
+                                {options}
+                            }
+                        }
+                    }
+                }
+            }
+            td {
+                "{description}"
+            }
+        }
+    }
+}
+
+// table line with a signal name
+fn NameLine(mut app_data: Signal<HdlWizardApp>, label: &str, description: &str, value: String, default_value: &'static str, update: fn(&mut Settings, String)) -> Element {
+    rsx! {
+        tr {
+            td {
+                div { class: "field-label is-normal", label { class: "label", "{label}" } }
+            }
+            td {
+                div { class: "field-body",
+                    div { class: "field",
+                        div { class: "control",
+                            input {
+                                class: "input",
+                                r#type: "text",
+                                placeholder: "{default_value}",
+                                pattern: "^[A-Za-z]([0-9A-Za-z]|_[0-9A-Za-z])*$",
+                                size: "40",
+                                onchange: move |evt| {
+                                    app_data.with_mut(|appdata| {
+                                        update(
+                                            &mut appdata.data.settings,
+                                            if evt.value().is_empty() {
+                                                default_value.to_owned()
+                                            } else {
+                                                evt.value()
+                                            }
+                                        );
+                                    })
+                                },
+                                value: "{value}"
+                            }
+                        }
+                    }
+                }
+            }
+            td {
+                "{description}"
+            }
+        }
+    }
+}
+
+// main page
+#[component]
+pub fn Content(app_data: Signal<HdlWizardApp>) -> Element {
+    let settings = app_data.read().data.settings.clone();
+
+    rsx! {
+        h1 { class: "title page-title", "Settings: Code generation" },
+        p { "Configure here the VHDL language revision and the coding style used for the generated code."}
+        table {
+            class:"table is-striped is-hoverable is-fullwidth",
+            thead {
+                tr {
+                    th { "Name" }
+                    th { "value" }
+                    th { "Description" }
+                }
+            }
+            tbody {
+                { EnumLine(app_data, "VHDL revision", "Language revision used for the generated code. It sets the format of the literals, sized bit string literals with VHDL-2008 and hexadecimal or binary strings with VHDL-93, and the conversion of the boolean signals to the bus data, a conditional expression with VHDL-2008 and a package function with VHDL-93",
+                    settings.vhdl_revision, |settings, value| settings.vhdl_revision = value) }
+                { NameLine(app_data, "Clock", "Name of the clock signal", settings.clock_name.clone(), "clk",
+                    |settings, value| settings.clock_name = value) }
+                { NameLine(app_data, "Reset", "Name of the reset signal", settings.reset_name.clone(), "rst",
+                    |settings, value| settings.reset_name = value) }
+                { EnumLine(app_data, "Reset polarity", "Active level of the reset signal",
+                    settings.reset_polarity, |settings, value| settings.reset_polarity = value) }
+                { EnumLine(app_data, "Reset type", "An asynchronous reset is in the processes sensitivity lists, a synchronous one is only sampled on the clock edge",
+                    settings.reset_type, |settings, value| settings.reset_type = value) }
+            }
+        }
+    }
+}
@@ -79,7 +79,7 @@ fn Table(app_data: Signal<HdlWizardApp>, templates: Iter<'_, user_strings::UserS
 pub fn Content(app_data: Signal<HdlWizardApp>) -> Element {
     // this string needs to be made here or else the rsx macro will try to format it, and escaping { and } seems to work
     // in different ways between the web and desktop platforms
-    let names_description = r#"Each string must have a * for digits to prevent duplicates, and can also use "{{ project }}", "{{ interface }}", "{{ register }}" and "{{ field }}", when applicable. The resulting string must be a valid VHDL identifier."#;
+    let names_description = r#"Each string must have a * for digits to prevent duplicates, and can also use "{{ project }}", "{{ interface }}", "{{ register }}", "{{ field }}", "{{ value }}", "{{ block }}", "{{ memory }}" and "{{ parameter }}", when applicable. The resulting string must be a valid VHDL identifier."#;
     let description_description = r#"These strings are used in comments or the documentation. They can contain "{{ full_name }}", which will be replaced by the name of the object described."#;
 
     rsx! {
@@ -88,7 +88,7 @@ pub fn Content(app_data: Signal<HdlWizardApp>) -> Element {
         h1 { class: "subtitle page-title", "Names" },
         p { {names_description } }
 
-        { Table(app_data, user_strings::USER_NAMES_SPECS.iter(),r"^(\{\{ *(project|interface|register|field) *\}\}|[A-Za-z])(\{\{ *(project|interface|register|field) *\}\}|[0-9A-Za-z_])*\*(\{\{ *(project|interface|register|field) *\}\}|[0-9A-Za-z_])*$") }
+        { Table(app_data, user_strings::USER_NAMES_SPECS.iter(),r"^(\{\{ *(project|interface|register|field|value|block|memory|parameter) *\}\}|[A-Za-z])(\{\{ *(project|interface|register|field|value|block|memory|parameter) *\}\}|[0-9A-Za-z_])*\*(\{\{ *(project|interface|register|field|value|block|memory|parameter) *\}\}|[0-9A-Za-z_])*$") }
 
         h1 { class: "subtitle page-title", "Descriptions" },
         p { {description_description} }
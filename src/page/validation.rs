@@ -0,0 +1,98 @@
+//! page listing the problems found in the model
+#![allow(non_snake_case)]
+
+use crate::app::HdlWizardApp;
+use crate::file_formats::mdf;
+use crate::page::{PageType, SettingsPageType};
+use crate::validation::{self, Severity};
+use dioxus::prelude::*;
+
+/// readable location of a diagnostic in the model
+fn location(model: &mdf::Mdf, page: &PageType) -> String {
+    let interface_name = |n: usize| model.interfaces.get(n).map_or(String::new(), |interface| interface.name.clone());
+    let register_name = |n: usize, r: usize| {
+        model.interfaces.get(n).and_then(|interface| interface.registers.get(r)).map_or(String::new(), |register| register.name.clone())
+    };
+
+    match page {
+        PageType::Project => "project".to_owned(),
+        PageType::Interface(n) => format!("interface {}", interface_name(*n)),
+        PageType::Register(n, r, None) => format!("{} / {}", interface_name(*n), register_name(*n, *r)),
+        PageType::Register(n, r, Some(f)) => {
+            let field_name = model.interfaces.get(*n)
+                .and_then(|interface| interface.registers.get(*r))
+                .and_then(|register| register.fields.get(*f))
+                .map_or(String::new(), |field| field.name.clone());
+            format!("{} / {}.{}", interface_name(*n), register_name(*n, *r), field_name)
+        }
+        PageType::Settings(SettingsPageType::Generation) => "code generation settings".to_owned(),
+        _ => String::new(),
+    }
+}
+
+/// Whole page with the validation results
+#[component]
+pub fn Content(app_data: Signal<HdlWizardApp>) -> Element {
+    // the validation runs on each render, so the list follows the model edits
+    let (lines, error_count, warning_count) = {
+        let data = &app_data.read().data;
+        let diagnostics = validation::validate(&data.model);
+        let error_count = diagnostics.iter().filter(|diagnostic| diagnostic.severity == Severity::Error).count();
+        let warning_count = diagnostics.len() - error_count;
+        let lines = diagnostics.into_iter().map(|diagnostic| {
+            let where_text = location(&data.model, &diagnostic.page);
+            (diagnostic, where_text)
+        }).collect::<Vec<_>>();
+        (lines, error_count, warning_count)
+    };
+
+    let items = lines.into_iter().enumerate().map(|(n, (diagnostic, where_text))| {
+        let (icon_class, severity_text) = match diagnostic.severity {
+            Severity::Error => ("fa-solid fa-circle-xmark has-text-danger", "error"),
+            Severity::Warning => ("fa-solid fa-triangle-exclamation has-text-warning", "warning"),
+        };
+        let page = diagnostic.page.clone();
+        rsx! {
+            tr { key: "{n}",
+                td {
+                    span { class: "icon-text",
+                        span { class: "icon", i { class: "{icon_class}" } }
+                        span { "{severity_text}" }
+                    }
+                }
+                td {
+                    a {
+                        onclick: move |_| app_data.with_mut(|app| app.page_type = page.clone()),
+                        "{where_text}"
+                    }
+                }
+                td { "{diagnostic.message}" }
+            }
+        }
+    });
+
+    rsx! {
+        div { class: "container",
+            h1 { class: "title page-title", "Validation" }
+            {
+                if error_count + warning_count == 0 {
+                    rsx! { p { "No problem found in the model." } }
+                } else {
+                    rsx! {
+                        p { "{error_count} error(s) and {warning_count} warning(s). Click on a location to go to the page where the problem can be fixed." }
+                        table { class: "table is-striped is-hoverable is-fullwidth",
+                            thead {
+                                tr {
+                                    th { "Severity" }
+                                    th { "Location" }
+                                    th { "Message" }
+                                }
+                            }
+                            tbody { {items} }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
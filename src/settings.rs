@@ -3,6 +3,67 @@
 use serde::{Deserialize, Serialize};
 use std::default::Default;
 use std::collections::BTreeMap;
+use strum_macros;
+
+#[derive(
+    Serialize,
+    Deserialize,
+    strum_macros::ToString,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+/// VHDL language revision used for the generated code
+pub enum VhdlRevision {
+    /// VHDL-93, for older tools
+    #[strum(serialize = "VHDL-93")]
+    Vhdl93,
+    /// VHDL-2008
+    #[strum(serialize = "VHDL-2008")]
+    Vhdl2008,
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    strum_macros::ToString,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+/// active level of the reset signal
+pub enum ResetPolarity {
+    /// reset is active when '1'
+    #[strum(serialize = "active high")]
+    ActiveHigh,
+    /// reset is active when '0'
+    #[strum(serialize = "active low")]
+    ActiveLow,
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    strum_macros::ToString,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+/// the way the reset signal is used in the generated processes
+pub enum ResetType {
+    /// reset is in the process sensitivity list
+    #[strum(serialize = "asynchronous")]
+    Asynchronous,
+    /// reset is only sampled on the clock edge
+    #[strum(serialize = "synchronous")]
+    Synchronous,
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(default)]
@@ -14,7 +75,17 @@ pub struct Settings {
     /// undo level
     pub undo_level: u32,
     /// user templates
-    pub user_templates: BTreeMap<String,String>
+    pub user_templates: BTreeMap<String,String>,
+    /// VHDL revision for the generated code
+    pub vhdl_revision: VhdlRevision,
+    /// name of the clock signal in the generated code
+    pub clock_name: String,
+    /// name of the reset signal in the generated code
+    pub reset_name: String,
+    /// reset active level
+    pub reset_polarity: ResetPolarity,
+    /// synchronous or asynchronous reset
+    pub reset_type: ResetType,
 }
 
 impl Default for Settings {
@@ -23,7 +94,12 @@ impl Default for Settings {
         Settings {
             dark_mode: None,
             undo_level: 10,
-            user_templates: Default::default()
+            user_templates: Default::default(),
+            vhdl_revision: VhdlRevision::Vhdl2008,
+            clock_name: "clk".to_owned(),
+            reset_name: "rst".to_owned(),
+            reset_polarity: ResetPolarity::ActiveHigh,
+            reset_type: ResetType::Asynchronous,
         }
     }
 }
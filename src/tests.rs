@@ -1,4 +1,6 @@
 #[cfg(test)]
+mod common;
+#[cfg(test)]
 mod address;
 #[cfg(test)]
 mod opt_vec_str;
@@ -6,3 +8,47 @@ mod opt_vec_str;
 mod vector_value;
 #[cfg(test)]
 mod tokenlist;
+#[cfg(test)]
+mod vhdl_literal;
+#[cfg(test)]
+mod interrupt_block;
+#[cfg(test)]
+mod field_array;
+#[cfg(test)]
+mod register_group;
+#[cfg(test)]
+mod memory;
+#[cfg(test)]
+mod fifo;
+#[cfg(test)]
+mod cdc;
+#[cfg(test)]
+mod wide_register;
+#[cfg(test)]
+mod shadow;
+#[cfg(test)]
+mod expression;
+#[cfg(test)]
+mod variant;
+#[cfg(test)]
+mod validation;
+#[cfg(test)]
+mod field_overlap;
+#[cfg(test)]
+mod address_map;
+#[cfg(test)]
+mod allocation;
+#[cfg(test)]
+mod addressing;
+#[cfg(test)]
+mod diff;
+#[cfg(test)]
+mod byte_enable;
+#[cfg(test)]
+mod access_type;
+#[cfg(test)]
+mod read_handshake;
+#[cfg(test)]
+mod hardware_access;
+#[cfg(test)]
+mod enum_values;
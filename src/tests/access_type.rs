@@ -0,0 +1,90 @@
+//! Tests for the access types and the flags they give to the generation
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf::{self, AccessType};
+use crate::generate::genmodel::GenField;
+use strum::IntoEnumIterator;
+
+/// read, write, read clear, write 1 clear, write 1 set, write 0 clear, write pulse and masked write flags
+fn flags(field: &GenField) -> [bool; 8] {
+    [
+        field.is_read,
+        field.is_write,
+        field.is_read_clear,
+        field.is_write_one_clear,
+        field.is_write_one_set,
+        field.is_write_zero_clear,
+        field.is_write_pulse,
+        field.is_write_masked,
+    ]
+}
+
+/// expected flags for each access type, in the AccessType order
+const EXPECTED: [[bool; 8]; 8] = [
+    [true, true, false, false, false, false, false, false],
+    [true, false, false, false, false, false, false, false],
+    [false, true, false, false, false, false, false, false],
+    [true, false, true, false, false, false, false, false],
+    [true, true, false, true, false, false, false, true],
+    [true, true, false, false, true, false, false, true],
+    [true, true, false, false, false, true, false, true],
+    [false, true, false, false, false, false, true, false],
+];
+
+/// build a register with the given access type and location
+fn access_register(access: AccessType, location: mdf::LocationType) -> mdf::Register {
+    let mut register = register(&access.to_string(), None);
+    register.access = Some(access);
+    register.location = Some(location);
+    register
+}
+
+/// each access type gives its flags to a register
+#[test]
+fn register_flags() {
+    let registers = AccessType::iter().map(|access| access_register(access, mdf::LocationType::Pif)).collect();
+    let generated = gen_model(&model(registers));
+    let flags = generated.interfaces[0].registers.iter().map(|register| flags(&register.fields[0])).collect::<Vec<_>>();
+    assert_eq!(EXPECTED.to_vec(), flags);
+    assert_eq!(
+        AccessType::iter().map(|access| access.to_string()).collect::<Vec<_>>(),
+        generated.interfaces[0].registers.iter().map(|register| register.fields[0].rw_mode.clone()).collect::<Vec<_>>()
+    );
+}
+
+/// and to the fields of a bitfield
+#[test]
+fn field_flags() {
+    let mut bitfield = register("flags", None);
+    bitfield.signal = None;
+    bitfield.fields = AccessType::iter().enumerate().map(|(n, access)| {
+        let mut field = mdf::Field::new();
+        field.name = access.to_string();
+        field.position = mdf::FieldPosition::Single(n as u32);
+        field.access = access;
+        field
+    }).collect();
+    let generated = gen_model(&model(vec![bitfield]));
+    let flags = generated.interfaces[0].registers[0].fields.iter().map(flags).collect::<Vec<_>>();
+    assert_eq!(EXPECTED.to_vec(), flags);
+}
+
+/// in the core, the read clear needs a read strobe and the masked writes a write strobe
+#[test]
+fn core_strobes() {
+    let registers = AccessType::iter().map(|access| access_register(access, mdf::LocationType::Core)).collect();
+    let generated = gen_model(&model(registers));
+    let strobes = generated.interfaces[0].registers.iter()
+        .map(|register| (register.fields[0].rw_mode.clone(), register.fields[0].core_read_enable, register.fields[0].core_write_enable))
+        .filter(|(_, read_enable, write_enable)| *read_enable || *write_enable)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("RC".to_owned(), true, false),
+            ("W1C".to_owned(), false, true),
+            ("W1S".to_owned(), false, true),
+            ("W0C".to_owned(), false, true),
+        ],
+        strobes
+    );
+}
@@ -37,7 +37,8 @@ fn from_str() {
                     value: 10,
                     radix: utils::RadixType::Decimal
                 },
-                increment: None
+                increment: None,
+                inner: None
             })
         },
         mdf::Address::from_str("0x40:stride:10").unwrap()
@@ -54,7 +55,8 @@ fn from_str() {
                     value: 4,
                     radix: utils::RadixType::Decimal
                 },
-                increment: None
+                increment: None,
+                inner: None
             })
         },
         mdf::Address::from_str("0x40:stride:4").unwrap()
@@ -74,7 +76,8 @@ fn from_str() {
                 increment: Some(utils::VectorValue {
                     value: 4,
                     radix: utils::RadixType::Hexadecimal
-                })
+                }),
+                inner: None
             })
         },
         mdf::Address::from_str("0x40:stride:10:0x4").unwrap()
@@ -88,7 +91,8 @@ fn from_str() {
                     value: 4,
                     radix: utils::RadixType::Decimal
                 },
-                increment: None
+                increment: None,
+                inner: None
             })
         },
         mdf::Address::from_str("auto:stride:4").unwrap()
@@ -105,7 +109,8 @@ fn from_str() {
                 increment: Some(utils::VectorValue {
                     value: 4,
                     radix: utils::RadixType::Hexadecimal
-                })
+                }),
+                inner: None
             })
         },
         mdf::Address::from_str("auto:stride:10:0x4").unwrap()
@@ -155,7 +160,8 @@ fn to_str() {
                     value: 10,
                     radix: utils::RadixType::Decimal
                 },
-                increment: None
+                increment: None,
+                inner: None
             })
         }
         .to_string(),
@@ -176,7 +182,8 @@ fn to_str() {
                 increment: Some(utils::VectorValue {
                     value: 4,
                     radix: utils::RadixType::Hexadecimal
-                })
+                }),
+                inner: None
             })
         }
         .to_string(),
@@ -191,7 +198,8 @@ fn to_str() {
                     value: 10,
                     radix: utils::RadixType::Decimal
                 },
-                increment: None
+                increment: None,
+                inner: None
             })
         }
         .to_string(),
@@ -209,10 +217,34 @@ fn to_str() {
                 increment: Some(utils::VectorValue {
                     value: 4,
                     radix: utils::RadixType::Hexadecimal
-                })
+                }),
+                inner: None
             })
         }
         .to_string(),
         "auto:stride:10:0x4"
     );
 }
+
+/// test multi-dimensional strides
+#[test]
+fn nested_stride() {
+    let address = mdf::Address::from_str("0x100:stride:4:0x40:stride:8").unwrap();
+    let outer = address.stride.clone().unwrap();
+    assert_eq!(4, outer.count.value);
+    assert_eq!(Some(0x40), outer.increment.map(|increment| increment.value));
+    let inner = outer.inner.unwrap();
+    assert_eq!(8, inner.count.value);
+    assert!(inner.increment.is_none());
+    assert!(inner.inner.is_none());
+
+    assert_eq!("0x100:stride:4:0x40:stride:8", address.to_string());
+    assert_eq!(
+        "auto:stride:2:stride:3:0x8",
+        mdf::Address::from_str("auto:stride:2:stride:3:0x8").unwrap().to_string()
+    );
+
+    if mdf::Address::from_str("0x40:stride:4:stride").is_ok() {
+        panic!("should generate an error")
+    }
+}
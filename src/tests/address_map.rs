@@ -0,0 +1,95 @@
+//! Tests for the interface address map
+
+use super::common::{model, register};
+use crate::address_map::{AddressMap, MapBlock};
+use crate::file_formats::mdf;
+use crate::page::PageType;
+use crate::utils::VectorValue;
+
+/// automatic addresses are placed like the generation does, after the fixed ones
+#[test]
+fn automatic_addresses() {
+    let map = AddressMap::new(&model(vec![register("a", Some(8)), register("b", None), register("c", None)]), 0);
+    assert_eq!(None, map.error);
+    let places = map.items.iter().map(|item| (item.name.as_str(), item.start(), item.auto)).collect::<Vec<_>>();
+    assert_eq!(vec![("a", 8, false), ("b", 12, true), ("c", 16, true)], places);
+    assert!(map.items[1].page == PageType::Register(0, 1, None));
+
+    let map = AddressMap::new(&model(vec![register("a", Some(8)), register("b", Some(0))]), 0);
+    assert_eq!(
+        vec![
+            MapBlock { item: Some(1), element: None, start: 0, size: 4 },
+            MapBlock { item: None, element: None, start: 4, size: 4 },
+            MapBlock { item: Some(0), element: None, start: 8, size: 4 },
+        ],
+        map.blocks(&[])
+    );
+}
+
+/// with a generated variant, the map shows the registers of the variant at the addresses they have in all of them
+#[test]
+fn generated_variant() {
+    let mut pro = register("b", None);
+    pro.variants = vec!["PRO".to_owned()];
+    let mut model = model(vec![register("a", None), pro, register("c", None)]);
+    model.variants = vec![mdf::Variant { name: "PRO".to_owned(), description: None }];
+    model.active_variant = Some("PRO".to_owned());
+    assert_eq!(3, AddressMap::new(&model, 0).items.len());
+
+    model.variants.push(mdf::Variant { name: "BASIC".to_owned(), description: None });
+    model.active_variant = Some("BASIC".to_owned());
+    let map = AddressMap::new(&model, 0);
+    assert_eq!(None, map.error);
+    let places = map.items.iter().map(|item| (item.name.as_str(), item.start())).collect::<Vec<_>>();
+    assert_eq!(vec![("a", 0), ("c", 8)], places);
+    assert!(map.items[1].page == PageType::Register(0, 2, None));
+}
+
+/// arrays are one block when collapsed and one block per element when expanded
+#[test]
+fn arrays() {
+    let mut array = register("a", Some(0));
+    array.address.stride = Some(mdf::AddressStride {
+        count: VectorValue::from(3),
+        increment: Some(VectorValue::from(8)),
+        inner: None,
+    });
+    let map = AddressMap::new(&model(vec![array, register("b", Some(4))]), 0);
+
+    // the other register between the elements isn't a conflict
+    assert!(map.items.iter().all(|item| item.conflicts.is_empty()));
+    assert_eq!(vec![MapBlock { item: Some(0), element: None, start: 0, size: 20 }], map.blocks(&[])[..1]);
+    assert_eq!(5, map.blocks(&[0]).len());
+    assert_eq!(MapBlock { item: Some(0), element: Some(2), start: 16, size: 4 }, map.blocks(&[0])[4]);
+}
+
+/// items sharing addresses are listed on both sides, and the map is still built
+#[test]
+fn conflicts() {
+    let map = AddressMap::new(&model(vec![register("a", Some(0)), register("b", Some(4)), register("c", Some(0))]), 0);
+    assert!(map.error.is_some());
+    assert_eq!(vec!["c".to_owned()], map.items[0].conflicts);
+    assert!(map.items[1].conflicts.is_empty());
+    assert_eq!(vec!["a".to_owned()], map.items[2].conflicts);
+}
+
+/// a reserved range up to the end of the address space doesn't overflow
+#[test]
+fn reserved_to_the_end() {
+    let mut model = model(vec![register("a", Some(0))]);
+    let mut range = mdf::ReservedRange::new();
+    range.name = "rest".to_owned();
+    range.start = VectorValue::from(0x100);
+    range.end = VectorValue::from(u128::MAX);
+    model.interfaces[0].reserved.push(range);
+    let map = AddressMap::new(&model, 0);
+    assert_eq!(u128::MAX, map.items[1].end());
+    let blocks = map.blocks(&[]);
+    assert_eq!(Some(u128::MAX), blocks.last().map(|block| block.last()));
+
+    // the whole address space only loses its last address
+    model.interfaces[0].reserved[0].start = VectorValue::from(0);
+    let map = AddressMap::new(&model, 0);
+    assert_eq!(vec!["rest".to_owned()], map.items[0].conflicts);
+    assert_eq!(u128::MAX, map.items[1].end());
+}
@@ -0,0 +1,50 @@
+//! Tests for the byte and word addressing modes
+
+use super::common::{self, addresses};
+use crate::file_formats::mdf;
+use crate::utils::VectorValue;
+
+/// build a 32 bits interface with a single register, a two words register and an array
+fn interface(addressing: mdf::AddressingMode) -> mdf::Interface {
+    let wide = mdf::Register { width: Some(64), ..common::register("wide", None) };
+    let mut array = common::register("array", None);
+    array.address.stride = Some(mdf::AddressStride {
+        count: VectorValue::from(4),
+        increment: None,
+        inner: None,
+    });
+    let mut interface = common::interface(vec![common::register("single", None), wide, array]);
+    interface.addressing = Some(addressing);
+    interface
+}
+
+/// the addresses are spaced by the bus width in bytes, or by 1 with word addressing
+#[test]
+fn allocation() {
+    let mut bytes = interface(mdf::AddressingMode::Byte);
+    bytes.assign_addresses().unwrap();
+    assert_eq!(vec![0, 4, 0xc], addresses(&bytes));
+    assert_eq!(Some(4), bytes.get_word_increment());
+    assert_eq!(Some(5), bytes.get_address_width());
+
+    let mut words = interface(mdf::AddressingMode::Word);
+    words.assign_addresses().unwrap();
+    assert_eq!(vec![0, 1, 3], addresses(&words));
+    assert_eq!(Some(1), words.get_word_increment());
+    assert_eq!(vec![0, 1], words.registers[1].offsets(32, 1));
+    assert_eq!(Some(6), words.registers[2].high_address(32, 1));
+    assert_eq!(Some(3), words.get_address_width());
+}
+
+/// the memories are aligned on their size in addresses
+#[test]
+fn memories() {
+    let mut interface = interface(mdf::AddressingMode::Word);
+    let mut memory = mdf::Memory::new();
+    memory.name = "ram".to_owned();
+    memory.depth = 16;
+    interface.memories.push(memory);
+    interface.assign_addresses().unwrap();
+    assert_eq!(Some(16), interface.memories[0].address.map(|address| address.value));
+    assert_eq!(Some(5), interface.get_address_width());
+}
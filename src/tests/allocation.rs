@@ -0,0 +1,130 @@
+//! Tests for the address allocation options
+
+use super::common::{self, addresses};
+use crate::file_formats::mdf;
+use crate::utils::VectorValue;
+
+/// build a 32 bits interface with registers at the given addresses, None being automatic
+fn interface(addresses: &[Option<u128>]) -> mdf::Interface {
+    common::interface(
+        addresses
+            .iter()
+            .enumerate()
+            .map(|(n, address)| common::register(&format!("r{}", n), *address))
+            .collect(),
+    )
+}
+
+/// the automatic addresses avoid the reserved ranges
+#[test]
+fn reserved_ranges() {
+    let mut interface = interface(&[None, None, None]);
+    interface.reserved.push(mdf::ReservedRange {
+        name: "future".to_owned(),
+        start: VectorValue::from(4),
+        end: VectorValue::from(0xb),
+    });
+    interface.assign_addresses().unwrap();
+    assert_eq!(vec![0, 0xc, 0x10], addresses(&interface));
+
+    interface.reserved[0].end = VectorValue::from(0);
+    assert!(interface.deassign_addresses().and_then(|_| interface.assign_addresses()).is_err());
+}
+
+/// large reserved ranges are skipped in one step, and a full address space is an error instead of a hang. A
+/// word partly in a reserved range is reserved
+#[test]
+fn large_reserved_ranges() {
+    let mut interface = interface(&[None, None]);
+    interface.reserved.push(mdf::ReservedRange {
+        name: "low".to_owned(),
+        start: VectorValue::from(0),
+        end: VectorValue::from(0xffff_fffe),
+    });
+    interface.assign_addresses().unwrap();
+    assert_eq!(vec![0x1_0000_0000, 0x1_0000_0004], addresses(&interface));
+
+    interface.deassign_addresses().unwrap();
+    interface.reserved[0].end = VectorValue::from(u128::MAX);
+    assert!(interface.assign_addresses().is_err());
+
+    interface.reserved[0].end = VectorValue::from(0xffff_ffff);
+    interface.registers[0].address.value = Some(VectorValue::from(0x1000));
+    assert!(interface.assign_addresses().is_err());
+}
+
+/// with the gaps kept, the automatic addresses go after the last fixed one
+#[test]
+fn keep_gaps() {
+    let mut interface = interface(&[None, Some(0x10), None]);
+    interface.assign_addresses().unwrap();
+    assert_eq!(vec![0, 0x10, 0x14], addresses(&interface));
+
+    interface.deassign_addresses().unwrap();
+    interface.registers[1].address.value = Some(VectorValue::from(0x10));
+    interface.keep_gaps = Some(true);
+    interface.assign_addresses().unwrap();
+    assert_eq!(vec![0x14, 0x10, 0x18], addresses(&interface));
+}
+
+/// arrays can be aligned on their size
+#[test]
+fn align_arrays() {
+    let mut interface = interface(&[None, None]);
+    interface.registers[1].address.stride = Some(mdf::AddressStride {
+        count: VectorValue::from(3),
+        increment: None,
+        inner: None,
+    });
+    interface.align_arrays = Some(true);
+    interface.assign_addresses().unwrap();
+    assert_eq!(vec![0, 0x10], addresses(&interface));
+}
+
+/// groups are placed after the other registers, or where their first register is declared
+#[test]
+fn allocation_order() {
+    let mut interface = interface(&[None, None, None]);
+    interface.registers[1].group = Some("block".to_owned());
+    let mut group = mdf::RegisterGroup::new();
+    group.name = "block".to_owned();
+    interface.groups.push(group);
+
+    let mut by_group = interface.clone();
+    by_group.assign_addresses().unwrap();
+    assert_eq!(8, by_group.groups[0].base.unwrap().value);
+    assert_eq!(vec![0, 0, 4], addresses(&by_group));
+
+    interface.allocation_order = Some(mdf::AllocationOrder::Declaration);
+    interface.assign_addresses().unwrap();
+    assert_eq!(4, interface.groups[0].base.unwrap().value);
+    assert_eq!(vec![0, 0, 8], addresses(&interface));
+}
+
+/// the pinned registers, groups and memories keep their address when the addresses are unassigned
+#[test]
+fn pinned() {
+    let mut interface = interface(&[None, None]);
+    for n in 0..2 {
+        let mut group = mdf::RegisterGroup::new();
+        group.name = format!("g{}", n);
+        interface.groups.push(group);
+        let mut memory = mdf::Memory::new();
+        memory.name = format!("m{}", n);
+        interface.memories.push(memory);
+    }
+    interface.assign_addresses().unwrap();
+    let group_base = interface.groups[1].base;
+    let memory_address = interface.memories[1].address;
+    interface.registers[1].pinned = Some(true);
+    interface.groups[1].pinned = Some(true);
+    interface.memories[1].pinned = Some(true);
+
+    interface.deassign_addresses().unwrap();
+    assert!(interface.registers[0].address.value.is_none());
+    assert_eq!(Some(4), interface.registers[1].address.value.map(|address| address.value));
+    assert!(interface.groups[0].base.is_none() && group_base.is_some());
+    assert!(interface.groups[1].base == group_base);
+    assert!(interface.memories[0].address.is_none() && memory_address.is_some());
+    assert!(interface.memories[1].address == memory_address);
+}
@@ -0,0 +1,66 @@
+//! Tests for the byte lanes of the registers written with byte enables
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf;
+use crate::utils::SignalType;
+
+/// build a register in the core with a write enable
+fn core_register(name: &str, width: u32) -> mdf::Register {
+    let mut register = register(name, None);
+    register.width = Some(width);
+    register.location = Some(mdf::LocationType::Core);
+    register.core_signal_properties.use_write_enable = Some(true);
+    register
+}
+
+/// the byte enables of a register cover its bytes, and each word of a split register gives its byte lanes
+#[test]
+fn register_lanes() {
+    let mut model = model(vec![core_register("ctrl", 16), core_register("counter", 48)]);
+    model.interfaces[0].use_byte_enables = Some(true);
+    let generated = gen_model(&model);
+    let registers = &generated.interfaces[0].registers;
+
+    let ctrl = &registers[0].fields[0];
+    assert!(ctrl.core_byte_enable);
+    assert_eq!((0, 2), (ctrl.byte_lane_first, ctrl.byte_lane_count));
+    assert!(registers[0].words.is_empty());
+
+    let counter = &registers[1];
+    assert_eq!((0, 6), (counter.fields[0].byte_lane_first, counter.fields[0].byte_lane_count));
+    let byte_enable = counter.fields[0].pif2core.iter().find(|signal| signal.function == "byte_enable").unwrap();
+    assert_eq!("std_logic_vector(5 downto 0)", byte_enable.signal_type);
+    assert_eq!(
+        vec![(0, 4), (4, 2)],
+        counter.words.iter().map(|word| (word.byte_first, word.byte_lane_count)).collect::<Vec<_>>()
+    );
+}
+
+/// a field only covers the bytes holding its bits
+#[test]
+fn field_lanes() {
+    let mut status = core_register("status", 32);
+    status.signal = None;
+    for (name, msb, lsb) in [("mode", 3, 0), ("level", 19, 8)] {
+        let mut field = mdf::Field::new();
+        field.name = name.to_owned();
+        field.position = mdf::FieldPosition::Field(msb, lsb);
+        field.signal = SignalType::StdLogicVector;
+        field.location = Some(mdf::LocationType::Core);
+        field.core_signal_properties.use_write_enable = Some(true);
+        status.fields.push(field);
+    }
+    let mut model = model(vec![status]);
+    model.interfaces[0].use_byte_enables = Some(true);
+    let generated = gen_model(&model);
+    let fields = &generated.interfaces[0].registers[0].fields;
+    assert_eq!(
+        vec![(0, 1), (1, 2)],
+        fields.iter().map(|field| (field.byte_lane_first, field.byte_lane_count)).collect::<Vec<_>>()
+    );
+    assert!(fields.iter().all(|field| field.core_byte_enable));
+
+    // without byte enables on the interface, the core gets none
+    model.interfaces[0].use_byte_enables = None;
+    assert!(gen_model(&model).interfaces[0].registers[0].fields.iter().all(|field| !field.core_byte_enable));
+}
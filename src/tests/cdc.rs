@@ -0,0 +1,171 @@
+//! Tests for the synchronizer selection between clock domains
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf;
+use crate::generate::genmodel::{cdc_type, GenInterface};
+
+/// build a register with the given access and location
+fn located_register(name: &str, access: mdf::AccessType, location: mdf::LocationType) -> mdf::Register {
+    let mut register = register(name, None);
+    register.access = Some(access);
+    register.location = Some(location);
+    register
+}
+
+/// build the generation model of an interface with a separate bus clock
+fn separate_clock(registers: Vec<mdf::Register>) -> GenInterface {
+    let mut model = model(registers);
+    model.interfaces[0].separate_clock = Some(true);
+    model.interfaces[0].use_byte_enables = Some(true);
+    gen_model(&model).interfaces.remove(0)
+}
+
+/// function, synchronizer and strobe of all the record signals of a register, sorted by function
+fn signals(interface: &GenInterface, register: &str) -> Vec<(String, String, String)> {
+    let register = interface.registers.iter().find(|reg| reg.name == register).unwrap();
+    let mut signals: Vec<(String, String, String)> = register
+        .core2pif
+        .iter()
+        .chain(register.fields.iter().flat_map(|field| field.core2pif.iter().chain(&field.pif2core)))
+        .map(|signal| (signal.function.clone(), signal.cdc.clone(), signal.cdc_strobe.clone()))
+        .collect();
+    signals.sort();
+    signals
+}
+
+/// expected signals, in the same order as signals()
+fn expected(signals: &[(&str, &str, &str)]) -> Vec<(String, String, String)> {
+    signals.iter().map(|(function, cdc, strobe)| (function.to_string(), cdc.to_string(), strobe.to_string())).collect()
+}
+
+/// strobes, single bit and multi-bit signals
+#[test]
+fn synchronizer_types() {
+    assert_eq!("pulse", cdc_type("write_enable", "boolean"));
+    assert_eq!("pulse", cdc_type("fifo_push", "boolean"));
+    assert_eq!("level", cdc_type("fifo_full", "boolean"));
+    assert_eq!("level", cdc_type("data", "std_logic"));
+    assert_eq!("handshake", cdc_type("data", "std_logic_vector(7 downto 0)"));
+    assert_eq!("pulse", cdc_type("hw_set", "unsigned(3 downto 0)"));
+    assert_eq!("pulse", cdc_type("hw_clear", "std_logic"));
+    assert_eq!("pulse", cdc_type("shadow_commit", "boolean"));
+}
+
+/// the write data and byte enables cross with the write enable, the read data without a strobe alone
+#[test]
+fn core_write() {
+    let mut ctrl = located_register("ctrl", mdf::AccessType::RW, mdf::LocationType::Core);
+    ctrl.core_signal_properties.use_read_enable = Some(true);
+    ctrl.core_signal_properties.use_write_enable = Some(true);
+    let interface = separate_clock(vec![ctrl]);
+    assert_eq!(
+        expected(&[
+            ("byte_enable", "handshake", "write_enable"),
+            ("data", "handshake", ""),
+            ("data", "handshake", "write_enable"),
+            ("read_enable", "pulse", ""),
+            ("write_enable", "handshake", "write_enable"),
+        ]),
+        signals(&interface, "ctrl")
+    );
+}
+
+/// the read data crosses with the read valid, the read request alone
+#[test]
+fn read_handshake() {
+    let mut status = located_register("status", mdf::AccessType::RO, mdf::LocationType::Core);
+    status.core_signal_properties.use_read_handshake = Some(true);
+    let interface = separate_clock(vec![status]);
+    assert_eq!(
+        expected(&[
+            ("data", "handshake", "read_valid"),
+            ("read_request", "pulse", ""),
+            ("read_valid", "handshake", "read_valid"),
+        ]),
+        signals(&interface, "status")
+    );
+}
+
+/// the set and clear masks are strobes, the hardware written value crosses with its valid strobe
+#[test]
+fn hardware_access() {
+    let mut flags = located_register("flags", mdf::AccessType::RW, mdf::LocationType::Pif);
+    flags.hardware_access.use_set = Some(true);
+    flags.hardware_access.use_clear = Some(true);
+    flags.hardware_access.use_write = Some(true);
+    let interface = separate_clock(vec![flags]);
+    assert_eq!(
+        expected(&[
+            ("data", "handshake", ""),
+            ("data", "handshake", "hw_valid"),
+            ("hw_clear", "pulse", ""),
+            ("hw_set", "pulse", ""),
+            ("hw_valid", "handshake", "hw_valid"),
+        ]),
+        signals(&interface, "flags")
+    );
+}
+
+/// the commit strobe of a shadowed register is a pulse
+#[test]
+fn shadow_commit() {
+    let mut apply = located_register("apply", mdf::AccessType::RW, mdf::LocationType::Pif);
+    apply.shadow = Some(mdf::ShadowProperties::default());
+    let interface = separate_clock(vec![apply]);
+    assert_eq!(expected(&[("data", "handshake", ""), ("shadow_commit", "pulse", "")]), signals(&interface, "apply"));
+}
+
+/// the pushed data crosses with the push, the flags and the level alone
+#[test]
+fn fifo() {
+    let fifo = mdf::FifoProperties {
+        blocked_access: Some(mdf::FifoBlockedAccess::Flag),
+        use_level: Some(true),
+        level_width: Some(4),
+    };
+    let mut tx = located_register("tx", mdf::AccessType::WO, mdf::LocationType::Core);
+    tx.fifo = Some(fifo.clone());
+    let mut rx = located_register("rx", mdf::AccessType::RO, mdf::LocationType::Core);
+    rx.fifo = Some(fifo);
+    let interface = separate_clock(vec![tx, rx]);
+    assert_eq!(
+        expected(&[
+            ("data", "handshake", "fifo_push"),
+            ("fifo_full", "level", ""),
+            ("fifo_level", "handshake", ""),
+            ("fifo_overflow", "pulse", ""),
+            ("fifo_push", "handshake", "fifo_push"),
+        ]),
+        signals(&interface, "tx")
+    );
+    assert_eq!(
+        expected(&[
+            ("data", "handshake", ""),
+            ("fifo_empty", "level", ""),
+            ("fifo_level", "handshake", ""),
+            ("fifo_pop", "pulse", ""),
+            ("fifo_underflow", "pulse", ""),
+        ]),
+        signals(&interface, "rx")
+    );
+}
+
+/// the signals crossing with a strobe share its synchronizer, the others get their own
+#[test]
+fn synchronizer_list() {
+    let mut ctrl = located_register("ctrl", mdf::AccessType::WO, mdf::LocationType::Core);
+    ctrl.core_signal_properties.use_write_enable = Some(true);
+    let interface = separate_clock(vec![ctrl]);
+    let synchronizers: Vec<(bool, usize, &str)> = interface
+        .synchronizers
+        .iter()
+        .map(|synchronizer| (synchronizer.is_core2pif, synchronizer.signals.len(), synchronizer.cdc.as_str()))
+        .collect();
+    assert_eq!(vec![(false, 3, "handshake")], synchronizers);
+    let synchronizer = &interface.synchronizers[0];
+    assert_eq!(synchronizer.strobe, synchronizer.signals[0]);
+
+    let mut model = model(vec![register("ctrl", None)]);
+    model.interfaces[0].separate_clock = None;
+    assert!(gen_model(&model).interfaces[0].synchronizers.is_empty());
+}
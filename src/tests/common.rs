@@ -0,0 +1,53 @@
+//! Model builders shared by the tests
+
+use crate::file_formats::mdf;
+use crate::generate::genmodel::GenModel;
+use crate::generate::user_strings;
+use crate::settings::Settings;
+use crate::utils::VectorValue;
+use tera::Tera;
+
+/// build a 32 bits interface holding the given registers
+pub fn interface(registers: Vec<mdf::Register>) -> mdf::Interface {
+    let mut interface = mdf::Interface::new();
+    interface.name = "regs".to_owned();
+    interface.data_width = Some(32);
+    interface.registers = registers;
+    interface
+}
+
+/// build a model with one 32 bits interface holding the given registers
+pub fn model(registers: Vec<mdf::Register>) -> mdf::Mdf {
+    mdf::Mdf { name: "test".to_owned(), interfaces: vec![interface(registers)], ..Default::default() }
+}
+
+/// build a register, at a fixed address or an automatic one
+pub fn register(name: &str, address: Option<u128>) -> mdf::Register {
+    let mut register = mdf::Register::new();
+    register.name = name.to_owned();
+    register.address.value = address.map(VectorValue::from);
+    register
+}
+
+/// addresses of the registers, once assigned
+pub fn addresses(interface: &mdf::Interface) -> Vec<u128> {
+    interface
+        .registers
+        .iter()
+        .map(|register| register.address.value.unwrap().value)
+        .collect()
+}
+
+/// build the generation model with the default settings and names
+pub fn gen_model(model: &mdf::Mdf) -> GenModel {
+    gen_variant(model, None).unwrap()
+}
+
+/// build the generation model of a variant with the default settings and names
+pub fn gen_variant(model: &mdf::Mdf, variant: Option<&str>) -> Result<GenModel, String> {
+    let mut settings = Settings::default();
+    user_strings::load_defaults(&mut settings.user_templates);
+    let mut templates = Tera::default();
+    templates.add_raw_templates(settings.user_templates.clone()).unwrap();
+    GenModel::from_model(model, &settings, &templates, variant).map_err(|e| e.to_string())
+}
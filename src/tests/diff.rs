@@ -0,0 +1,194 @@
+//! Tests for the comparison of two models
+
+use super::common::{self, model};
+use crate::diff::{self, ChangeKind, ModelDiff, OutputFormat};
+use crate::file_formats::mdf;
+use crate::utils::{SignalType, VectorValue};
+
+/// build a register with the given fields, at an automatic address
+fn bitfield(name: &str, fields: &[(&str, u32, u32)]) -> mdf::Register {
+    let mut register = common::register(name, None);
+    register.fields = fields
+        .iter()
+        .map(|(name, msb, lsb)| {
+            let mut field = mdf::Field::new();
+            field.name = (*name).to_owned();
+            field.position = mdf::FieldPosition::Field(*msb, *lsb);
+            field
+        })
+        .collect();
+    register
+}
+
+/// kind and subject of each change
+fn summary(diff: &ModelDiff) -> Vec<(ChangeKind, String)> {
+    diff.changes.iter().map(|change| (change.kind, change.subject())).collect()
+}
+
+/// identical models have no change
+#[test]
+fn no_change() {
+    let old = model(vec![bitfield("ctrl", &[("enable", 0, 0)])]);
+    let diff = ModelDiff::new(&old, &old.clone());
+    assert!(diff.is_empty());
+    assert!(diff.to_text().contains("No change."));
+}
+
+/// an inserted register moves the automatic addresses of the following ones
+#[test]
+fn added_and_moved() {
+    let old = model(vec![bitfield("ctrl", &[]), bitfield("status", &[])]);
+    let new = model(vec![bitfield("ctrl", &[]), bitfield("irq", &[]), bitfield("status", &[])]);
+    let diff = ModelDiff::new(&old, &new);
+    assert_eq!(
+        vec![(ChangeKind::Added, "register irq".to_owned()), (ChangeKind::Moved, "register status".to_owned())],
+        summary(&diff)
+    );
+    assert_eq!(Some("0x4".to_owned()), diff.changes[0].new);
+    assert_eq!((Some("0x4".to_owned()), Some("0x8".to_owned())), (diff.changes[1].old.clone(), diff.changes[1].new.clone()));
+}
+
+/// renames are found from the fixed address or the fields of the registers, and from the bits of the fields
+#[test]
+fn renamed() {
+    let mut old_register = bitfield("ctrl", &[("enable", 0, 0), ("mode", 3, 1)]);
+    old_register.address.value = Some(VectorValue::from(0));
+    let mut new_register = bitfield("control", &[("enable", 0, 0), ("speed", 3, 1)]);
+    new_register.address.value = Some(VectorValue::from(0));
+    let old = model(vec![old_register, bitfield("status", &[])]);
+    let new = model(vec![new_register]);
+    let diff = ModelDiff::new(&old, &new);
+    assert_eq!(
+        vec![
+            (ChangeKind::Removed, "register status".to_owned()),
+            (ChangeKind::Renamed, "register control".to_owned()),
+            (ChangeKind::Renamed, "field control.speed".to_owned()),
+        ],
+        summary(&diff)
+    );
+    assert_eq!(Some("ctrl".to_owned()), diff.changes[1].old);
+    assert_eq!("field control.speed renamed from mode", diff.changes[2].description());
+
+    // the automatic addresses shift when a register is removed, they aren't enough to find a rename
+    let old = model(vec![bitfield("ctrl", &[]), bitfield("status", &[("busy", 0, 0)])]);
+    let new = model(vec![bitfield("state", &[("busy", 0, 0)])]);
+    assert_eq!(
+        vec![(ChangeKind::Removed, "register ctrl".to_owned()), (ChangeKind::Renamed, "register state".to_owned())],
+        summary(&ModelDiff::new(&old, &new))[..2]
+    );
+}
+
+/// width, access, reset and description changes of registers and fields
+#[test]
+fn properties() {
+    let mut old_register = bitfield("ctrl", &[("enable", 0, 0), ("mode", 3, 1)]);
+    old_register.summary = Some(vec!["control".to_owned()]);
+    let mut new_register = bitfield("ctrl", &[("enable", 0, 0), ("mode", 4, 1)]);
+    new_register.summary = Some(vec!["control register".to_owned()]);
+    new_register.fields[0].access = mdf::AccessType::RO;
+    new_register.fields[0].reset = VectorValue::from(1);
+
+    let diff = ModelDiff::new(&model(vec![old_register]), &model(vec![new_register]));
+    assert_eq!(
+        vec![ChangeKind::Description, ChangeKind::Access, ChangeKind::Reset, ChangeKind::Width],
+        diff.changes.iter().map(|change| change.kind).collect::<Vec<_>>()
+    );
+    assert_eq!(Some("field ctrl.mode".to_owned()), diff.changes.get(3).map(|change| change.subject()));
+    assert_eq!(Some("bits 4:1".to_owned()), diff.changes[3].new);
+
+    // the width of a register with a signal is compared
+    let mut old_register = bitfield("data", &[]);
+    old_register.signal = Some(SignalType::StdLogicVector);
+    old_register.width = Some(16);
+    let mut new_register = old_register.clone();
+    new_register.width = Some(32);
+    let diff = ModelDiff::new(&model(vec![old_register]), &model(vec![new_register]));
+    assert_eq!(vec![(ChangeKind::Width, "register data".to_owned())], summary(&diff));
+    assert_eq!(Some("16 bits".to_owned()), diff.changes[0].old);
+}
+
+/// an array keeping its base address with another number of elements isn't moved, the following registers are
+#[test]
+fn element_count() {
+    let array = |count| {
+        let mut register = bitfield("channel", &[]);
+        register.address.value = Some(VectorValue::from(0x10));
+        register.address.stride =
+            Some(mdf::AddressStride { count: VectorValue::from(count), increment: None, inner: None });
+        register
+    };
+    let old = model(vec![array(2), bitfield("status", &[])]);
+    let diff = ModelDiff::new(&old, &model(vec![array(4), bitfield("status", &[])]));
+    assert_eq!(
+        vec![(ChangeKind::Count, "register channel".to_owned()), (ChangeKind::Moved, "register status".to_owned())],
+        summary(&diff)
+    );
+    assert_eq!("register channel changed from 2 elements to 4 elements", diff.changes[0].description());
+}
+
+/// only the generated variant of each model is compared
+#[test]
+fn generated_variant() {
+    let mut pro = bitfield("gain", &[]);
+    pro.variants = vec!["PRO".to_owned()];
+    let mut old = model(vec![bitfield("ctrl", &[]), pro, bitfield("status", &[])]);
+    old.variants =
+        ["BASIC", "PRO"].iter().map(|name| mdf::Variant { name: name.to_string(), description: None }).collect();
+    let mut new = old.clone();
+    new.active_variant = Some("BASIC".to_owned());
+    let diff = ModelDiff::new(&old, &new);
+    assert_eq!(vec![(ChangeKind::Removed, "register gain".to_owned())], summary(&diff));
+    assert!(diff.warnings.is_empty());
+}
+
+/// the three report formats
+#[test]
+fn formats() {
+    let old = model(vec![bitfield("ctrl", &[])]);
+    let new = model(vec![bitfield("ctrl", &[]), bitfield("a<b", &[])]);
+    let diff = ModelDiff::new(&old, &new);
+
+    assert!(diff.report(OutputFormat::Text).contains("Interface regs\n  - register a<b added at 0x4\n"));
+    assert!(diff.report(OutputFormat::Html).contains("<td>register a&lt;b</td>"));
+    let json: serde_json::Value = serde_json::from_str(&diff.report(OutputFormat::Json)).unwrap();
+    assert_eq!("added", json["changes"][0]["kind"]);
+    assert_eq!("a<b", json["changes"][0]["register"]);
+}
+
+/// command line arguments
+#[test]
+fn arguments() {
+    let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        Ok((OutputFormat::Text, "a.regwiz".to_owned(), "b.regwiz".to_owned())),
+        diff::parse_arguments(&args(&["a.regwiz", "b.regwiz"]))
+    );
+    assert_eq!(
+        Ok((OutputFormat::Json, "a.regwiz".to_owned(), "b.regwiz".to_owned())),
+        diff::parse_arguments(&args(&["--format", "json", "a.regwiz", "b.regwiz"]))
+    );
+    assert!(diff::parse_arguments(&args(&["--format=pdf", "a.regwiz", "b.regwiz"])).is_err());
+    assert!(diff::parse_arguments(&args(&["a.regwiz"])).is_err());
+}
+
+/// the command line loads the files like the application does
+#[test]
+fn command_line() {
+    let folder = std::env::temp_dir();
+    let old_file = folder.join("hdl_register_wizard_diff_old.regwiz");
+    let new_file = folder.join("hdl_register_wizard_diff_new.regwiz");
+    std::fs::write(&old_file, serde_json::to_string(&model(vec![bitfield("ctrl", &[])])).unwrap()).unwrap();
+    std::fs::write(&new_file, serde_json::to_string(&model(vec![bitfield("ctrl", &[]), bitfield("status", &[])])).unwrap()).unwrap();
+    let file_names = [old_file.to_str().unwrap().to_owned(), new_file.to_str().unwrap().to_owned()];
+
+    let (report, changed) = diff::command_line(&file_names).unwrap();
+    assert!(changed);
+    assert!(report.contains("register status added at 0x4"));
+    let (_, changed) = diff::command_line(&[file_names[0].clone(), file_names[0].clone()]).unwrap();
+    assert!(!changed);
+
+    std::fs::write(&new_file, "{ \"name\": \"regs\" }").unwrap();
+    assert!(diff::command_line(&file_names).unwrap_err().starts_with("Error while loading"));
+    let _ = std::fs::remove_file(old_file);
+    let _ = std::fs::remove_file(new_file);
+}
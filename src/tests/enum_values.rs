@@ -0,0 +1,79 @@
+//! Tests for the named values of the registers and fields
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf;
+use crate::generate::genmodel::GenEnumValue;
+use crate::utils::{SignalType, VectorValue};
+
+/// build a named value
+fn enum_value(name: &str, value: u128) -> mdf::EnumValue {
+    mdf::EnumValue { name: name.to_owned(), value: VectorValue::from(value), description: None }
+}
+
+/// constant names of the named values
+fn const_names(enum_values: &[GenEnumValue]) -> Vec<&str> {
+    enum_values.iter().map(|enum_value| enum_value.const_name.as_str()).collect()
+}
+
+/// a register gets a type and a constant per named value
+#[test]
+fn register_values() {
+    let mut mode = register("mode", None);
+    mode.width = Some(4);
+    mode.signal = Some(SignalType::StdLogicVector);
+    mode.enum_values = vec![enum_value("idle", 0), enum_value("fast run", 10)];
+    let generated = gen_model(&model(vec![mode]));
+    let field = &generated.interfaces[0].registers[0].fields[0];
+    assert!(field.has_enum_values);
+    assert!(!field.enum_type_name.is_empty());
+    assert_eq!("test_mode_values_t", field.enum_type_name);
+    assert_eq!("Mode", field.rust_enum_name);
+    assert_eq!(vec!["c_test_mode_idle", "c_test_mode_fast_run"], const_names(&field.enum_values));
+    let fast_run = &field.enum_values[1];
+    assert_eq!(
+        ("fast_run", "FastRun", "C_TEST_MODE_FAST_RUN"),
+        (fast_run.token_name.as_str(), fast_run.rust_name.as_str(), fast_run.c_name.as_str())
+    );
+    assert_eq!(("a", "4x\"a\""), (fast_run.value_hex.as_str(), fast_run.literal.as_str()));
+}
+
+/// a field gets a type and a constant per named value, named after the register and the field
+#[test]
+fn field_values() {
+    let mut ctrl = register("ctrl", None);
+    ctrl.signal = None;
+    ctrl.fields = vec![mdf::Field {
+        name: "state".to_owned(),
+        position: mdf::FieldPosition::Field(5, 4),
+        signal: SignalType::Unsigned,
+        enum_values: vec![enum_value("off", 0), enum_value("on", 3)],
+        ..Default::default()
+    }];
+    let generated = gen_model(&model(vec![ctrl]));
+    let field = &generated.interfaces[0].registers[0].fields[0];
+    assert!(field.has_enum_values);
+    assert_eq!("test_ctrl_state_values_t", field.enum_type_name);
+    assert_eq!("CtrlState", field.rust_enum_name);
+    // on is a VHDL keyword
+    assert_eq!(vec!["c_test_ctrl_state_off", "c_test_ctrl_state_on_2"], const_names(&field.enum_values));
+    assert_eq!("3", field.enum_values[1].value_hex);
+}
+
+/// colliding value names are numbered within the enumeration, and colliding constants within the model
+#[test]
+fn name_collisions() {
+    let mut first = register("a_b", None);
+    first.enum_values = vec![enum_value("c", 0), enum_value("Run", 1), enum_value("run", 2)];
+    let mut second = register("a", None);
+    second.enum_values = vec![enum_value("b_c", 0)];
+    let generated = gen_model(&model(vec![first, second]));
+    let registers = &generated.interfaces[0].registers;
+    assert_eq!(
+        vec!["c_test_a_b_c", "c_test_a_b_run", "c_test_a_b_run_2"],
+        const_names(&registers[0].fields[0].enum_values)
+    );
+    assert_eq!(vec!["c_test_a_b_c_2"], const_names(&registers[1].fields[0].enum_values));
+    let rust_names: Vec<&str> =
+        registers[0].fields[0].enum_values.iter().map(|enum_value| enum_value.rust_name.as_str()).collect();
+    assert_eq!(vec!["C", "Run", "Run2"], rust_names);
+}
@@ -0,0 +1,93 @@
+//! Tests for the expressions and the project parameters
+
+use crate::expression::evaluate;
+use crate::file_formats::mdf;
+use std::collections::HashMap;
+
+/// operators, priorities, literals and the clog2 function
+#[test]
+fn arithmetic() {
+    let parameters = HashMap::from([("N_CHANNELS".to_owned(), 6)]);
+    assert_eq!(Ok(14), evaluate("2 + 3 * 4", &parameters));
+    assert_eq!(Ok(20), evaluate("(2 + 3) * 4", &parameters));
+    assert_eq!(Ok(0x100 + 24), evaluate("0x100 + N_CHANNELS * 4", &parameters));
+    assert_eq!(Ok(1), evaluate("N_CHANNELS % 5 - 0b0", &parameters));
+    assert_eq!(Ok(3), evaluate("clog2(N_CHANNELS)", &parameters));
+    assert_eq!(Ok(0), evaluate("clog2(1)", &parameters));
+    assert!(evaluate("N_CHANNELS / 0", &parameters).is_err());
+    assert!(evaluate("2 - 3", &parameters).is_err());
+    assert!(evaluate("N_CHANNEL", &parameters).is_err());
+    assert!(evaluate("(2 + 3", &parameters).is_err());
+    assert_eq!(Err("expression '2 3': unexpected number 3".to_owned()), evaluate("2 3", &parameters));
+    assert_eq!(Err("expression '2 + * 3': unexpected '*'".to_owned()), evaluate("2 + * 3", &parameters));
+}
+
+/// parameters using each other, and the model values replaced by the expression results
+#[test]
+fn model_expressions() {
+    let mut model = mdf::Mdf::default();
+    for (name, value) in [("N_CHANNELS", "4"), ("COUNTER_WIDTH", "clog2(N_CHANNELS) + 8")] {
+        model.parameters.push(mdf::Parameter {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            ..Default::default()
+        });
+    }
+    let mut register = mdf::Register::new();
+    register.width_expr = Some("COUNTER_WIDTH".to_owned());
+    register.count_expr = Some("N_CHANNELS".to_owned());
+    register.address_expr = Some("0x40".to_owned());
+    let mut bitfield = mdf::Register::new();
+    let mut field = mdf::Field::new();
+    field.lsb_expr = Some("N_CHANNELS".to_owned());
+    field.width_expr = Some("COUNTER_WIDTH".to_owned());
+    bitfield.fields.push(field);
+    let mut interface = mdf::Interface::new();
+    interface.registers.push(register);
+    interface.registers.push(bitfield);
+    model.interfaces.push(interface);
+
+    model.evaluate_expressions().unwrap();
+    let register = &model.interfaces[0].registers[0];
+    assert_eq!(Some(10), register.width);
+    assert_eq!(0x40, register.address.value.unwrap().value);
+    assert_eq!(4, register.address.stride.as_ref().unwrap().count.value);
+    assert!(model.interfaces[0].registers[1].fields[0].position == mdf::FieldPosition::Field(13, 4));
+
+    // a field can't go beyond the last bit number
+    let field = &mut model.interfaces[0].registers[1].fields[0];
+    field.lsb_expr = Some("0xffffffff".to_owned());
+    field.width_expr = Some("2".to_owned());
+    assert!(model.clone().evaluate_expressions().unwrap_err().ends_with("field position out of range"));
+    model.interfaces[0].registers[1].fields[0].width_expr = Some("1".to_owned());
+    assert!(model.clone().evaluate_expressions().is_err());
+
+    // a parameter can only use the ones defined before it
+    model.parameters.swap(0, 1);
+    assert!(model.evaluate_expressions().is_err());
+}
+
+/// the addresses assigned from the interface page use the expression results, and keep the expressions
+#[test]
+fn assign_addresses() {
+    let mut model = mdf::Mdf::default();
+    model.parameters.push(mdf::Parameter { name: "N_CHANNELS".to_owned(), value: "4".to_owned(), ..Default::default() });
+    let mut interface = mdf::Interface::new();
+    interface.data_width = Some(32);
+    for name in ["channel", "status"] {
+        let mut register = mdf::Register::new();
+        register.name = name.to_owned();
+        interface.registers.push(register);
+    }
+    interface.registers[0].count_expr = Some("N_CHANNELS".to_owned());
+    model.interfaces.push(interface);
+
+    model.assign_interface_addresses(0).unwrap();
+    let registers = &model.interfaces[0].registers;
+    assert_eq!(
+        vec![Some(0), Some(0x10)],
+        registers.iter().map(|register| register.address.value.map(|address| address.value)).collect::<Vec<_>>()
+    );
+    assert_eq!(Some("N_CHANNELS".to_owned()), registers[0].count_expr);
+    assert!(model.assign_interface_addresses(1).is_err());
+}
@@ -0,0 +1,64 @@
+//! Tests for the field arrays
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf;
+use crate::utils::{SignalType, VectorValue};
+
+/// build a field at the given position, repeated count times
+fn field(position: mdf::FieldPosition, count: Option<u32>, stride: Option<u32>) -> mdf::Field {
+    mdf::Field {
+        position,
+        array: count.map(|count| mdf::FieldArray { count, stride }),
+        ..Default::default()
+    }
+}
+
+/// test the bits used by an array
+#[test]
+fn span() {
+    assert_eq!((0, 0), field(mdf::FieldPosition::Single(0), None, None).span());
+    assert_eq!((31, 0), field(mdf::FieldPosition::Field(3, 0), Some(8), None).span());
+    assert_eq!((25, 4), field(mdf::FieldPosition::Field(5, 4), Some(3), Some(10)).span());
+}
+
+/// test that assign_fields moves an array as a unit
+#[test]
+fn assign_fields() {
+    let mut register = mdf::Register::new();
+    register.signal = None;
+    register.fields = vec![
+        field(mdf::FieldPosition::Single(0), None, None),
+        field(mdf::FieldPosition::Field(3, 0), Some(4), None),
+        field(mdf::FieldPosition::Single(0), None, None),
+    ];
+    register.assign_fields().unwrap();
+
+    assert!(register.fields[1].position == mdf::FieldPosition::Field(4, 1));
+    assert!(register.fields[2].position == mdf::FieldPosition::Single(17));
+    assert_eq!(Some(18), register.get_data_width());
+
+    // stride smaller than the element
+    register.fields = vec![field(mdf::FieldPosition::Field(3, 0), Some(4), Some(2))];
+    assert!(register.assign_fields().is_err());
+}
+
+/// the reset value of an array is an aggregate of its array type, with the value in each element
+#[test]
+fn gen_reset() {
+    let mut register = register("ctrl", None);
+    register.signal = None;
+    register.fields = vec![
+        mdf::Field { name: "enable".to_owned(), ..field(mdf::FieldPosition::Single(5), None, None) },
+        mdf::Field {
+            name: "gain".to_owned(),
+            signal: SignalType::Unsigned,
+            reset: VectorValue::from(2),
+            ..field(mdf::FieldPosition::Field(11, 8), Some(3), None)
+        },
+    ];
+    let generated = gen_model(&model(vec![register]));
+    let fields = &generated.interfaces[0].registers[0].fields;
+    assert_eq!(("5", "'0'"), (fields[0].position.as_str(), fields[0].reset.as_str()));
+    assert_eq!(("19..8", "(others => unsigned'(4x\"2\"))"), (fields[1].position.as_str(), fields[1].reset.as_str()));
+    assert_eq!(fields[1].array_type_name, fields[1].pif2core[0].signal_type);
+}
@@ -0,0 +1,75 @@
+//! Tests for the field overlap detection and resolution
+
+use crate::file_formats::mdf;
+
+/// build a bitfield register with fields at the given positions
+fn register(positions: &[(u32, u32)]) -> mdf::Register {
+    let mut register = mdf::Register::new();
+    register.signal = None;
+    register.fields = positions
+        .iter()
+        .enumerate()
+        .map(|(n, &(msb, lsb))| mdf::Field {
+            name: format!("f{}", n),
+            position: mdf::FieldPosition::Field(msb, lsb),
+            ..Default::default()
+        })
+        .collect();
+    register
+}
+
+/// each overlapping pair is reported once, with the first common bit
+#[test]
+fn overlaps() {
+    assert!(register(&[(3, 0), (7, 4)]).field_overlaps().is_empty());
+    assert_eq!(vec![(1, 0, 2), (2, 0, 3), (2, 1, 4)], register(&[(3, 0), (5, 2), (4, 3)]).field_overlaps());
+
+    // the array elements are taken into account, not the bits between them
+    let mut array = register(&[(1, 0), (3, 2)]);
+    array.fields[0].array = Some(mdf::FieldArray { count: 2, stride: Some(4) });
+    assert!(array.field_overlaps().is_empty());
+    array.fields[1].position = mdf::FieldPosition::Field(5, 4);
+    assert_eq!(vec![(1, 0, 4)], array.field_overlaps());
+}
+
+/// the overlapping fields are moved to the lowest free bits, the others stay in place
+#[test]
+fn resolve() {
+    let register = register(&[(7, 4), (1, 0), (5, 4), (2, 1)]);
+    assert_eq!(
+        vec![(2, Some(mdf::FieldPosition::Field(3, 2))), (3, Some(mdf::FieldPosition::Field(9, 8)))],
+        register.resolve_overlaps(32)
+    );
+
+    // the fields aren't moved beyond the data width
+    assert_eq!(
+        vec![(2, Some(mdf::FieldPosition::Field(3, 2))), (3, None)],
+        register.resolve_overlaps(8)
+    );
+}
+
+/// a field with its lsb above its msb is left to the validation
+#[test]
+fn reversed_field() {
+    let mut register = register(&[(8, 8), (3, 8)]);
+    register.fields[0].position = mdf::FieldPosition::Single(8);
+    assert!(register.field_overlaps().is_empty());
+    assert!(register.resolve_overlaps(32).is_empty());
+
+    register.fields.push(mdf::Field { position: mdf::FieldPosition::Single(8), ..Default::default() });
+    assert_eq!(vec![(2, 0, 8)], register.field_overlaps());
+    assert_eq!(vec![(2, Some(mdf::FieldPosition::Single(0)))], register.resolve_overlaps(32));
+}
+
+/// positions built and moved by the bitmap editor
+#[test]
+fn position_edit() {
+    assert_eq!(mdf::FieldPosition::Single(5), mdf::FieldPosition::from_bits(5, 5));
+    assert_eq!(mdf::FieldPosition::Field(7, 2), mdf::FieldPosition::from_bits(2, 7));
+    assert_eq!(mdf::FieldPosition::Field(7, 2), mdf::FieldPosition::from_bits(7, 2));
+
+    assert_eq!(Some(mdf::FieldPosition::Field(9, 4)), mdf::FieldPosition::Field(7, 2).shifted(2));
+    assert_eq!(Some(mdf::FieldPosition::Field(5, 0)), mdf::FieldPosition::Field(7, 2).shifted(-2));
+    assert_eq!(None, mdf::FieldPosition::Field(7, 2).shifted(-3));
+    assert_eq!(Some(mdf::FieldPosition::Single(0)), mdf::FieldPosition::Single(1).shifted(-1));
+}
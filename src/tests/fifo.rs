@@ -0,0 +1,104 @@
+//! Tests for the registers mapped to a FIFO
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf;
+use crate::generate::genmodel::GenStructSignal;
+
+/// a FIFO register is moved to the core, without enables or hardware access
+#[test]
+fn clean_fifo_register() {
+    let mut register = mdf::Register::new();
+    register.fifo = Some(mdf::FifoProperties::default());
+    register.access = Some(mdf::AccessType::W1C);
+    register.core_signal_properties.use_write_enable = Some(true);
+    register.clean();
+    assert!(register.location == Some(mdf::LocationType::Core));
+    assert!(register.access == Some(mdf::AccessType::RW));
+    assert!(register.core_signal_properties.must_skip());
+    assert!(register.fifo.is_some());
+}
+
+/// a bitfield can't be mapped to a FIFO
+#[test]
+fn clean_bitfield() {
+    let mut register = mdf::Register::new();
+    register.fifo = Some(mdf::FifoProperties::default());
+    register.fields.push(mdf::Field::new());
+    register.clean();
+    assert!(register.fifo.is_none());
+}
+
+/// build a FIFO register in the core with the given access
+fn fifo_register(name: &str, access: mdf::AccessType, fifo: mdf::FifoProperties) -> mdf::Register {
+    let mut register = register(name, None);
+    register.access = Some(access);
+    register.location = Some(mdf::LocationType::Core);
+    register.fifo = Some(fifo);
+    register
+}
+
+/// functions of the signals of a record
+fn functions(signals: &[GenStructSignal]) -> Vec<&str> {
+    signals.iter().map(|signal| signal.function.as_str()).collect()
+}
+
+/// a write pushes the FIFO and a read pops it, an access to a full or empty FIFO stalls by default
+#[test]
+fn gen_push_pop() {
+    let generated = gen_model(&model(vec![
+        fifo_register("data", mdf::AccessType::RW, Default::default()),
+        fifo_register("tx", mdf::AccessType::WO, Default::default()),
+        fifo_register("rx", mdf::AccessType::RO, Default::default()),
+    ]));
+    let interface = &generated.interfaces[0];
+    assert!(interface.use_wait_states);
+
+    let data = &interface.registers[0];
+    assert!(data.is_fifo && data.fifo_stall && !data.fifo_use_level);
+    assert_eq!(vec!["data", "fifo_push", "fifo_pop"], functions(&data.fields[0].pif2core));
+    assert_eq!(vec!["data", "fifo_full", "fifo_empty"], functions(&data.fields[0].core2pif));
+
+    let tx = &interface.registers[1].fields[0];
+    assert_eq!((vec!["data", "fifo_push"], vec!["fifo_full"]), (functions(&tx.pif2core), functions(&tx.core2pif)));
+    let rx = &interface.registers[2].fields[0];
+    assert_eq!((vec!["fifo_pop"], vec!["data", "fifo_empty"]), (functions(&rx.pif2core), functions(&rx.core2pif)));
+}
+
+/// the core can provide the FIFO level, 8 bits by default
+#[test]
+fn gen_level() {
+    let generated = gen_model(&model(vec![
+        fifo_register("rx", mdf::AccessType::RO, mdf::FifoProperties { use_level: Some(true), ..Default::default() }),
+        fifo_register(
+            "tx",
+            mdf::AccessType::WO,
+            mdf::FifoProperties { use_level: Some(true), level_width: Some(5), ..Default::default() },
+        ),
+    ]));
+    let registers = &generated.interfaces[0].registers;
+    assert!(registers[0].fifo_use_level);
+    assert_eq!((8, 5), (registers[0].fifo_level_width, registers[1].fifo_level_width));
+    let level_types: Vec<&str> = registers
+        .iter()
+        .map(|register| {
+            let level = register.fields[0].core2pif.iter().find(|signal| signal.function == "fifo_level").unwrap();
+            level.signal_type.as_str()
+        })
+        .collect();
+    assert_eq!(vec!["unsigned(7 downto 0)", "unsigned(4 downto 0)"], level_types);
+}
+
+/// with the flag, an access to a full or empty FIFO is dropped and flagged to the core instead of stalling
+#[test]
+fn gen_flag() {
+    let flag = mdf::FifoProperties { blocked_access: Some(mdf::FifoBlockedAccess::Flag), ..Default::default() };
+    let generated = gen_model(&model(vec![fifo_register("data", mdf::AccessType::RW, flag)]));
+    let interface = &generated.interfaces[0];
+    assert!(!interface.use_wait_states);
+    let data = &interface.registers[0];
+    assert!(!data.fifo_stall);
+    assert_eq!(
+        vec!["data", "fifo_push", "fifo_pop", "fifo_overflow", "fifo_underflow"],
+        functions(&data.fields[0].pif2core)
+    );
+}
@@ -0,0 +1,93 @@
+//! Tests for the hardware set, clear and write access of the pif registers
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf;
+use crate::generate::genmodel::GenField;
+
+/// hardware access with set, clear and write
+fn set_clear_write() -> mdf::HardwareAccessProperties {
+    mdf::HardwareAccessProperties {
+        use_set: Some(true),
+        use_clear: Some(true),
+        use_write: Some(true),
+        priority: Some(mdf::HardwarePriority::Software),
+    }
+}
+
+/// functions of the core2pif signals of a field
+fn core2pif(field: &GenField) -> Vec<&str> {
+    field.core2pif.iter().map(|signal| signal.function.as_str()).collect()
+}
+
+/// a pif register gets the set and clear masks, and the written value with its valid strobe
+#[test]
+fn pif_register() {
+    let mut flags = register("flags", None);
+    flags.location = Some(mdf::LocationType::Pif);
+    flags.hardware_access = set_clear_write();
+    let generated = gen_model(&model(vec![flags]));
+    let field = &generated.interfaces[0].registers[0].fields[0];
+    assert!(field.hw_set && field.hw_clear && field.hw_write);
+    assert!(!field.hw_has_priority);
+    assert_eq!(vec!["data", "hw_set", "hw_clear", "hw_valid"], core2pif(field));
+    assert_eq!(field.core2pif[1].signal_type, field.core2pif[2].signal_type);
+}
+
+/// a pif field gets its own signals, a core field none
+#[test]
+fn pif_field() {
+    let mut flags = register("flags", None);
+    flags.location = Some(mdf::LocationType::Pif);
+    flags.signal = None;
+    flags.fields = vec![
+        mdf::Field {
+            name: "done".to_owned(),
+            position: mdf::FieldPosition::Single(0),
+            hardware_access: mdf::HardwareAccessProperties { use_set: Some(true), ..Default::default() },
+            ..Default::default()
+        },
+        mdf::Field {
+            name: "count".to_owned(),
+            position: mdf::FieldPosition::Field(11, 4),
+            hardware_access: mdf::HardwareAccessProperties { use_write: Some(true), ..Default::default() },
+            ..Default::default()
+        },
+        mdf::Field {
+            name: "level".to_owned(),
+            position: mdf::FieldPosition::Field(23, 16),
+            location: Some(mdf::LocationType::Core),
+            hardware_access: set_clear_write(),
+            ..Default::default()
+        },
+    ];
+    let generated = gen_model(&model(vec![flags]));
+    let fields = &generated.interfaces[0].registers[0].fields;
+    assert_eq!(vec!["hw_set"], core2pif(&fields[0]));
+    assert_eq!(vec!["data", "hw_valid"], core2pif(&fields[1]));
+    assert!(!fields[2].core2pif.iter().any(|signal| signal.function.starts_with("hw_")));
+    assert!(!fields[2].hw_set && !fields[2].hw_clear && !fields[2].hw_write);
+}
+
+/// the hardware access is dropped from the registers and fields located in the core
+#[test]
+fn clean_core_location() {
+    let mut register = mdf::Register::new();
+    register.location = Some(mdf::LocationType::Core);
+    register.hardware_access = set_clear_write();
+    register.clean();
+    assert!(register.hardware_access.must_skip());
+
+    let mut register = mdf::Register::new();
+    register.location = Some(mdf::LocationType::Pif);
+    register.fields = vec![
+        mdf::Field {
+            location: Some(mdf::LocationType::Core),
+            hardware_access: set_clear_write(),
+            ..Default::default()
+        },
+        mdf::Field { hardware_access: set_clear_write(), ..Default::default() },
+    ];
+    register.clean();
+    assert!(register.fields[0].hardware_access.must_skip());
+    assert!(!register.fields[1].hardware_access.must_skip());
+}
@@ -0,0 +1,89 @@
+//! Tests for the interrupt blocks expansion
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf;
+
+/// build an interrupt block with the given sources
+fn block(sources: &[&str], use_force: bool) -> mdf::InterruptBlock {
+    mdf::InterruptBlock {
+        name: "irq".to_owned(),
+        description: None,
+        sources: sources
+            .iter()
+            .map(|name| mdf::InterruptSource {
+                name: name.to_string(),
+                description: None,
+            })
+            .collect(),
+        use_force: Some(use_force),
+    }
+}
+
+/// test the registers generated by a block
+#[test]
+fn to_registers() {
+    let registers = block(&["rx", "tx"], false).to_registers().unwrap();
+    let names: Vec<&str> = registers.iter().map(|reg| reg.name.as_str()).collect();
+    assert_eq!(vec!["irq_status", "irq_enable", "irq_pending"], names);
+
+    let status = &registers[0];
+    assert_eq!(2, status.fields.len());
+    assert!(status.fields[1].position == mdf::FieldPosition::Single(1));
+    assert!(status.fields.iter().all(|field| field.access == mdf::AccessType::W1C));
+    assert!(status.fields.iter().all(|field| field.hardware_access.use_set == Some(true)));
+
+    let registers = block(&["rx"], true).to_registers().unwrap();
+    assert_eq!(4, registers.len());
+    assert!(registers[3].fields[0].access == mdf::AccessType::WP);
+
+    assert!(block(&[], false).to_registers().is_err());
+}
+
+/// test the expansion in an interface
+#[test]
+fn expand() {
+    let mut interface = mdf::Interface::new();
+    interface.registers.push(mdf::Register::new());
+    interface.interrupt_blocks.push(block(&["rx", "tx"], true));
+    interface.expand_interrupt_blocks().unwrap();
+    assert_eq!(5, interface.registers.len());
+    assert!(interface.interrupt_blocks.is_empty());
+
+    // too many sources for the interface width
+    let mut interface = mdf::Interface::new();
+    interface.data_width = Some(1);
+    interface.interrupt_blocks.push(block(&["rx", "tx"], false));
+    assert!(interface.expand_interrupt_blocks().is_err());
+
+    // or for the width inferred from the other registers
+    let mut interface = mdf::Interface::new();
+    let mut register = mdf::Register::new();
+    register.width = Some(1);
+    interface.registers.push(register);
+    interface.interrupt_blocks.push(block(&["rx", "tx"], false));
+    assert!(interface.expand_interrupt_blocks().is_err());
+}
+
+/// the block registers are linked in the generation model, and the core sets the status bits
+#[test]
+fn gen_links() {
+    let mut model = model(vec![register("ctrl", None)]);
+    model.interfaces[0].interrupt_blocks.push(block(&["rx", "tx"], true));
+    let generated = gen_model(&model);
+    let interface = &generated.interfaces[0];
+    let functions: Vec<(&str, &str)> = interface
+        .registers
+        .iter()
+        .map(|register| (register.interrupt_block.as_str(), register.interrupt_function.as_str()))
+        .collect();
+    assert_eq!(
+        vec![("", ""), ("irq", "status"), ("irq", "enable"), ("irq", "pending"), ("irq", "force")],
+        functions
+    );
+
+    let status = &interface.registers[1];
+    let set_signals: Vec<&str> = status.fields.iter().map(|field| field.core2pif_names["hw_set"].as_str()).collect();
+    let block = &interface.interrupt_blocks[0];
+    assert_eq!(set_signals, block.set_signals);
+    assert!(interface.ports.iter().any(|port| port.name == block.irq_name && port.direction == "out"));
+}
@@ -0,0 +1,55 @@
+//! Tests for the address allocation of memory windows
+
+use super::common;
+use crate::file_formats::mdf;
+use crate::utils::VectorValue;
+
+/// build a 32 bits interface with one register and a memory
+fn interface(depth: u32, address: Option<u128>) -> mdf::Interface {
+    let mut interface = common::interface(vec![common::register("ctrl", None)]);
+    let mut memory = mdf::Memory::new();
+    memory.name = "lut".to_owned();
+    memory.depth = depth;
+    memory.address = address.map(VectorValue::from);
+    interface.memories.push(memory);
+    interface
+}
+
+/// size, alignment and index width of a memory
+#[test]
+fn geometry() {
+    let memory = interface(100, None).memories.remove(0);
+    assert_eq!(400, memory.span(4));
+    assert_eq!(512, memory.alignment(4));
+    assert_eq!(7, memory.index_width());
+
+    let memory = interface(1, None).memories.remove(0);
+    assert_eq!(1, memory.index_width());
+}
+
+/// automatic placement on an aligned address after the registers
+#[test]
+fn automatic_address() {
+    let mut interface = interface(16, None);
+    interface.assign_addresses().unwrap();
+    assert_eq!(Some(64), interface.memories[0].address.map(|address| address.value));
+    assert_eq!(Some(0), interface.registers[0].address.value.map(|address| address.value));
+    assert_eq!(Some(7), interface.get_address_width());
+
+    interface.deassign_addresses().unwrap();
+    assert!(interface.memories[0].address.is_none());
+}
+
+/// fixed addresses must be aligned and can't overlap with registers
+#[test]
+fn fixed_address() {
+    let mut aligned = interface(16, Some(0x80));
+    aligned.assign_addresses().unwrap();
+    assert_eq!(Some(0), aligned.registers[0].address.value.map(|address| address.value));
+
+    assert!(interface(16, Some(0x20)).assign_addresses().is_err());
+
+    let mut overlap = interface(16, Some(0x40));
+    overlap.registers[0].address.value = Some(VectorValue::from(0x44));
+    assert!(overlap.assign_addresses().is_err());
+}
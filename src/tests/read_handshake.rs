@@ -0,0 +1,74 @@
+//! Tests for the read request/valid handshake of the core registers
+
+use super::common::{gen_model, model, register};
+use crate::file_formats::mdf;
+
+/// build a register asking for a read handshake
+fn handshake_register(name: &str, access: mdf::AccessType, location: mdf::LocationType) -> mdf::Register {
+    let mut register = register(name, None);
+    register.access = Some(access);
+    register.location = Some(location);
+    register.core_signal_properties.use_read_handshake = Some(true);
+    register
+}
+
+/// only a readable register in the core gets the handshake
+#[test]
+fn core_register() {
+    let generated = gen_model(&model(vec![
+        handshake_register("status", mdf::AccessType::RO, mdf::LocationType::Core),
+        handshake_register("ctrl", mdf::AccessType::RW, mdf::LocationType::Pif),
+        handshake_register("command", mdf::AccessType::WO, mdf::LocationType::Core),
+    ]));
+    let interface = &generated.interfaces[0];
+    assert!(interface.use_read_handshake);
+    assert_eq!(
+        vec![true, false, false],
+        interface.registers.iter().map(|register| register.fields[0].core_read_handshake).collect::<Vec<_>>()
+    );
+
+    let status = &interface.registers[0].fields[0];
+    assert!(status.pif2core.iter().any(|signal| signal.function == "read_request"));
+    assert!(status.core2pif.iter().any(|signal| signal.function == "read_valid"));
+    let ctrl = &interface.registers[1].fields[0];
+    assert!(!ctrl.pif2core.iter().chain(&ctrl.core2pif).any(|signal| signal.function.starts_with("read_")));
+}
+
+/// an interface without any handshake doesn't need wait states for it
+#[test]
+fn no_handshake() {
+    let generated = gen_model(&model(vec![handshake_register("ctrl", mdf::AccessType::RW, mdf::LocationType::Pif)]));
+    assert!(!generated.interfaces[0].use_read_handshake);
+}
+
+/// Avalon has no ready signal, the waitrequest port holds the bus when an access needs wait states
+#[test]
+fn avalon_wait_request() {
+    let ports = |mut model: mdf::Mdf| {
+        model.interfaces[0].interface_type = mdf::InterfaceType::AvalonMm;
+        let generated = gen_model(&model);
+        let interface = &generated.interfaces[0];
+        (interface.use_wait_states, interface.ports.iter().any(|port| port.function == "waitrequest"))
+    };
+    let handshake = model(vec![handshake_register("status", mdf::AccessType::RO, mdf::LocationType::Core)]);
+    assert_eq!((true, true), ports(handshake.clone()));
+    assert_eq!((false, false), ports(model(vec![register("ctrl", None)])));
+
+    let mut fifo = register("data", None);
+    fifo.fifo = Some(mdf::FifoProperties::default());
+    assert_eq!((true, true), ports(model(vec![fifo.clone()])));
+    fifo.fifo = Some(mdf::FifoProperties { blocked_access: Some(mdf::FifoBlockedAccess::Flag), ..Default::default() });
+    assert_eq!((false, false), ports(model(vec![fifo])));
+
+    let mut memory = model(vec![register("ctrl", None)]);
+    let mut lut = mdf::Memory::new();
+    lut.name = "lut".to_owned();
+    lut.depth = 16;
+    memory.interfaces[0].memories.push(lut);
+    assert_eq!((true, true), ports(memory));
+
+    // the other interfaces already have a signal to hold the bus
+    let generated = gen_model(&handshake);
+    assert!(generated.interfaces[0].use_wait_states);
+    assert!(!generated.interfaces[0].ports.iter().any(|port| port.function == "waitrequest"));
+}
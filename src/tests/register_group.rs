@@ -0,0 +1,61 @@
+//! Tests for the address allocation of register groups
+
+use super::common::{self, addresses};
+use crate::file_formats::mdf;
+use crate::utils::VectorValue;
+
+/// build a 32 bits interface with two registers outside of any group and a group of two registers
+fn interface(group_base: Option<u128>, group_count: Option<u128>) -> mdf::Interface {
+    let mut interface = common::interface(Vec::new());
+    for (name, group) in [("ctrl", None), ("status", None), ("rx", Some("channel")), ("tx", Some("channel"))] {
+        let mut register = common::register(name, None);
+        register.group = group.map(|group| group.to_owned());
+        interface.registers.push(register);
+    }
+    let mut group = mdf::RegisterGroup::new();
+    group.name = "channel".to_owned();
+    group.base = group_base.map(VectorValue::from);
+    group.count = group_count.map(VectorValue::from);
+    interface.groups.push(group);
+    interface
+}
+
+/// automatic group placement after the other registers
+#[test]
+fn automatic_base() {
+    let mut interface = interface(None, None);
+    interface.assign_addresses().unwrap();
+    assert_eq!(vec![0, 4, 0, 4], addresses(&interface));
+    assert_eq!(8, interface.groups[0].base.unwrap().value);
+
+    interface.flatten_groups().unwrap();
+    assert_eq!(vec![0, 4, 8, 12], addresses(&interface));
+    assert!(interface.registers[2].address.stride.is_none());
+}
+
+/// repeated group at a fixed base address
+#[test]
+fn repeated_group() {
+    let mut interface = interface(Some(0x100), Some(4));
+    interface.assign_addresses().unwrap();
+    assert_eq!(Some(8), interface.get_group_increment(0));
+
+    interface.flatten_groups().unwrap();
+    assert_eq!(vec![0, 4, 0x100, 0x104], addresses(&interface));
+    let stride = interface.registers[3].address.stride.as_ref().unwrap();
+    assert_eq!(4, stride.count.value);
+    assert_eq!(Some(8), stride.increment.map(|increment| increment.value));
+    assert_eq!(0x11c, interface.registers[3].high_address(32, 4).unwrap());
+}
+
+/// overlapping and unknown groups are detected
+#[test]
+fn errors() {
+    let mut overlap = interface(Some(4), None);
+    overlap.registers[1].address.value = Some(VectorValue::from(8));
+    assert!(overlap.assign_addresses().is_err());
+
+    let mut unknown = interface(None, None);
+    unknown.registers[2].group = Some("unknown".to_owned());
+    assert!(unknown.assign_addresses().is_err());
+}
@@ -0,0 +1,83 @@
+//! Tests for the shadowed (double buffered) registers
+
+use super::common::{gen_model, gen_variant, model, register};
+use crate::file_formats::mdf;
+
+/// a shadowed register is moved to the pif, and a strobe commit doesn't keep a commit register
+#[test]
+fn clean_shadow_register() {
+    let mut register = mdf::Register::new();
+    register.location = Some(mdf::LocationType::Core);
+    register.core_signal_properties.use_write_enable = Some(true);
+    register.shadow = Some(mdf::ShadowProperties {
+        commit: Some(mdf::ShadowCommit::Strobe),
+        commit_register: Some("apply".to_owned()),
+    });
+    register.clean();
+    assert!(register.location == Some(mdf::LocationType::Pif));
+    assert!(register.core_signal_properties.must_skip());
+    assert!(register.shadow.as_ref().unwrap().commit_register.is_none());
+}
+
+/// read only and FIFO registers can't be shadowed
+#[test]
+fn clean_not_writable() {
+    let mut register = mdf::Register::new();
+    register.access = Some(mdf::AccessType::RO);
+    register.shadow = Some(mdf::ShadowProperties::default());
+    register.clean();
+    assert!(register.shadow.is_none());
+
+    let mut register = mdf::Register::new();
+    register.fifo = Some(mdf::FifoProperties::default());
+    register.shadow = Some(mdf::ShadowProperties::default());
+    register.clean();
+    assert!(register.shadow.is_none());
+}
+
+/// the fields of a shadowed bitfield register are moved to the pif too, to get their shadow copy
+#[test]
+fn clean_bitfield_register() {
+    let mut register = mdf::Register::new();
+    register.location = Some(mdf::LocationType::Core);
+    let mut field =
+        mdf::Field { name: "mode".to_owned(), location: Some(mdf::LocationType::Core), ..Default::default() };
+    field.core_signal_properties.use_write_enable = Some(true);
+    register.fields = vec![field];
+    register.shadow = Some(mdf::ShadowProperties::default());
+    register.clean();
+    assert!(register.location == Some(mdf::LocationType::Pif));
+    assert!(register.fields[0].location == Some(mdf::LocationType::Pif));
+    assert!(register.fields[0].core_signal_properties.must_skip());
+}
+
+/// a register commit needs an existing and writable register, which lists the registers it commits
+#[test]
+fn commit_register() {
+    let shadowed = |commit_register: &str| {
+        let mut register = register("gain", None);
+        register.location = Some(mdf::LocationType::Pif);
+        register.shadow = Some(mdf::ShadowProperties {
+            commit: Some(mdf::ShadowCommit::Register),
+            commit_register: Some(commit_register.to_owned()),
+        });
+        register
+    };
+    let mut apply = register("apply", None);
+    apply.location = Some(mdf::LocationType::Pif);
+
+    let generated = gen_model(&model(vec![shadowed("apply"), apply.clone()]));
+    let registers = &generated.interfaces[0].registers;
+    assert!(!registers[0].shadow_commit_strobe);
+    assert!(registers[0].core2pif.is_empty());
+    assert_eq!(vec!["gain".to_owned()], registers[1].commits);
+
+    let error = gen_variant(&model(vec![shadowed("update"), apply.clone()]), None).err().unwrap();
+    assert!(error.contains("unknown register 'update'"));
+    let error = gen_variant(&model(vec![shadowed("gain")]), None).err().unwrap();
+    assert!(error.contains("unknown register 'gain'"));
+
+    apply.access = Some(mdf::AccessType::RO);
+    let error = gen_variant(&model(vec![shadowed("apply"), apply]), None).err().unwrap();
+    assert!(error.contains("can't be written"));
+}
@@ -32,3 +32,10 @@ fn generate_vhdl_token() {
     assert_eq!(list.generate_token("_abcd_*"), "abcd_3");
     assert_eq!(list.generate_token("*signal"), "x2signal");
 }
+
+#[test]
+fn to_camel_case() {
+    assert_eq!(tokenlist::to_camel_case("abcd"), "Abcd");
+    assert_eq!(tokenlist::to_camel_case("mode_select"), "ModeSelect");
+    assert_eq!(tokenlist::to_camel_case("RX_FIFO_level"), "RxFifoLevel");
+}
@@ -0,0 +1,71 @@
+//! Tests for the model validation
+
+use super::common::{model, register};
+use crate::file_formats::mdf;
+use crate::page::PageType;
+use crate::utils::VectorValue;
+use crate::validation::{self, Severity};
+
+/// a correct model gives no diagnostics
+#[test]
+fn valid_model() {
+    let model = model(vec![register("a", Some(0)), register("b", Some(4))]);
+    assert!(validation::validate(&model).is_empty());
+}
+
+/// all the problems are reported, each one on its page
+#[test]
+fn all_errors() {
+    let mut too_wide = register("b", Some(4));
+    too_wide.width = Some(4);
+    too_wide.reset = Some(VectorValue::from(0x10));
+    let mut no_location = register("c", Some(8));
+    no_location.location = None;
+    let model = model(vec![register("a", Some(0)), too_wide, no_location, register("d", Some(0))]);
+
+    let diagnostics = validation::validate(&model);
+    let pages = diagnostics.iter().map(|diagnostic| diagnostic.page.clone()).collect::<Vec<_>>();
+    assert_eq!(3, diagnostics.len());
+    assert!(validation::has_errors(&diagnostics));
+    assert!(pages.contains(&PageType::Register(0, 1, None)));
+    assert!(pages.contains(&PageType::Register(0, 2, None)));
+    assert!(pages.contains(&PageType::Register(0, 3, None)));
+}
+
+/// field problems point to the field
+#[test]
+fn fields() {
+    let mut bitfield = register("a", Some(0));
+    bitfield.signal = None;
+    bitfield.fields = vec![
+        mdf::Field { name: "x".to_owned(), position: mdf::FieldPosition::Field(3, 0), ..Default::default() },
+        mdf::Field { name: "y".to_owned(), position: mdf::FieldPosition::Field(5, 2), ..Default::default() },
+        mdf::Field { name: "z".to_owned(), position: mdf::FieldPosition::Field(6, 8), ..Default::default() },
+    ];
+    let model = model(vec![bitfield]);
+
+    let diagnostics = validation::validate(&model);
+    assert_eq!(2, diagnostics.len());
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.severity == Severity::Error));
+    assert!(diagnostics[0].page == PageType::Register(0, 0, Some(2)));
+    assert!(diagnostics[1].page == PageType::Register(0, 0, Some(1)));
+}
+
+/// fixed registers can't use a reserved range, and a pinned register needs an address
+#[test]
+fn allocation() {
+    let mut pinned = register("b", Some(0));
+    pinned.address.value = None;
+    pinned.pinned = Some(true);
+    let mut model = model(vec![register("a", Some(8)), pinned]);
+    model.interfaces[0].reserved.push(mdf::ReservedRange {
+        name: "future".to_owned(),
+        start: VectorValue::from(8),
+        end: VectorValue::from(0xf),
+    });
+
+    let diagnostics = validation::validate(&model);
+    assert_eq!(2, diagnostics.len());
+    assert!(diagnostics[0].severity == Severity::Error && diagnostics[0].page == PageType::Register(0, 0, None));
+    assert!(diagnostics[1].severity == Severity::Warning && diagnostics[1].page == PageType::Register(0, 1, None));
+}
@@ -0,0 +1,132 @@
+//! Tests for the product variants
+
+use super::common::gen_variant;
+use crate::file_formats::mdf;
+use crate::validation::{self, Severity};
+
+/// build a project with the BASIC and PRO variants, and a register whose field and
+/// second register are only in PRO
+fn model() -> mdf::Mdf {
+    let mut model = mdf::Mdf {
+        variants: ["BASIC", "PRO"].iter().map(|name| mdf::Variant {
+            name: name.to_string(),
+            description: None,
+        }).collect(),
+        ..Default::default()
+    };
+
+    let mut interface = mdf::Interface::new();
+    let mut ctrl = mdf::Register::new();
+    ctrl.name = "ctrl".to_owned();
+    ctrl.signal = None;
+    ctrl.fields = vec![
+        mdf::Field { name: "enable".to_owned(), ..Default::default() },
+        mdf::Field { name: "turbo".to_owned(), variants: vec!["PRO".to_owned()], ..Default::default() },
+    ];
+    let mut gain = mdf::Register::new();
+    gain.name = "gain".to_owned();
+    gain.variants = vec!["PRO".to_owned()];
+    // a bitfield register whose only field is in PRO
+    let mut dsp = mdf::Register::new();
+    dsp.name = "dsp".to_owned();
+    dsp.signal = None;
+    dsp.fields = vec![mdf::Field { name: "mode".to_owned(), variants: vec!["PRO".to_owned()], ..Default::default() }];
+    interface.registers = vec![ctrl, gain, dsp];
+    model.interfaces = vec![interface];
+    model
+}
+
+/// selecting a variant removes the elements that are not in it
+#[test]
+fn select_variant() {
+    let mut pro = model();
+    pro.select_variant("PRO").unwrap();
+    assert_eq!(3, pro.interfaces[0].registers.len());
+    assert_eq!(2, pro.interfaces[0].registers[0].fields.len());
+
+    let mut basic = model();
+    basic.select_variant("BASIC").unwrap();
+    let registers = &basic.interfaces[0].registers;
+    assert_eq!(1, registers.len());
+    assert_eq!("ctrl", registers[0].name);
+    assert_eq!(1, registers[0].fields.len());
+
+    assert!(model().select_variant("LITE").is_err());
+}
+
+/// references to undefined variants are errors
+#[test]
+fn check_variants() {
+    let mut model = model();
+    assert!(model.check_variants().is_ok());
+    model.interfaces[0].registers[1].variants.push("LITE".to_owned());
+    assert!(model.check_variants().is_err());
+}
+
+/// renaming a variant follows the references, removing it drops them
+#[test]
+fn set_variants() {
+    let mut model = model();
+    let mut variants = model.variants.clone();
+    variants[1].name = "PREMIUM".to_owned();
+    model.set_variants(variants.clone());
+    assert_eq!(vec!["PREMIUM".to_owned()], model.interfaces[0].registers[1].variants);
+
+    variants.remove(1);
+    model.set_variants(variants);
+    assert!(model.interfaces[0].registers[1].variants.is_empty());
+    assert!(model.interfaces[0].registers[0].fields[1].variants.is_empty());
+}
+
+/// the active variant is saved with the project and follows the renames
+#[test]
+fn active_variant() {
+    let mut model = model();
+    model.active_variant = Some("PRO".to_owned());
+    let json = serde_json::to_string(&model).unwrap();
+    assert!(json.contains("\"activeVariant\":\"PRO\""));
+    assert_eq!(Some("PRO".to_owned()), mdf::Mdf::from_slice(json.as_bytes()).unwrap().active_variant);
+
+    let mut variants = model.variants.clone();
+    variants[1].name = "PREMIUM".to_owned();
+    model.set_variants(variants.clone());
+    assert_eq!(Some("PREMIUM".to_owned()), model.active_variant);
+
+    variants.remove(1);
+    model.set_variants(variants);
+    assert_eq!(None, model.active_variant);
+
+    // an unknown variant is reported by the validation
+    model.active_variant = Some("LITE".to_owned());
+    assert!(validation::validate(&model)
+        .iter()
+        .any(|diagnostic| diagnostic.severity == Severity::Error && diagnostic.message.contains("LITE")));
+}
+
+/// the registers keep the same address in each generated variant, and the variant given to the generation
+/// replaces the one of the project
+#[test]
+fn generated_variant() {
+    let mut model = model();
+    model.interfaces[0].data_width = Some(32);
+    let mut status = mdf::Register::new();
+    status.name = "status".to_owned();
+    model.interfaces[0].registers.push(status);
+    model.active_variant = Some("PRO".to_owned());
+
+    let addresses = |variant: Option<&str>| {
+        let generated = gen_variant(&model, variant).unwrap();
+        generated.interfaces[0]
+            .registers
+            .iter()
+            .map(|register| (register.name.clone(), register.address_hex.clone()))
+            .collect::<Vec<_>>()
+    };
+    let pro = addresses(None);
+    assert_eq!(4, pro.len());
+    assert_eq!(("status".to_owned(), "c".to_owned()), pro[3]);
+    assert_eq!(vec![pro[0].clone(), pro[3].clone()], addresses(Some("BASIC")));
+    assert_eq!(pro, addresses(Some("")));
+
+    assert!(gen_variant(&model, Some("LITE")).is_err());
+}
@@ -0,0 +1,83 @@
+//! Tests for the VHDL literals and conversions generation
+
+use super::common::{model, register};
+use crate::generate::genmodel::{vhdl_bus_conversions, vhdl_value_literal, vhdl_vector_literal, GenModel};
+use crate::generate::user_strings;
+use crate::settings::{Settings, VhdlRevision};
+use crate::utils::SignalType;
+use tera::Tera;
+
+/// VHDL-2008 uses sized bit string literals
+#[test]
+fn vhdl_2008() {
+    assert_eq!(vhdl_vector_literal(0, 32, VhdlRevision::Vhdl2008), "32x\"0\"");
+    assert_eq!(vhdl_vector_literal(0x1f, 5, VhdlRevision::Vhdl2008), "5x\"1f\"");
+}
+
+/// VHDL-93 uses hexadecimal literals when possible and binary strings otherwise
+#[test]
+fn vhdl_93() {
+    assert_eq!(vhdl_vector_literal(0, 32, VhdlRevision::Vhdl93), "x\"00000000\"");
+    assert_eq!(vhdl_vector_literal(0xab, 12, VhdlRevision::Vhdl93), "x\"0ab\"");
+    assert_eq!(vhdl_vector_literal(0x1f, 5, VhdlRevision::Vhdl93), "\"11111\"");
+    assert_eq!(vhdl_vector_literal(2, 3, VhdlRevision::Vhdl93), "\"010\"");
+}
+
+/// the unsigned and signed literals are qualified by their type
+#[test]
+fn value_literals() {
+    assert_eq!(vhdl_value_literal(1, 1, SignalType::StdLogic, VhdlRevision::Vhdl93), "'1'");
+    assert_eq!(vhdl_value_literal(0, 1, SignalType::Boolean, VhdlRevision::Vhdl2008), "false");
+    assert_eq!(vhdl_value_literal(3, 4, SignalType::StdLogicVector, VhdlRevision::Vhdl2008), "4x\"3\"");
+    assert_eq!(vhdl_value_literal(3, 4, SignalType::Unsigned, VhdlRevision::Vhdl2008), "unsigned'(4x\"3\")");
+    assert_eq!(vhdl_value_literal(0x1f, 5, SignalType::Signed, VhdlRevision::Vhdl93), "signed'(\"11111\")");
+}
+
+/// a boolean uses a conditional expression with VHDL-2008 and the package function with VHDL-93
+#[test]
+fn bus_conversions() {
+    let conversions = |signal, revision| {
+        let (to_bus, from_bus) = vhdl_bus_conversions(signal, revision);
+        (to_bus.replace("{}", "s"), from_bus.replace("{}", "d"))
+    };
+    assert_eq!(
+        ("'1' when s else '0'".to_owned(), "d = '1'".to_owned()),
+        conversions(SignalType::Boolean, VhdlRevision::Vhdl2008)
+    );
+    assert_eq!(
+        ("bool_to_std_logic(s)".to_owned(), "d = '1'".to_owned()),
+        conversions(SignalType::Boolean, VhdlRevision::Vhdl93)
+    );
+    assert_eq!(("s".to_owned(), "d".to_owned()), conversions(SignalType::StdLogicVector, VhdlRevision::Vhdl93));
+    assert_eq!(
+        ("std_logic_vector(s)".to_owned(), "unsigned(d)".to_owned()),
+        conversions(SignalType::Unsigned, VhdlRevision::Vhdl2008)
+    );
+    assert_eq!(
+        ("std_logic_vector(s)".to_owned(), "signed(d)".to_owned()),
+        conversions(SignalType::Signed, VhdlRevision::Vhdl93)
+    );
+}
+
+/// the model names the conversion function only with VHDL-93
+#[test]
+fn gen_conversions() {
+    let mut settings = Settings::default();
+    user_strings::load_defaults(&mut settings.user_templates);
+    let mut templates = Tera::default();
+    templates.add_raw_templates(settings.user_templates.clone()).unwrap();
+    let mut flag = register("flag", None);
+    flag.signal = Some(SignalType::Boolean);
+    flag.width = Some(1);
+    let model = model(vec![flag]);
+
+    settings.vhdl_revision = VhdlRevision::Vhdl93;
+    let generated = GenModel::from_model(&model, &settings, &templates, None).unwrap();
+    assert_eq!("bool_to_std_logic", generated.bool_to_std_logic_name);
+    assert_eq!("bool_to_std_logic({})", generated.interfaces[0].registers[0].fields[0].to_bus);
+
+    settings.vhdl_revision = VhdlRevision::Vhdl2008;
+    let generated = GenModel::from_model(&model, &settings, &templates, None).unwrap();
+    assert!(generated.bool_to_std_logic_name.is_empty());
+    assert_eq!("{} = '1'", generated.interfaces[0].registers[0].fields[0].from_bus);
+}
@@ -0,0 +1,53 @@
+//! Tests for the registers wider than the interface data bus
+
+use super::common::{self, addresses};
+use crate::file_formats::mdf;
+use crate::utils::VectorValue;
+
+/// build a 32 bits interface with a 64 bits counter between two 32 bits registers
+fn interface() -> mdf::Interface {
+    common::interface(
+        [("ctrl", 32), ("counter", 64), ("status", 32)]
+            .into_iter()
+            .map(|(name, width)| mdf::Register { width: Some(width), ..common::register(name, None) })
+            .collect(),
+    )
+}
+
+/// the wide register uses two consecutive addresses
+#[test]
+fn split_addresses() {
+    let mut interface = interface();
+    assert_eq!(Some(32), interface.get_data_width());
+    assert_eq!(2, interface.registers[1].word_count(32));
+    assert_eq!(1, interface.registers[1].word_count(64));
+    interface.assign_addresses().unwrap();
+    assert_eq!(vec![0, 4, 0xc], addresses(&interface));
+    assert_eq!(vec![0, 4], interface.registers[1].offsets(32, 4));
+    assert_eq!(Some(4), interface.get_address_width());
+}
+
+/// a fixed address overlapping the high word is detected
+#[test]
+fn high_word_overlap() {
+    let mut interface = interface();
+    interface.registers[1].address.value = Some(VectorValue::from(0x10));
+    interface.registers[2].address.value = Some(VectorValue::from(0x14));
+    assert!(interface.assign_addresses().is_err());
+}
+
+/// an array of wide registers has its elements next to each other by default
+#[test]
+fn split_array() {
+    let mut interface = interface();
+    interface.registers[1].width = Some(48);
+    interface.registers[1].address.stride = Some(mdf::AddressStride {
+        count: VectorValue::from(3),
+        increment: None,
+        inner: None,
+    });
+    interface.assign_addresses().unwrap();
+    assert_eq!(vec![0, 4, 8, 0xc, 0x10, 0x14], interface.registers[1].offsets(32, 4));
+    assert_eq!(vec![0, 4, 0x1c], addresses(&interface));
+    assert_eq!(Some(0x18), interface.registers[1].high_address(32, 4));
+}
@@ -206,3 +206,13 @@ pub fn textarea_to_opt_vec_str(value_str: &str) -> Option<Vec<String>> {
         Some(value_str.split('\n').map(|s| s.to_string()).collect())
     }
 }
+
+/// convert from the string typed for an optional expression. An empty string means no expression
+pub fn text_to_opt_expression(value_str: &str) -> Option<String> {
+    let value_str = value_str.trim();
+    if value_str.is_empty() {
+        None
+    } else {
+        Some(value_str.to_owned())
+    }
+}
@@ -0,0 +1,389 @@
+//! model validation
+//!
+//! Goes through the whole model and lists all the problems found, with their location, instead of
+//! stopping at the first one like the generation does. Errors prevent the generation, warnings
+//! are for constructions that are accepted but probably not what the user wants.
+
+use crate::file_formats::mdf;
+use crate::page::PageType;
+use std::collections::HashMap;
+
+/// severity of a diagnostic
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Severity {
+    /// the model can't be generated
+    Error,
+    /// the model can be generated, but probably not as intended
+    Warning,
+}
+
+/// problem found in the model
+#[derive(PartialEq, Clone)]
+pub struct Diagnostic {
+    /// error or warning
+    pub severity: Severity,
+    /// page where the problem can be fixed
+    pub page: PageType,
+    /// description of the problem
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// create an error diagnostic
+    pub fn error(page: PageType, message: String) -> Self {
+        Diagnostic { severity: Severity::Error, page, message }
+    }
+
+    /// create a warning diagnostic
+    pub fn warning(page: PageType, message: String) -> Self {
+        Diagnostic { severity: Severity::Warning, page, message }
+    }
+}
+
+/// true if at least one of the diagnostics is an error
+pub fn has_errors(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error)
+}
+
+/// list of the errors, one per line, to report them when the generation is refused
+pub fn error_summary(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .map(|diagnostic| diagnostic.message.clone())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// true if a value doesn't fit in the given number of bits
+fn too_wide(value: u128, width: u32) -> bool {
+    width < u128::BITS && (value >> width) != 0
+}
+
+/// validate the whole model, and return all the errors and warnings found
+pub fn validate(model: &mdf::Mdf) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if model.name.trim().is_empty() {
+        diagnostics.push(Diagnostic::warning(PageType::Project, "the project has no name".to_owned()));
+    }
+    let values = match model.parameter_values() {
+        Ok(values) => values,
+        Err(e) => {
+            diagnostics.push(Diagnostic::error(PageType::Project, e));
+            HashMap::new()
+        }
+    };
+    if let Err(e) = model.check_variants() {
+        diagnostics.push(Diagnostic::error(PageType::Project, e));
+    }
+    if let Some(variant) = &model.active_variant {
+        if !model.variants.iter().any(|v| &v.name == variant) {
+            diagnostics.push(Diagnostic::error(
+                PageType::Project,
+                format!("the selected variant {} isn't defined in the project", variant),
+            ));
+        }
+    }
+    if model.interfaces.is_empty() {
+        diagnostics.push(Diagnostic::warning(PageType::Project, "the project has no interface".to_owned()));
+    }
+
+    for (n, interface) in model.interfaces.iter().enumerate() {
+        if model.interfaces[..n].iter().any(|other| other.name == interface.name) {
+            diagnostics.push(Diagnostic::error(
+                PageType::Interface(n),
+                format!("interface name '{}' is used several times", interface.name),
+            ));
+        }
+
+        // check the interface with the expression results, or as is if they can't be evaluated
+        let mut interface = interface.clone();
+        if let Err(e) = interface.evaluate_expressions(&values) {
+            diagnostics.push(Diagnostic::error(PageType::Interface(n), e));
+            interface = model.interfaces[n].clone();
+        }
+        validate_interface(&interface, n, &mut diagnostics);
+    }
+
+    diagnostics
+}
+
+/// validate an interface and its registers
+fn validate_interface(interface: &mdf::Interface, int_num: usize, diagnostics: &mut Vec<Diagnostic>) {
+    let page = PageType::Interface(int_num);
+    let errors_before = diagnostics.len();
+
+    if interface.name.trim().is_empty() {
+        diagnostics.push(Diagnostic::warning(page.clone(), "the interface has no name".to_owned()));
+    }
+    let data_width = match interface.get_data_width() {
+        Some(width) if width > 0 => width,
+        _ => {
+            diagnostics.push(Diagnostic::error(
+                page.clone(),
+                format!("couldn't determine interface {} data width", interface.name),
+            ));
+            return;
+        }
+    };
+    let word_increment = interface.addressing_mode().word_increment(data_width);
+    if interface.separate_clock.unwrap_or(false) && !interface.memories.is_empty() {
+        diagnostics.push(Diagnostic::error(
+            page.clone(),
+            format!("interface {} has memories, they need the core and the bus on the same clock", interface.name),
+        ));
+    }
+    if interface.registers.is_empty() && interface.memories.is_empty() && interface.interrupt_blocks.is_empty() {
+        diagnostics.push(Diagnostic::warning(page.clone(), format!("interface {} is empty", interface.name)));
+    }
+
+    for range in &interface.reserved {
+        if range.end.value < range.start.value {
+            diagnostics
+                .push(Diagnostic::error(page.clone(), format!("reserved range {} ends before it starts", range.name)));
+        }
+    }
+    for group in interface.groups.iter().filter(|group| group.pinned.unwrap_or(false) && group.base.is_none()) {
+        diagnostics.push(Diagnostic::warning(
+            page.clone(),
+            format!("register group {} is pinned but its base address is automatic", group.name),
+        ));
+    }
+    for memory in interface.memories.iter().filter(|memory| memory.pinned.unwrap_or(false) && memory.address.is_none())
+    {
+        diagnostics.push(Diagnostic::warning(
+            page.clone(),
+            format!("memory {} is pinned but its address is automatic", memory.name),
+        ));
+    }
+
+    // used addresses of the fixed registers outside of groups, to report all the overlaps
+    let mut used_addresses: HashMap<u128, usize> = HashMap::new();
+
+    for (reg_num, register) in interface.registers.iter().enumerate() {
+        let reg_page = PageType::Register(int_num, reg_num, None);
+
+        if register.name.trim().is_empty() {
+            diagnostics.push(Diagnostic::error(reg_page.clone(), "the register has no name".to_owned()));
+        } else if interface.registers[..reg_num].iter().any(|other| other.name == register.name) {
+            diagnostics.push(Diagnostic::error(
+                reg_page.clone(),
+                format!("register name '{}' is used several times", register.name),
+            ));
+        }
+
+        if let Some(address) = register.address.value {
+            if address.value % word_increment != 0 {
+                diagnostics.push(Diagnostic::warning(
+                    reg_page.clone(),
+                    format!(
+                        "register {} address 0x{:x} isn't aligned on the interface width",
+                        register.name, address.value
+                    ),
+                ));
+            }
+            if register.group.is_none() {
+                let offsets = register.offsets(data_width, word_increment);
+                let reserved = interface.reserved.iter().find(|range| {
+                    offsets.iter().any(|offset| {
+                        (range.start.value..=range.end.value).contains(&address.value.saturating_add(*offset))
+                    })
+                });
+                if let Some(range) = reserved {
+                    diagnostics.push(Diagnostic::error(
+                        reg_page.clone(),
+                        format!("register {} uses addresses in reserved range {}", register.name, range.name),
+                    ));
+                }
+
+                let mut overlapped: Vec<usize> = Vec::new();
+                for offset in offsets {
+                    match used_addresses.get(&(address.value + offset)) {
+                        Some(other) => {
+                            if !overlapped.contains(other) {
+                                overlapped.push(*other);
+                                diagnostics.push(Diagnostic::error(
+                                    reg_page.clone(),
+                                    format!(
+                                        "register {} overlaps register {} at address 0x{:x}",
+                                        register.name,
+                                        interface.registers[*other].name,
+                                        address.value + offset
+                                    ),
+                                ));
+                            }
+                        }
+                        None => {
+                            used_addresses.insert(address.value + offset, reg_num);
+                        }
+                    }
+                }
+            }
+        }
+
+        if register.pinned.unwrap_or(false) && register.address.value.is_none() {
+            diagnostics.push(Diagnostic::warning(
+                reg_page.clone(),
+                format!("register {} is pinned but its address is automatic", register.name),
+            ));
+        }
+
+        if let Some(mdf::ShadowProperties { commit: Some(mdf::ShadowCommit::Register), commit_register }) =
+            &register.shadow
+        {
+            let commit_name = commit_register.clone().unwrap_or_default();
+            match interface.registers.iter().find(|other| other.name == commit_name && other.name != register.name) {
+                Some(other) if is_writable(other) => (),
+                Some(_) => diagnostics.push(Diagnostic::error(
+                    reg_page.clone(),
+                    format!(
+                        "register {} is committed by register {}, which can't be written",
+                        register.name, commit_name
+                    ),
+                )),
+                None => diagnostics.push(Diagnostic::error(
+                    reg_page.clone(),
+                    format!("register {} is committed by unknown register '{}'", register.name, commit_name),
+                )),
+            }
+        }
+
+        match register.signal {
+            Some(_) => validate_register_value(register, &reg_page, data_width, diagnostics),
+            None => validate_fields(register, int_num, reg_num, diagnostics),
+        }
+    }
+
+    // run the address assignment on a copy, to catch the problems with groups, memories and interrupt
+    // blocks. Only if nothing was found before, the first problem is probably the same
+    if diagnostics[errors_before..].iter().all(|diagnostic| diagnostic.severity != Severity::Error) {
+        let mut interface = interface.clone();
+        let result = interface
+            .expand_interrupt_blocks()
+            .and_then(|_| interface.assign_addresses())
+            .and_then(|_| interface.flatten_groups());
+        if let Err(e) = result {
+            diagnostics.push(Diagnostic::error(page, e));
+        }
+    }
+}
+
+/// true if the register, or at least one of its fields, can be written
+fn is_writable(register: &mdf::Register) -> bool {
+    match register.signal {
+        Some(_) => register.access.is_some_and(|access| access.is_write()),
+        None => register.fields.iter().any(|field| field.access.is_write()),
+    }
+}
+
+/// check the named values: unique names and values that fit in the width
+fn validate_enum_values(
+    enum_values: &[mdf::EnumValue],
+    width: u32,
+    what: &str,
+    page: &PageType,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (n, enum_value) in enum_values.iter().enumerate() {
+        if enum_values[..n].iter().any(|other| other.name == enum_value.name) {
+            diagnostics.push(Diagnostic::error(
+                page.clone(),
+                format!("{} has several values named '{}'", what, enum_value.name),
+            ));
+        }
+        if too_wide(enum_value.value.value, width) {
+            diagnostics.push(Diagnostic::error(
+                page.clone(),
+                format!("{} value {} is too wide for {} bits", what, enum_value.name, width),
+            ));
+        }
+    }
+}
+
+/// validate a register that isn't a bitfield
+fn validate_register_value(
+    register: &mdf::Register,
+    page: &PageType,
+    data_width: u32,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let width = register.width.unwrap_or(data_width);
+    let what = format!("register {}", register.name);
+
+    if register.access.is_none() {
+        diagnostics.push(Diagnostic::error(page.clone(), format!("{} needs an access type", what)));
+    }
+    if register.location.is_none() {
+        diagnostics.push(Diagnostic::error(page.clone(), format!("{} needs a location", what)));
+    }
+    match register.reset {
+        None => diagnostics.push(Diagnostic::error(page.clone(), format!("{} has no reset value", what))),
+        Some(reset) if too_wide(reset.value, width) => diagnostics.push(Diagnostic::error(
+            page.clone(),
+            format!("{} reset value 0x{:x} is too wide for {} bits", what, reset.value, width),
+        )),
+        Some(_) => (),
+    }
+    validate_enum_values(&register.enum_values, width, &what, page, diagnostics);
+}
+
+/// validate the fields of a bitfield register
+fn validate_fields(register: &mdf::Register, int_num: usize, reg_num: usize, diagnostics: &mut Vec<Diagnostic>) {
+    if register.fields.is_empty() {
+        diagnostics.push(Diagnostic::warning(
+            PageType::Register(int_num, reg_num, None),
+            format!("bitfield register {} has no fields", register.name),
+        ));
+    }
+
+    for (field_num, field) in register.fields.iter().enumerate() {
+        let page = PageType::Register(int_num, reg_num, Some(field_num));
+        let what = format!("field {}.{}", register.name, field.name);
+
+        if field.name.trim().is_empty() {
+            diagnostics.push(Diagnostic::error(
+                page.clone(),
+                format!("register {} has a field without a name", register.name),
+            ));
+        } else if register.fields[..field_num].iter().any(|other| other.name == field.name) {
+            diagnostics.push(Diagnostic::error(page.clone(), format!("{} is defined several times", what)));
+        }
+
+        if let mdf::FieldPosition::Field(msb, lsb) = field.position {
+            if lsb > msb {
+                diagnostics.push(Diagnostic::error(page.clone(), format!("{} has lsb bigger than msb", what)));
+                continue;
+            }
+        }
+        if field.array_stride() < field.width() {
+            diagnostics
+                .push(Diagnostic::error(page.clone(), format!("{} has an array stride smaller than its width", what)));
+            continue;
+        }
+        if field.location.is_none() && register.location.is_none() {
+            diagnostics.push(Diagnostic::error(
+                page.clone(),
+                format!("{} needs a location, on the field or the register", what),
+            ));
+        }
+        if too_wide(field.reset.value, field.width()) {
+            diagnostics.push(Diagnostic::error(
+                page.clone(),
+                format!("{} reset value 0x{:x} is too wide for {} bits", what, field.reset.value, field.width()),
+            ));
+        }
+        validate_enum_values(&field.enum_values, field.width(), &what, &page, diagnostics);
+    }
+
+    // two fields can't share bits
+    for (field_num, other, bit) in register.field_overlaps() {
+        diagnostics.push(Diagnostic::error(
+            PageType::Register(int_num, reg_num, Some(field_num)),
+            format!(
+                "field {}.{} overlaps field {} at bit {}",
+                register.name, register.fields[field_num].name, register.fields[other].name, bit
+            ),
+        ));
+    }
+}